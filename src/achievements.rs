@@ -8,8 +8,10 @@ use std::collections::HashSet;
 use serde::{Deserialize, Serialize};
 
 use crate::data::{FishId, FishSize, PlayerState};
+use crate::i18n::{t, tf};
 use crate::plugins::FishRegistry;
-use crate::render::{Colors, GameRenderer};
+use crate::render::Colors;
+use crate::surface::DrawSurface;
 
 // ── Achievement Identifiers ──────────────────────────────────────────────────
 
@@ -27,6 +29,14 @@ pub mod ids {
     pub const DAY_30: &str = "ACH_DAY_30";
     pub const CATCH_50: &str = "ACH_CATCH_50";
     pub const PLUGIN_FISH: &str = "ACH_PLUGIN_FISH";
+    pub const SHINY: &str = "ACH_SHINY";
+    pub const STREAK_5: &str = "ACH_STREAK_5";
+}
+
+/// Steam stat API names, used for stat-based achievements computed by Steam.
+pub mod stats {
+    pub const TOTAL_CATCHES: &str = "STAT_TOTAL_CATCHES";
+    pub const TOTAL_DATES: &str = "STAT_TOTAL_DATES";
 }
 
 /// Human-readable metadata for an achievement.
@@ -112,6 +122,18 @@ const ACHIEVEMENTS: &[AchievementDef] = &[
         description: "Catch a plugin fish.",
         hidden: false,
     },
+    AchievementDef {
+        id: ids::SHINY,
+        name: "Something Sparkly",
+        description: "Catch a shiny fish.",
+        hidden: true,
+    },
+    AchievementDef {
+        id: ids::STREAK_5,
+        name: "Reel Talent",
+        description: "Land 5 Large catches in a row.",
+        hidden: false,
+    },
 ];
 
 // ── Toast Notification ───────────────────────────────────────────────────────
@@ -179,6 +201,27 @@ impl AchievementTracker {
         }
     }
 
+    /// Show `status` as the player's Steam rich presence.
+    pub fn set_presence(&self, status: &str) {
+        let Some(ref steam) = self.steam else { return };
+        if let Err(e) = steam.friends().set_rich_presence("steam_display", status) {
+            tracing::warn!("Failed to set Steam rich presence: {}", e);
+        }
+    }
+
+    /// Push lifetime totals to Steam stats.
+    pub fn update_stats(&self, catches: i32, dates: i32) {
+        let Some(ref steam) = self.steam else { return };
+        let stats = steam.stats();
+        let result = stats
+            .set_i32(stats::TOTAL_CATCHES, catches)
+            .and_then(|_| stats.set_i32(stats::TOTAL_DATES, dates))
+            .and_then(|_| stats.store());
+        if let Err(e) = result {
+            tracing::warn!("Failed to sync Steam stats: {}", e);
+        }
+    }
+
     /// Unlock an achievement if it hasn't been unlocked yet.
     /// Adds a toast and syncs to Steam when applicable.
     fn unlock(
@@ -224,6 +267,7 @@ impl AchievementTracker {
         let dates_completed = player.dates_completed;
         let current_day = player.current_day;
         let has_won = player.has_won();
+        let perfect_streak = player.perfect_streak;
 
         let all_fish = FishId::all_with_plugins(registry);
         let all_caught = !all_fish.is_empty() && all_fish.iter().all(|f| player.has_caught(f));
@@ -245,6 +289,9 @@ impl AchievementTracker {
         if has_plugin_catch {
             self.unlock(ids::PLUGIN_FISH, unlocked);
         }
+        if perfect_streak >= 5 {
+            self.unlock(ids::STREAK_5, unlocked);
+        }
 
         // Date achievements
         if dates_completed >= 1 {
@@ -277,6 +324,14 @@ impl AchievementTracker {
         }
     }
 
+    /// Unlock the shiny achievement when a shiny fish is reeled in.
+    pub fn on_shiny_catch(
+        &mut self,
+        unlocked: &mut UnlockedAchievements,
+    ) {
+        self.unlock(ids::SHINY, unlocked);
+    }
+
     /// Unlock the moon battle discovery achievement.
     pub fn on_moon_battle_started(
         &mut self,
@@ -304,7 +359,7 @@ impl AchievementTracker {
     }
 
     /// Render active toasts in the top-right corner.
-    pub fn render_toasts(&self, renderer: &mut GameRenderer) {
+    pub fn render_toasts(&self, renderer: &mut dyn DrawSurface) {
         let cols = renderer.screen_cols();
 
         for (i, toast) in self.toasts.iter().enumerate() {
@@ -323,28 +378,28 @@ impl AchievementTracker {
             renderer.draw_at_grid(bar, bar_col, row + 2.0, [0.2, 0.2, 0.3, alpha * 0.8]);
 
             // Trophy + name
-            let header = format!(" * ACHIEVEMENT UNLOCKED * ");
+            let header = format!(" {} ", t("* ACHIEVEMENT UNLOCKED *"));
             renderer.draw_at_grid(
                 &header,
-                cols - header.len() as f32 - 1.0,
+                cols - header.chars().count() as f32 - 1.0,
                 row,
                 [1.0, 0.85, 0.0, alpha],
             );
 
             // Achievement name
-            let name_line = format!(" {}", toast.name);
+            let name_line = format!(" {}", t(&toast.name));
             renderer.draw_at_grid(
                 &name_line,
-                cols - name_line.len() as f32 - 1.0,
+                cols - name_line.chars().count() as f32 - 1.0,
                 row + 1.0,
                 [1.0, 1.0, 1.0, alpha],
             );
 
             // Description
-            let desc_line = format!(" {}", toast.description);
+            let desc_line = format!(" {}", t(&toast.description));
             renderer.draw_at_grid(
                 &desc_line,
-                cols - desc_line.len() as f32 - 1.0,
+                cols - desc_line.chars().count() as f32 - 1.0,
                 row + 2.0,
                 [0.7, 0.7, 0.7, alpha * 0.9],
             );
@@ -361,33 +416,49 @@ impl AchievementTracker {
         unlocked.ids.len()
     }
 
-    /// Render a full achievement list (for a future achievements screen).
-    #[allow(dead_code)]
+    /// Render the full achievement list, starting `scroll` entries down.
+    ///
+    /// Entries that don't fit above the bottom of the screen are cut off and
+    /// hinted at with a "more" marker. Returns how many entries fit.
     pub fn render_list(
-        renderer: &mut GameRenderer,
+        renderer: &mut dyn DrawSurface,
         unlocked: &UnlockedAchievements,
         start_row: f32,
-    ) {
-        let header = format!(
+        scroll: usize,
+    ) -> usize {
+        let header = tf(
             "=== ACHIEVEMENTS ({}/{}) ===",
-            unlocked.ids.len(),
-            ACHIEVEMENTS.len()
+            &[&Self::unlocked_count(unlocked), &Self::total_count()],
         );
-        renderer.draw_centered(&header, start_row, Colors::YELLOW);
+        renderer.draw_centered(&header, start_row, Colors::yellow());
+
+        // Two rows per entry, leaving room for the header and footer hints
+        let visible = ((renderer.screen_rows() - start_row - 6.0) / 2.0).max(1.0) as usize;
+        let scroll = scroll.min(ACHIEVEMENTS.len().saturating_sub(visible));
+
+        if scroll > 0 {
+            renderer.draw_centered(t("^ more ^"), start_row + 1.0, Colors::dark_gray());
+        }
 
-        for (i, def) in ACHIEVEMENTS.iter().enumerate() {
+        for (i, def) in ACHIEVEMENTS.iter().skip(scroll).take(visible).enumerate() {
             let row = start_row + 2.0 + i as f32 * 2.0;
             let is_unlocked = unlocked.ids.contains(def.id);
 
             if is_unlocked {
-                let line = format!("[x] {} - {}", def.name, def.description);
-                renderer.draw_centered(&line, row, Colors::GREEN);
+                let line = format!("[x] {} - {}", t(def.name), t(def.description));
+                renderer.draw_centered(&line, row, Colors::green());
             } else if def.hidden {
-                renderer.draw_centered("[ ] ???", row, Colors::DARK_GRAY);
+                renderer.draw_centered("[ ] ???", row, Colors::dark_gray());
             } else {
-                let line = format!("[ ] {} - {}", def.name, def.description);
-                renderer.draw_centered(&line, row, Colors::GRAY);
+                let line = format!("[ ] {} - {}", t(def.name), t(def.description));
+                renderer.draw_centered(&line, row, Colors::gray());
             }
         }
+
+        if scroll + visible < ACHIEVEMENTS.len() {
+            let row = start_row + 2.0 + visible as f32 * 2.0;
+            renderer.draw_centered(t("v more v"), row, Colors::dark_gray());
+        }
+        visible
     }
 }
@@ -128,6 +128,22 @@ pub const GILL_SHY: &str = r#"
       '---'
 "#;
 
+// ─── Idle Animation ─────────────────────────────────────────────────────────
+
+/// Frames per second for idle fish animations.
+pub const IDLE_FRAME_RATE: f32 = 2.0;
+
+/// Two-frame idle wiggle: odd frames nudge the art one column to the right.
+pub fn idle_wiggle(art: &str, frame: usize) -> String {
+    if frame.is_multiple_of(2) {
+        return art.to_string();
+    }
+    art.lines()
+        .map(|line| if line.is_empty() { String::new() } else { format!(" {}", line) })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 // ─── Fishing Scenes ─────────────────────────────────────────────────────────
 
 pub const POND_SCENE: &str = r#"
@@ -145,6 +161,20 @@ pub const POND_SCENE: &str = r#"
  \___________________________/
 "#;
 
+pub const MYSTERY_POND_SCENE: &str = r#"
+      .  ?        .    ?
+   ?      .   ___    .
+      .     /  ?  \       .
+   .       |  ? ?  |   ?
+     ?      \_____/  .
+  ___________|_______________
+ /                           \
+|  ~~~  ???  ~~~ ~~  ?~>  ~~  |
+|  ~~ ~~  ~~ <?> ~~ ~~  ~~ ~~ |
+|  ~~~ ~~  ~~ ~~  ~~~ ??>  ~~ |
+ \___________________________/
+"#;
+
 pub const CASTING_ART: &str = r#"
     O
    /|\  ~*
@@ -236,6 +266,12 @@ pub const DIALOGUE_SIDE: &str = "|";
 
 pub const POND_NAMES: [&str; 3] = ["Sunny Shallows", "Misty Depths", "Crystal Cove"];
 
+/// The extra pond where any fish might bite.
+pub const MYSTERY_POND_NAME: &str = "Mystery Pond";
+
+/// Stand-in for the fish on the line in the mystery pond.
+pub const MYSTERY_FISH_SMALL: &str = "  ><???>";
+
 // ─── Easter Egg: cult_papa vs The Moon ──────────────────────────────────────
 
 pub const CULT_PAPA_STANDING: &str = r#"
@@ -397,3 +433,23 @@ pub const MOON_FACE_PANIC: &str = "\
 pub const BUBBLES_DESC: &str = "A cheerful clownfish who loves puns and always\nlooks on the bright side. Energetic and warm.";
 pub const MARINA_DESC: &str = "An elegant swordfish with a sharp wit and a\ncompetitive streak. Beneath the edge, she cares.";
 pub const GILL_DESC: &str = "A shy pufferfish who puffs up when nervous.\nQuiet on the surface, but deeply thoughtful.";
+
+// ─── Encyclopedia Entries ───────────────────────────────────────────────────
+
+pub const BUBBLES_LORE: &str = "Clownfish live among the stinging tentacles of sea anemones, protected by a layer of mucus that keeps the anemone from recognizing them as food. Bubbles has never once been stung and will tell you about it.
+
+She runs the open mic at the Coral Cafe and has a pun for every occasion, including several occasions that have not happened yet. Most of the reef has learned to groan before the punchline lands.
+
+Under the jokes is a fish who remembers every name and every birthday in the shallows. If she likes you, she will bring you a seashell she found, and she will have been carrying it around for days waiting for the right moment.";
+
+pub const MARINA_LORE: &str = "Swordfish are among the fastest fish in the ocean, able to heat their eyes and brain to hunt in cold, deep water. Marina holds the reef sprint record and has the medal polished to a mirror finish.
+
+Her long-running rivalry with a barracuda named Darren is followed closely by the whole reef. Darren has never beaten her. Darren has written many essays about why that doesn't count.
+
+She treats every conversation like a race she intends to win, but she slows down for the people she cares about. Bring her a pearl and she will pretend it means nothing, then wear it to every meet for a month.";
+
+pub const GILL_LORE: &str = "Pufferfish inflate by swallowing water until they are too round to bite. Gill inflates when a stranger says hello, when a friend says hello, and occasionally when nobody says anything at all.
+
+He spends most days in the hold of the Sunken Ship, reading waterlogged books one careful page at a time. He has opinions about all of them and will share them if you wait long enough.
+
+Patience is the way in. Gill collects sea glass, and each piece on his shelf comes with a story he has never told anyone. Yet.";
@@ -0,0 +1,223 @@
+//! Background music and sound effects.
+//!
+//! Clips load from `assets/audio/`. A missing file, a missing audio device,
+//! or a build without the `audio` feature all just mean silence.
+
+#[cfg(feature = "audio")]
+use std::collections::HashMap;
+#[cfg(feature = "audio")]
+use std::io::Cursor;
+#[cfg(feature = "audio")]
+use std::sync::Arc;
+
+#[cfg(feature = "audio")]
+use rodio::Source;
+
+/// Directory audio clips are loaded from.
+#[cfg(feature = "audio")]
+const AUDIO_DIR: &str = "assets/audio";
+
+/// Short one-shot sound effects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sfx {
+    /// A fish takes the bait.
+    Bite,
+    /// A fish is landed.
+    Catch,
+    /// The line snaps.
+    Snap,
+    /// A date begins.
+    DateStart,
+}
+
+#[cfg(feature = "audio")]
+impl Sfx {
+    const ALL: [Sfx; 4] = [Sfx::Bite, Sfx::Catch, Sfx::Snap, Sfx::DateStart];
+
+    fn file_name(&self) -> &'static str {
+        match self {
+            Sfx::Bite => "bite.wav",
+            Sfx::Catch => "catch.wav",
+            Sfx::Snap => "snap.wav",
+            Sfx::DateStart => "date_start.wav",
+        }
+    }
+}
+
+/// Looping background music.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Track {
+    Menu,
+}
+
+#[cfg(feature = "audio")]
+impl Track {
+    const ALL: [Track; 1] = [Track::Menu];
+
+    fn file_name(&self) -> &'static str {
+        match self {
+            Track::Menu => "menu.ogg",
+        }
+    }
+}
+
+/// An open audio device and the clips loaded for it.
+#[cfg(feature = "audio")]
+struct Output {
+    /// Must stay alive for anything to play.
+    _stream: rodio::OutputStream,
+    handle: rodio::OutputStreamHandle,
+    /// Encoded clip data by file name.
+    clips: HashMap<&'static str, Arc<[u8]>>,
+    /// Music playing now. Dropping the sink stops it.
+    music: Option<(Track, rodio::Sink)>,
+}
+
+/// Plays music and sound effects, or nothing if audio isn't available.
+pub struct AudioManager {
+    #[cfg(feature = "audio")]
+    output: Option<Output>,
+    /// Master volume, 0.0 to 1.0.
+    volume: f32,
+    /// Whether everything is silenced, whatever the volume.
+    muted: bool,
+}
+
+impl AudioManager {
+    pub fn new(volume: f32, muted: bool) -> Self {
+        Self {
+            #[cfg(feature = "audio")]
+            output: Self::try_open(),
+            volume: volume.clamp(0.0, 1.0),
+            muted,
+        }
+    }
+
+    /// Change the master volume, including the music playing now.
+    pub fn set_volume(&mut self, volume: f32) {
+        self.volume = volume.clamp(0.0, 1.0);
+        self.apply_volume();
+    }
+
+    /// Silence or unsilence everything, including the music playing now.
+    pub fn set_muted(&mut self, muted: bool) {
+        self.muted = muted;
+        self.apply_volume();
+    }
+
+    /// Volume sounds actually play at.
+    #[cfg_attr(not(feature = "audio"), allow(dead_code))]
+    fn output_volume(&self) -> f32 {
+        if self.muted { 0.0 } else { self.volume }
+    }
+
+    /// Bring the playing music in line with the volume and mute setting.
+    fn apply_volume(&mut self) {
+        #[cfg(feature = "audio")]
+        {
+            let volume = self.output_volume();
+            if let Some((_, sink)) = self.output.as_mut().and_then(|o| o.music.as_mut()) {
+                sink.set_volume(volume);
+            }
+        }
+    }
+
+    /// Open the default audio device and load every clip found on disk.
+    #[cfg(feature = "audio")]
+    fn try_open() -> Option<Output> {
+        let (stream, handle) = match rodio::OutputStream::try_default() {
+            Ok(output) => output,
+            Err(e) => {
+                tracing::info!("No audio device ({}), playing without sound", e);
+                return None;
+            }
+        };
+
+        let names = Sfx::ALL
+            .iter()
+            .map(Sfx::file_name)
+            .chain(Track::ALL.iter().map(Track::file_name));
+        let mut clips = HashMap::new();
+        for name in names {
+            let path = std::path::Path::new(AUDIO_DIR).join(name);
+            match std::fs::read(&path) {
+                Ok(bytes) => {
+                    clips.insert(name, Arc::from(bytes));
+                }
+                Err(_) => tracing::debug!("Audio clip {} not found", path.display()),
+            }
+        }
+        tracing::info!("Audio ready, {} clips loaded", clips.len());
+
+        Some(Output {
+            _stream: stream,
+            handle,
+            clips,
+            music: None,
+        })
+    }
+
+    /// Play a one-shot sound effect.
+    pub fn play_sfx(&self, sfx: Sfx) {
+        #[cfg(feature = "audio")]
+        {
+            let Some(output) = &self.output else { return };
+            if self.muted {
+                return;
+            }
+            let Some(sink) = output.sink(sfx.file_name(), self.volume, false) else {
+                return;
+            };
+            // Let it finish on its own
+            sink.detach();
+        }
+        #[cfg(not(feature = "audio"))]
+        let _ = sfx;
+    }
+
+    /// Start looping a music track. Does nothing if it's already playing.
+    pub fn play_music(&mut self, track: Track) {
+        #[cfg(feature = "audio")]
+        {
+            let volume = self.output_volume();
+            let Some(output) = &mut self.output else { return };
+            if output.music.as_ref().is_some_and(|(playing, _)| *playing == track) {
+                return;
+            }
+            output.music = output
+                .sink(track.file_name(), volume, true)
+                .map(|sink| (track, sink));
+        }
+        #[cfg(not(feature = "audio"))]
+        let _ = track;
+    }
+}
+
+#[cfg(feature = "audio")]
+impl Output {
+    /// A new sink playing the clip `name`, if it loaded and decodes.
+    fn sink(&self, name: &str, volume: f32, looping: bool) -> Option<rodio::Sink> {
+        let bytes = self.clips.get(name)?.clone();
+        let decoder = match rodio::Decoder::new(Cursor::new(bytes)) {
+            Ok(decoder) => decoder,
+            Err(e) => {
+                tracing::warn!("Failed to decode audio clip {}: {}", name, e);
+                return None;
+            }
+        };
+        let sink = match rodio::Sink::try_new(&self.handle) {
+            Ok(sink) => sink,
+            Err(e) => {
+                tracing::warn!("Failed to play audio clip {}: {}", name, e);
+                return None;
+            }
+        };
+        sink.set_volume(volume);
+        if looping {
+            sink.append(decoder.repeat_infinite());
+        } else {
+            sink.append(decoder);
+        }
+        Some(sink)
+    }
+}
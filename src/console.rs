@@ -0,0 +1,95 @@
+//! Debug console: recent log events shown in place of the game.
+//!
+//! With the `debug_console` setting on, [`ConsoleLayer`] keeps the last
+//! [`CAPACITY`] `tracing` events in memory and backtick shows them. With it
+//! off the layer is never installed, so logging costs nothing extra.
+
+use std::collections::VecDeque;
+use std::fmt::{self, Write};
+use std::sync::Mutex;
+
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+use winit::keyboard::KeyCode;
+
+use crate::render::Colors;
+use crate::surface::DrawSurface;
+use crate::ui;
+
+/// Most log lines kept for the console.
+pub const CAPACITY: usize = 200;
+
+/// Formatted log lines, oldest first.
+static LINES: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+
+/// A `tracing` layer that copies each event into the console's buffer.
+pub struct ConsoleLayer;
+
+impl<S: Subscriber> Layer<S> for ConsoleLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut fields = FieldText::default();
+        event.record(&mut fields);
+        let meta = event.metadata();
+        let line = format!("{:>5} {}: {}", meta.level(), meta.target(), fields.0);
+
+        let Ok(mut lines) = LINES.lock() else {
+            return;
+        };
+        if lines.len() >= CAPACITY {
+            lines.pop_front();
+        }
+        lines.push_back(line);
+    }
+}
+
+/// An event's message followed by its other fields as `name=value`.
+#[derive(Default)]
+struct FieldText(String);
+
+impl Visit for FieldText {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+        let _ = if field.name() == "message" {
+            write!(self.0, "{:?}", value)
+        } else {
+            write!(self.0, "{}={:?}", field.name(), value)
+        };
+    }
+}
+
+/// The newest `count` log lines, oldest first.
+pub fn recent(count: usize) -> Vec<String> {
+    let Ok(lines) = LINES.lock() else {
+        return Vec::new();
+    };
+    lines.iter().skip(lines.len().saturating_sub(count)).cloned().collect()
+}
+
+/// Draw the console across the top half of the screen: the current screen,
+/// the last key pressed, then as many recent log lines as fit. The game
+/// isn't drawn while it's open, as nothing here would hide it.
+pub fn draw(renderer: &mut dyn DrawSurface, screen: &str, last_key: Option<KeyCode>) {
+    let width = renderer.screen_cols() as usize;
+    let height = (renderer.screen_rows() / 2.0).max(6.0) as usize;
+
+    ui::draw_box(renderer, 0.0, 0.0, width, height, Colors::dark_gray());
+
+    let key = last_key.map_or_else(|| "-".to_string(), |k| format!("{:?}", k));
+    renderer.draw_at_grid(&format!(" Screen: {}  Key: {} ", screen, key), 2.0, 0.0, Colors::cyan());
+
+    let room = height.saturating_sub(2);
+    let text_width = width.saturating_sub(4);
+    for (i, line) in recent(room).iter().enumerate() {
+        let color = if line.starts_with("ERROR") {
+            Colors::red()
+        } else if line.starts_with(" WARN") {
+            Colors::yellow()
+        } else {
+            Colors::white()
+        };
+        renderer.draw_at_grid(&ui::truncate_to_width(line, text_width), 2.0, 1.0 + i as f32, color);
+    }
+}
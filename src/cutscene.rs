@@ -0,0 +1,78 @@
+//! Plugin cutscenes: short ASCII art slideshows started by a secret word.
+//!
+//! Plugins register them with `register_cutscene`. Like the moon battle, each
+//! frame moves on by itself after its duration or when the player presses
+//! Enter, and Escape leaves at any time.
+
+use winit::keyboard::KeyCode;
+
+use crate::game::GameScreen;
+use crate::i18n::t;
+use crate::plugins::cutscene_def::CutsceneDef;
+use crate::render::Colors;
+use crate::surface::DrawSurface;
+
+/// A plugin cutscene being played.
+pub struct CutsceneState {
+    def: CutsceneDef,
+    frame: usize,
+    frame_timer: f32,
+}
+
+impl CutsceneState {
+    pub fn new(def: CutsceneDef) -> Self {
+        Self {
+            def,
+            frame: 0,
+            frame_timer: 0.0,
+        }
+    }
+
+    fn is_last_frame(&self) -> bool {
+        self.frame + 1 >= self.def.frames.len()
+    }
+
+    pub fn update(&mut self, dt: f32, key: Option<KeyCode>) -> Option<GameScreen> {
+        self.frame_timer += dt;
+
+        match key {
+            Some(KeyCode::Escape) => return Some(GameScreen::MainMenu),
+            Some(KeyCode::Enter | KeyCode::Space | KeyCode::ArrowRight | KeyCode::ArrowDown) => {
+                if self.is_last_frame() {
+                    return Some(GameScreen::MainMenu);
+                }
+                self.advance();
+            }
+            Some(_) => {}
+            None => {
+                // The last frame holds until the player dismisses it
+                let duration = self.def.frames[self.frame].duration;
+                if !self.is_last_frame() && self.frame_timer >= duration {
+                    self.advance();
+                }
+            }
+        }
+
+        None
+    }
+
+    fn advance(&mut self) {
+        self.frame += 1;
+        self.frame_timer = 0.0;
+    }
+
+    pub fn render(&self, renderer: &mut dyn DrawSurface) {
+        let frame = &self.def.frames[self.frame];
+        renderer.draw_multiline_centered(&frame.art, 2.0, Colors::white());
+        if !frame.caption.is_empty() {
+            renderer.draw_centered(t(&frame.caption), 24.0, Colors::yellow());
+        }
+
+        let hint = if self.is_last_frame() {
+            t("[Enter] Return")
+        } else {
+            t("[Enter] Next  [Esc] Skip")
+        };
+        renderer.draw_centered(hint, 28.0, Colors::dark_gray());
+    }
+}
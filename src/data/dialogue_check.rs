@@ -0,0 +1,180 @@
+//! Checks dialogue graphs for mistakes `build_unchecked` lets through:
+//! links to nodes that don't exist, nodes nothing leads to, and dates that
+//! can't end (or end in more than one place).
+
+use std::collections::HashSet;
+use std::fmt;
+
+use sable_dialogue::prelude::*;
+
+/// Where one dialogue node can lead.
+#[derive(Debug, Clone)]
+pub struct NodeLinks {
+    pub id: String,
+    /// Ids of the nodes this one can move on to.
+    pub targets: Vec<String>,
+    /// Whether this node ends the dialogue.
+    pub end: bool,
+}
+
+impl From<&DialogueNode> for NodeLinks {
+    fn from(node: &DialogueNode) -> Self {
+        match node {
+            DialogueNode::Text { id, next_node, .. } => Self {
+                id: id.clone(),
+                targets: next_node.iter().cloned().collect(),
+                end: false,
+            },
+            DialogueNode::Choice { id, choices, .. } => Self {
+                id: id.clone(),
+                targets: choices.iter().map(|c| c.next_node.clone()).collect(),
+                end: false,
+            },
+            DialogueNode::End { id } => Self {
+                id: id.clone(),
+                targets: Vec::new(),
+                end: true,
+            },
+        }
+    }
+}
+
+/// A problem found in a dialogue graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DialogueIssue {
+    /// The dialogue has no nodes at all.
+    Empty,
+    DuplicateId(String),
+    /// The start node doesn't exist.
+    MissingStart(String),
+    /// A node leads to a node that doesn't exist.
+    MissingTarget { from: String, to: String },
+    /// No path from the start reaches this node.
+    Orphan(String),
+    /// End nodes reachable from the start, when that isn't exactly one.
+    EndCount(usize),
+}
+
+impl fmt::Display for DialogueIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DialogueIssue::Empty => write!(f, "dialogue has no nodes"),
+            DialogueIssue::DuplicateId(id) => write!(f, "duplicate node id '{}'", id),
+            DialogueIssue::MissingStart(id) => write!(f, "start node '{}' doesn't exist", id),
+            DialogueIssue::MissingTarget { from, to } => {
+                write!(f, "node '{}' points to missing node '{}'", from, to)
+            }
+            DialogueIssue::Orphan(id) => write!(f, "node '{}' can't be reached from the start", id),
+            DialogueIssue::EndCount(0) => write!(f, "no end node can be reached"),
+            DialogueIssue::EndCount(n) => write!(f, "{} end nodes can be reached, expected 1", n),
+        }
+    }
+}
+
+/// Check a dialogue graph starting at `start`. Returns every problem found,
+/// or nothing for a well-formed dialogue.
+pub fn validate_links(start: &str, nodes: &[NodeLinks]) -> Vec<DialogueIssue> {
+    if nodes.is_empty() {
+        return vec![DialogueIssue::Empty];
+    }
+
+    let mut issues = Vec::new();
+    let mut ids: HashSet<&str> = HashSet::new();
+    for node in nodes {
+        if !ids.insert(&node.id) {
+            issues.push(DialogueIssue::DuplicateId(node.id.clone()));
+        }
+    }
+    for node in nodes {
+        for target in node.targets.iter().filter(|t| !ids.contains(t.as_str())) {
+            issues.push(DialogueIssue::MissingTarget {
+                from: node.id.clone(),
+                to: target.clone(),
+            });
+        }
+    }
+    if !ids.contains(start) {
+        issues.push(DialogueIssue::MissingStart(start.to_string()));
+        return issues;
+    }
+
+    // Walk every path from the start
+    let mut reached: HashSet<&str> = HashSet::from([start]);
+    let mut queue = vec![start];
+    while let Some(id) = queue.pop() {
+        for node in nodes.iter().filter(|n| n.id == id) {
+            for target in &node.targets {
+                if ids.contains(target.as_str()) && reached.insert(target) {
+                    queue.push(target);
+                }
+            }
+        }
+    }
+
+    for node in nodes.iter().filter(|n| !reached.contains(n.id.as_str())) {
+        issues.push(DialogueIssue::Orphan(node.id.clone()));
+    }
+    let ends = nodes
+        .iter()
+        .filter(|n| n.end && reached.contains(n.id.as_str()))
+        .count();
+    if ends != 1 {
+        issues.push(DialogueIssue::EndCount(ends));
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(id: &str, targets: &[&str]) -> NodeLinks {
+        NodeLinks {
+            id: id.to_string(),
+            targets: targets.iter().map(|t| t.to_string()).collect(),
+            end: false,
+        }
+    }
+
+    fn end(id: &str) -> NodeLinks {
+        NodeLinks {
+            id: id.to_string(),
+            targets: Vec::new(),
+            end: true,
+        }
+    }
+
+    #[test]
+    fn well_formed_dialogue_has_no_issues() {
+        let nodes = [node("start", &["a", "b"]), node("a", &["end"]), node("b", &["end"]), end("end")];
+        assert!(validate_links("start", &nodes).is_empty());
+    }
+
+    #[test]
+    fn missing_target_is_reported() {
+        let nodes = [node("start", &["nowhere"]), end("end")];
+        let issues = validate_links("start", &nodes);
+        assert!(issues.contains(&DialogueIssue::MissingTarget {
+            from: "start".to_string(),
+            to: "nowhere".to_string(),
+        }));
+    }
+
+    #[test]
+    fn orphan_is_reported() {
+        let nodes = [node("start", &["end"]), node("lost", &["end"]), end("end")];
+        assert_eq!(validate_links("start", &nodes), vec![DialogueIssue::Orphan("lost".to_string())]);
+    }
+
+    #[test]
+    fn unreachable_end_is_reported() {
+        let nodes = [node("start", &["start"])];
+        assert_eq!(validate_links("start", &nodes), vec![DialogueIssue::EndCount(0)]);
+    }
+
+    #[test]
+    fn two_reachable_ends_are_reported() {
+        let nodes = [node("start", &["a", "b"]), end("a"), end("b")];
+        assert_eq!(validate_links("start", &nodes), vec![DialogueIssue::EndCount(2)]);
+    }
+}
@@ -2,53 +2,123 @@
 //!
 //! Each fish has 3 dialogue trees that rotate based on how many dates
 //! you've been on. Date 1 is sweet and normal. Date 2 gets weird.
-//! Date 3 goes full unhinged shitpost energy.
+//! Date 3 goes full unhinged shitpost energy. Once a fish sees you as a
+//! Romantic Interest, a special romantic date joins the rotation.
+//!
+//! Choices can set memory flags (see [`flag_variable`]) that later dates
+//! check to change what the fish says.
 
 use sable_dialogue::prelude::*;
 use sable_dialogue::dialogue::DialogueBuilder;
 
-use crate::data::FishId;
+use crate::data::dialogue_check::NodeLinks;
+use crate::data::{FishId, MilestoneEvent, PlayerState, milestone_label, tiers};
+use crate::i18n::{t, tf};
 use crate::plugins::FishRegistry;
 
 /// Number of unique dialogues per fish.
 const DIALOGUES_PER_FISH: u32 = 3;
 
-/// Build the dialogue tree for a specific fish and date number.
-pub fn build_dialogue(fish_id: &FishId, date_number: u32, registry: &FishRegistry) -> DialogueTree {
-    match fish_id {
-        FishId::Bubbles => {
-            let variant = date_number % DIALOGUES_PER_FISH;
-            match variant {
-                0 => build_bubbles_date1(),
-                1 => build_bubbles_date2(),
-                _ => build_bubbles_date3(),
-            }
-        }
-        FishId::Marina => {
-            let variant = date_number % DIALOGUES_PER_FISH;
-            match variant {
-                0 => build_marina_date1(),
-                1 => build_marina_date2(),
-                _ => build_marina_date3(),
-            }
-        }
-        FishId::Gill => {
-            let variant = date_number % DIALOGUES_PER_FISH;
-            match variant {
-                0 => build_gill_date1(),
-                1 => build_gill_date2(),
-                _ => build_gill_date3(),
-            }
-        }
+/// Affection needed to unlock romantic date variants: the Romantic
+/// Interest tier, or whichever custom tier plays its milestone.
+pub fn romantic_threshold() -> i32 {
+    tiers::current().romantic_threshold()
+}
+
+/// Dialogue variables starting with this set a memory flag of the same name
+/// without the prefix.
+pub const FLAG_VARIABLE_PREFIX: &str = "flag_";
+
+/// Dialogue variable a choice sets to end the date on the spot, with
+/// the fish storming off.
+pub const END_DATE_VARIABLE: &str = "end_date";
+
+/// Name of the dialogue variable a choice sets to remember `flag`.
+pub fn flag_variable(flag: &str) -> String {
+    format!("{}{}", FLAG_VARIABLE_PREFIX, flag)
+}
+
+/// Build the dialogue tree for the player's next date with a fish.
+///
+/// Below [`romantic_threshold`] the regular dates rotate by date number. At or
+/// above it, the romantic date becomes an extra slot at the end of the rotation.
+pub fn build_dialogue(fish_id: &FishId, player: &PlayerState, registry: &FishRegistry) -> DialogueTree {
+    let date_number = player.date_count(fish_id);
+    let affection = player.relationship(fish_id);
+    let romantic = affection >= romantic_threshold();
+    let rotation = if romantic { DIALOGUES_PER_FISH + 1 } else { DIALOGUES_PER_FISH };
+    let variant = date_number % rotation;
+    let dialogue = match fish_id {
+        FishId::Bubbles => match variant {
+            0 => build_bubbles_date1(),
+            1 => build_bubbles_date2(),
+            2 => build_bubbles_date3(),
+            _ => build_bubbles_romantic(),
+        },
+        FishId::Marina => match variant {
+            0 => build_marina_date1(),
+            1 => build_marina_date2(),
+            2 => build_marina_date3(player),
+            _ => build_marina_romantic(),
+        },
+        FishId::Gill => match variant {
+            0 => build_gill_date1(),
+            1 => build_gill_date2(),
+            2 => build_gill_date3(),
+            _ => build_gill_romantic(),
+        },
         FishId::Plugin(plugin_id) => {
-            if let Some(fish) = registry.get(plugin_id) {
-                fish.dialogue_for_date(date_number)
+            return if let Some(fish) = registry.get(plugin_id) {
+                fish.dialogue_for_date(date_number, romantic, &player.memory_flags)
             } else {
                 // Fallback empty dialogue
                 crate::plugins::FishDef::fallback_dialogue_for(fish_id.name())
-            }
+            };
+        }
+    };
+    dialogue.build_unchecked()
+}
+
+/// A `DialogueBuilder` that also keeps where each node leads, so the tests
+/// can check built-in dialogues.
+#[cfg_attr(not(test), allow(dead_code))]
+struct BuiltinDialogue {
+    title: String,
+    start: String,
+    builder: DialogueBuilder,
+    links: Vec<NodeLinks>,
+}
+
+impl BuiltinDialogue {
+    fn new(start: &str) -> Self {
+        Self {
+            title: String::new(),
+            start: start.to_string(),
+            builder: DialogueBuilder::new(start),
+            links: Vec::new(),
         }
     }
+
+    fn title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self.builder = self.builder.title(title);
+        self
+    }
+
+    fn speaker(mut self, speaker: Speaker) -> Self {
+        self.builder = self.builder.speaker(speaker);
+        self
+    }
+
+    fn node(mut self, node: DialogueNode) -> Self {
+        self.links.push(NodeLinks::from(&node));
+        self.builder = self.builder.node(node);
+        self
+    }
+
+    fn build_unchecked(self) -> DialogueTree {
+        self.builder.build_unchecked()
+    }
 }
 
 fn text_node(id: &str, speaker: &str, text: &str, next: &str) -> DialogueNode {
@@ -56,8 +126,22 @@ fn text_node(id: &str, speaker: &str, text: &str, next: &str) -> DialogueNode {
         id: id.into(),
         speaker: Some(speaker.into()),
         emotion: None,
-        text: text.into(),
-        text_key: None,
+        text: t(text).into(),
+        text_key: Some(text.into()),
+        next_node: Some(next.into()),
+        actions: Vec::new(),
+        voice_clip: None,
+    }
+}
+
+/// A text node that also swaps the fish's portrait to `emotion` for the line.
+fn emotion_node(id: &str, speaker: &str, emotion: &str, text: &str, next: &str) -> DialogueNode {
+    DialogueNode::Text {
+        id: id.into(),
+        speaker: Some(speaker.into()),
+        emotion: Some(emotion.into()),
+        text: t(text).into(),
+        text_key: Some(text.into()),
         next_node: Some(next.into()),
         actions: Vec::new(),
         voice_clip: None,
@@ -68,8 +152,8 @@ fn text_node(id: &str, speaker: &str, text: &str, next: &str) -> DialogueNode {
 //  BUBBLES - Date 1 (Normal sweet clownfish)
 // ═══════════════════════════════════════════════════════════════════════════
 
-fn build_bubbles_date1() -> DialogueTree {
-    DialogueBuilder::new("start")
+fn build_bubbles_date1() -> BuiltinDialogue {
+    BuiltinDialogue::new("start")
         .title("Date with Bubbles")
         .speaker(Speaker::new("bubbles", "Bubbles"))
         .speaker(Speaker::new("player", "You"))
@@ -91,9 +175,10 @@ fn build_bubbles_date1() -> DialogueTree {
                     .sets("affection", 2_i32),
             ],
         })
-        .node(text_node(
+        .node(emotion_node(
             "q1_good",
             "bubbles",
+            "shy",
             "Oh em gee, EVERYTHING! But the kelp smoothie is to DIE for. Get it? Die? Like... fish don't actually die from kelp... okay that was dark.",
             "q2",
         ))
@@ -158,28 +243,29 @@ fn build_bubbles_date1() -> DialogueTree {
             "Me too! Can we do this again? I know this great place where the bioluminescent plankton glow at night!",
             "end",
         ))
-        .node(text_node(
+        .node(emotion_node(
             "ending_great",
             "bubbles",
+            "happy",
             "*turns bright orange* Stop it, you're making me blush! ...Wait, I'm ALWAYS orange. BUT THE POINT STANDS!",
             "end",
         ))
-        .node(text_node(
+        .node(emotion_node(
             "ending_meh",
             "bubbles",
+            "shy",
             "Oh... okay! Well, the offer stands if you ever want to hang out! No pressure! *nervous laugh*",
             "end",
         ))
         .node(DialogueNode::end("end"))
-        .build_unchecked()
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
 //  BUBBLES - Date 2 (Getting comfortable, chaotic energy emerging)
 // ═══════════════════════════════════════════════════════════════════════════
 
-fn build_bubbles_date2() -> DialogueTree {
-    DialogueBuilder::new("start")
+fn build_bubbles_date2() -> BuiltinDialogue {
+    BuiltinDialogue::new("start")
         .title("Date with Bubbles II")
         .speaker(Speaker::new("bubbles", "Bubbles"))
         .speaker(Speaker::new("player", "You"))
@@ -269,28 +355,29 @@ fn build_bubbles_date2() -> DialogueTree {
             "UNHINGED?? Every time you are nice to me my fins grow even more luscious and powerful. I'm reaching levels of radiance that are genuinely concerning. Thank you for enabling this.",
             "end",
         ))
-        .node(text_node(
+        .node(emotion_node(
             "ending_great",
             "bubbles",
+            "happy",
             "*slams fin on table* YOURS?? Oh I'm posting about this. 'They called me THEIRS. I am ascending. Goodbye mortal reef.' This is the best date I've EVER had and I've had SEVERAL.",
             "end",
         ))
-        .node(text_node(
+        .node(emotion_node(
             "ending_meh",
             "bubbles",
+            "shy",
             "A MISTAKE?! The espressos were the only correct decision I made today. The first mistake was when evolution gave me a mouth this powerful. I cannot be stopped, only contained.",
             "end",
         ))
         .node(DialogueNode::end("end"))
-        .build_unchecked()
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
 //  BUBBLES - Date 3 (Full unhinged, maximum shitpost energy)
 // ═══════════════════════════════════════════════════════════════════════════
 
-fn build_bubbles_date3() -> DialogueTree {
-    DialogueBuilder::new("start")
+fn build_bubbles_date3() -> BuiltinDialogue {
+    BuiltinDialogue::new("start")
         .title("Date with Bubbles III")
         .speaker(Speaker::new("bubbles", "Bubbles"))
         .speaker(Speaker::new("player", "You"))
@@ -381,28 +468,29 @@ fn build_bubbles_date3() -> DialogueTree {
             "NEEDS me?! Oh I'm going to CRY. Not sad cry. POWERFUL cry. The kind where a single tear rolls down and a rainbow appears and somewhere a baby dolphin learns to flip. That's the energy you just gave me. I love you. Wait I said that out loud. I'M NOT TAKING IT BACK.",
             "end",
         ))
-        .node(text_node(
+        .node(emotion_node(
             "ending_great",
             "bubbles",
+            "happy",
             "MAIN CHARACTER?! *knocks over three drinks* PLEASE join me in screaming for three full minutes because THAT is the most correct thing anyone has EVER said. This is canon now. WE are canon. I'm updating my reef bio IMMEDIATELY.",
             "end",
         ))
-        .node(text_node(
+        .node(emotion_node(
             "ending_meh",
             "bubbles",
+            "shy",
             "The TABLE is my STAGE and this cafe is my VENUE. You can't contain this. Really is a shame that once fish realize the vibes contradict them on suppressing my energy, they lean into 'calm down' like PLEASE stop lying to my face. Just say you can't handle the glow.",
             "end",
         ))
         .node(DialogueNode::end("end"))
-        .build_unchecked()
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
 //  MARINA - Date 1 (Cool, competitive, guarded)
 // ═══════════════════════════════════════════════════════════════════════════
 
-fn build_marina_date1() -> DialogueTree {
-    DialogueBuilder::new("start")
+fn build_marina_date1() -> BuiltinDialogue {
+    BuiltinDialogue::new("start")
         .title("Date with Marina")
         .speaker(Speaker::new("marina", "Marina"))
         .speaker(Speaker::new("player", "You"))
@@ -425,9 +513,10 @@ fn build_marina_date1() -> DialogueTree {
                     .sets("affection", 1_i32),
             ],
         })
-        .node(text_node(
+        .node(emotion_node(
             "q1_flirt",
             "marina",
+            "happy",
             "*pauses* ...That was... smoother than I expected. Don't think flattery will make me go easy on you, though.",
             "q2",
         ))
@@ -437,9 +526,10 @@ fn build_marina_date1() -> DialogueTree {
             "The fastest? Please. I'm the fastest in the ENTIRE eastern reef system. I clocked 60 knots last Tuesday. Care to race?",
             "q2",
         ))
-        .node(text_node(
+        .node(emotion_node(
             "q1_snarky",
             "marina",
+            "angry",
             "Careful with that attitude. I have a sword on my face and I'm not afraid to use it. ...I'm kidding. Mostly.",
             "q2",
         ))
@@ -454,6 +544,8 @@ fn build_marina_date1() -> DialogueTree {
                     .sets("affection", 3_i32),
                 Choice::new("You seem really intense.", "q2_blunt")
                     .sets("affection", 1_i32),
+                Choice::new("Honestly? This is kind of boring.", "q2_storm_off")
+                    .sets(END_DATE_VARIABLE, 1_i32),
             ],
         })
         .node(text_node(
@@ -474,6 +566,13 @@ fn build_marina_date1() -> DialogueTree {
             "Intense is how legends are made. But... maybe tonight I can dial it down. Just a notch. For you.",
             "q3",
         ))
+        .node(emotion_node(
+            "q2_storm_off",
+            "marina",
+            "angry",
+            "Boring? BORING?! I have never been boring a day in my life. *vanishes in a flash of silver*",
+            "end",
+        ))
         .node(DialogueNode::Choice {
             id: "q3".into(),
             prompt: Some("The moonlight glitters across the reef.".into()),
@@ -493,28 +592,29 @@ fn build_marina_date1() -> DialogueTree {
             "*long pause* ...You might be the first person to say that to me. *looks away* ...Same time next week?",
             "end",
         ))
-        .node(text_node(
+        .node(emotion_node(
             "ending_great",
             "marina",
+            "happy",
             "Deal. But I'm warning you - I don't lose. *small genuine smile* This was... not terrible. At all.",
             "end",
         ))
-        .node(text_node(
+        .node(emotion_node(
             "ending_meh",
             "marina",
+            "angry",
             "Educational. Right. Well... good night then. *swims away quickly*",
             "end",
         ))
         .node(DialogueNode::end("end"))
-        .build_unchecked()
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
 //  MARINA - Date 2 (Competitive shitposting, trash talk arc)
 // ═══════════════════════════════════════════════════════════════════════════
 
-fn build_marina_date2() -> DialogueTree {
-    DialogueBuilder::new("start")
+fn build_marina_date2() -> BuiltinDialogue {
+    BuiltinDialogue::new("start")
         .title("Date with Marina II")
         .speaker(Speaker::new("marina", "Marina"))
         .speaker(Speaker::new("player", "You"))
@@ -546,7 +646,7 @@ fn build_marina_date2() -> DialogueTree {
         .node(text_node(
             "q1_name",
             "marina",
-            "RIGHT?! We don't even need a trash nickname for him. Darren. DARREN. It's already an objectively hilarious name. A barracuda named DARREN. The jokes write themselves. Do less, nature.",
+            "RIGHT?! We don't even need a trash nickname for him. Darren. {red}DARREN{/}. It's already an objectively hilarious name. A barracuda named {red}DARREN{/}. The jokes write themselves. Do less, nature.",
             "q2",
         ))
         .node(text_node(
@@ -592,11 +692,13 @@ fn build_marina_date2() -> DialogueTree {
             speaker: None,
             choices: vec![
                 Choice::new("Ratio him. I'll boost you.", "ending_good")
-                    .sets("affection", 6_i32),
+                    .sets("affection", 6_i32)
+                    .sets(&flag_variable("boosted_marina"), 1_i32),
                 Choice::new("You're terrifying and I'm into it.", "ending_great")
                     .sets("affection", 5_i32),
                 Choice::new("I feel bad for Darren honestly.", "ending_meh")
-                    .sets("affection", 1_i32),
+                    .sets("affection", 1_i32)
+                    .sets(&flag_variable("sided_with_darren"), 1_i32),
             ],
         })
         .node(text_node(
@@ -605,31 +707,57 @@ fn build_marina_date2() -> DialogueTree {
             "BOOST ME?! *slams sword on table* We are a POWER COUPLE now. Darren doesn't stand a chance against our combined energy. This is the greatest alliance since... I don't know, I don't do history, I do WINNING.",
             "end",
         ))
-        .node(text_node(
+        .node(emotion_node(
             "ending_great",
             "marina",
+            "happy",
             "Terrifying and you're INTO it. That's it. That's the whole personality test. You passed. Most fish fail right around 'the sword is kind of scary' but you just LEANED IN. I'm keeping you.",
             "end",
         ))
-        .node(text_node(
+        .node(emotion_node(
             "ending_meh",
             "marina",
+            "angry",
             "Feel BAD for-- Darren posted 'Marina only won because of hydrodynamic privilege' and you feel BAD for him?! We're going to need to have a conversation about where your loyalties lie.",
             "end",
         ))
         .node(DialogueNode::end("end"))
-        .build_unchecked()
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
 //  MARINA - Date 3 (Full villain arc energy, maximum chaos)
 // ═══════════════════════════════════════════════════════════════════════════
 
-fn build_marina_date3() -> DialogueTree {
-    DialogueBuilder::new("start")
+fn build_marina_date3(player: &PlayerState) -> BuiltinDialogue {
+    // She remembers how you took the Darren situation last time
+    let recall = if player.has_flag("boosted_marina") {
+        Some(emotion_node(
+            "recall",
+            "marina",
+            "happy",
+            "Before anything else: that boost? Darren's post got ratioed into the SEA FLOOR. He turned his comments off. You did that. We did that. Power couple behavior.",
+            "start",
+        ))
+    } else if player.has_flag("sided_with_darren") {
+        Some(emotion_node(
+            "recall",
+            "marina",
+            "angry",
+            "I've decided to forgive you for feeling bad for Darren. Mostly. I'm still thinking about it. Sometimes at night. Anyway.",
+            "start",
+        ))
+    } else {
+        None
+    };
+
+    let mut builder = BuiltinDialogue::new(if recall.is_some() { "recall" } else { "start" })
         .title("Date with Marina III")
         .speaker(Speaker::new("marina", "Marina"))
-        .speaker(Speaker::new("player", "You"))
+        .speaker(Speaker::new("player", "You"));
+    if let Some(node) = recall {
+        builder = builder.node(node);
+    }
+    builder
         .node(text_node(
             "start",
             "marina",
@@ -649,9 +777,10 @@ fn build_marina_date3() -> DialogueTree {
                     .sets("affection", 1_i32),
             ],
         })
-        .node(text_node(
+        .node(emotion_node(
             "q1_outrage",
             "marina",
+            "angry",
             "THANK YOU. This is a clip from 'The Fabulous Life of Eastern Reef Champions' featuring ME and I need everyone to understand that banning excellence is a CHOICE that reflects on THEM not on this sword right here on my FACE.",
             "q2",
         ))
@@ -717,28 +846,29 @@ fn build_marina_date3() -> DialogueTree {
             "INTO BATTLE?! *sword gleaming* We ride at DAWN. Or whenever the tide is right. We ride at... high tide probably. The point is we're riding SOMEWHERE and Darren is going to SEE us and WEEP. You complete me. In a tactical sense.",
             "end",
         ))
-        .node(text_node(
+        .node(emotion_node(
             "ending_great",
             "marina",
+            "happy",
             "Can't look away? That's called RESPECT and also maybe FEAR and honestly both are valid expressions of love in this economy. I accept your devotion. Now help me workshop this post about Darren, I need it to hit different.",
             "end",
         ))
-        .node(text_node(
+        .node(emotion_node(
             "ending_meh",
             "marina",
+            "angry",
             "A professional what? A professional WINNER? Because that's what I AM. The Darren situation isn't a PROBLEM it's a NARRATIVE and every good narrative needs an antagonist. He should be THANKING me for making him relevant.",
             "end",
         ))
         .node(DialogueNode::end("end"))
-        .build_unchecked()
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
 //  GILL - Date 1 (Shy, anxious, secretly deep)
 // ═══════════════════════════════════════════════════════════════════════════
 
-fn build_gill_date1() -> DialogueTree {
-    DialogueBuilder::new("start")
+fn build_gill_date1() -> BuiltinDialogue {
+    BuiltinDialogue::new("start")
         .title("Date with Gill")
         .speaker(Speaker::new("gill", "Gill"))
         .speaker(Speaker::new("player", "You"))
@@ -767,9 +897,10 @@ fn build_gill_date1() -> DialogueTree {
             "*slowly deflates* ...Thank you. That's... really nice of you. Most fish get scared when I puff up. This sunken ship is actually my favorite place.",
             "q2",
         ))
-        .node(text_node(
+        .node(emotion_node(
             "q1_cute",
             "gill",
+            "shy",
             "*PUFFS UP MORE* D-don't say that! *tiny voice* ...but thank you... nobody's ever called it adorable before...",
             "q2",
         ))
@@ -829,28 +960,29 @@ fn build_gill_date1() -> DialogueTree {
             "*completely deflates to normal size* ...Really? You... you mean that? *tiny smile* ...Next time I'll show you the part of the ship where the starlight comes through the hull. It's... it's beautiful.",
             "end",
         ))
-        .node(text_node(
+        .node(emotion_node(
             "ending_great",
             "gill",
+            "happy",
             "*blushes* ...Thank you. That means more than you know. Maybe... maybe next time I won't puff up so much. *puffs up* ...Okay maybe a little.",
             "end",
         ))
-        .node(text_node(
+        .node(emotion_node(
             "ending_meh",
             "gill",
+            "angry",
             "Oh... okay. Yeah. It was nice of you to come. *sinks a little* I'll just... be here. With the ship. It's fine.",
             "end",
         ))
         .node(DialogueNode::end("end"))
-        .build_unchecked()
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
 //  GILL - Date 2 (Coming out of shell, anxious shitposting)
 // ═══════════════════════════════════════════════════════════════════════════
 
-fn build_gill_date2() -> DialogueTree {
-    DialogueBuilder::new("start")
+fn build_gill_date2() -> BuiltinDialogue {
+    BuiltinDialogue::new("start")
         .title("Date with Gill II")
         .speaker(Speaker::new("gill", "Gill"))
         .speaker(Speaker::new("player", "You"))
@@ -941,28 +1073,29 @@ fn build_gill_date2() -> DialogueTree {
             "*puffs up* *deflates* *puffs up again* ...Okay. *typing* 'Currently on a date with someone who makes me forget to be scared. If you don't hear from me it's because I've ascended. Or my puffing finally reached critical mass. Either way. I'm happy.' ...Posted. *hides face in fins*",
             "end",
         ))
-        .node(text_node(
+        .node(emotion_node(
             "ending_great",
             "gill",
+            "happy",
             "A... gift? *tiny puff* I've never thought of it as a gift. I thought it was just... the broken parts of my brain leaking out. But if the leaks are... beautiful? Then maybe I'm not broken. Maybe I'm just... dripping with content. Wait that sounds gross. I'm keeping it.",
             "end",
         ))
-        .node(text_node(
+        .node(emotion_node(
             "ending_meh",
             "gill",
+            "angry",
             "Private? ...Yeah. You're probably right. Some things should stay in the dark parts of the shipwreck. Where nobody can judge them. Where nobody can judge... me. *deflates completely* It's fine. This is fine.",
             "end",
         ))
         .node(DialogueNode::end("end"))
-        .build_unchecked()
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
 //  GILL - Date 3 (Fully unleashed philosopher pufferfish, unhinged in his own quiet way)
 // ═══════════════════════════════════════════════════════════════════════════
 
-fn build_gill_date3() -> DialogueTree {
-    DialogueBuilder::new("start")
+fn build_gill_date3() -> BuiltinDialogue {
+    BuiltinDialogue::new("start")
         .title("Date with Gill III")
         .speaker(Speaker::new("gill", "Gill"))
         .speaker(Speaker::new("player", "You"))
@@ -1053,18 +1186,851 @@ fn build_gill_date3() -> DialogueTree {
             "*for the first time in three dates, puffs up on purpose* You love my... my brain. The part of me I was most scared of. The loud messy 3 AM thought tornado. You love THAT. *single tear* I'm going to post 'someone loves the broken parts and suddenly they're not broken anymore' and it's going to get so many likes and I deserve every single one.",
             "end",
         ))
-        .node(text_node(
+        .node(emotion_node(
             "ending_great",
             "gill",
+            "happy",
             "The LORE?! You want to be in MY lore?! *typing furiously* 'Plot twist: the main character found a co-author. The sequel is going to hit different.' Posted. We're canon. The comments are already going insane. Someone said 'GILL HAS A PARTNER?!' and four fish puffed up in solidarity.",
             "end",
         ))
-        .node(text_node(
+        .node(emotion_node(
             "ending_meh",
             "gill",
+            "angry",
             "Log... off? *thousand yard stare* I can't log off. The thoughts don't stop when I log off. They just... happen without an audience. And if a pufferfish thinks in a shipwreck and nobody's around to read it... did the thought even matter? ...I'm posting that. That's going to SLAP at 2 AM.",
             "end",
         ))
         .node(DialogueNode::end("end"))
-        .build_unchecked()
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  BUBBLES - Romantic (Unlocked at Romantic Interest)
+// ═══════════════════════════════════════════════════════════════════════════
+
+fn build_bubbles_romantic() -> BuiltinDialogue {
+    BuiltinDialogue::new("start")
+        .title("Date with Bubbles")
+        .speaker(Speaker::new("bubbles", "Bubbles"))
+        .speaker(Speaker::new("player", "You"))
+        .node(text_node(
+            "start",
+            "bubbles",
+            "So I booked the Coral Cafe's back booth. The one with the candle. It's a real candle! Underwater! Nobody knows how it works and I'm NOT asking questions.",
+            "q1",
+        ))
+        .node(DialogueNode::Choice {
+            id: "q1".into(),
+            prompt: Some("Bubbles is unusually quiet, fins folded in the candlelight.".into()),
+            speaker: None,
+            choices: vec![
+                Choice::new("You're quiet tonight. Everything okay?", "q1_care")
+                    .sets("affection", 4_i32),
+                Choice::new("A mystery candle for a mystery date. I love it.", "q1_playful")
+                    .sets("affection", 3_i32),
+                Choice::new("Is the candle a fire hazard?", "q1_hazard")
+                    .sets("affection", 1_i32),
+            ],
+        })
+        .node(text_node(
+            "q1_care",
+            "bubbles",
+            "I'm GREAT. That's the problem! I'm usually loud so nobody notices when I'm nervous. But around you I don't have to be loud. It's weird! Good weird! The best weird!",
+            "q2",
+        ))
+        .node(text_node(
+            "q1_playful",
+            "bubbles",
+            "*giggles* A mystery date! I'm writing that in my shiny-things journal. Next to the spoon. The spoon is very important to me, so that's a BIG honor.",
+            "q2",
+        ))
+        .node(text_node(
+            "q1_hazard",
+            "bubbles",
+            "We're UNDERWATER. ...Okay, now I'm worried about it too. *scoots the candle an inch away* There. Safe. Romance restored.",
+            "q2",
+        ))
+        .node(DialogueNode::Choice {
+            id: "q2".into(),
+            prompt: Some("Bubbles slides a bottle cap across the table. It's the shiniest one you've ever seen.".into()),
+            speaker: None,
+            choices: vec![
+                Choice::new("I'll treasure it. Like I treasure you.", "ending_good")
+                    .sets("affection", 6_i32),
+                Choice::new("Does this mean I get a spot in the anemone?", "ending_great")
+                    .sets("affection", 5_i32),
+                Choice::new("Thanks, I guess?", "ending_meh")
+                    .sets("affection", 1_i32),
+            ],
+        })
+        .node(text_node(
+            "ending_good",
+            "bubbles",
+            "*turns an orange so bright the candle gets jealous* That was SO cheesy and I'm going to think about it every day for the rest of my life. Okay. Okay! Same time next week?!",
+            "end",
+        ))
+        .node(emotion_node(
+            "ending_great",
+            "bubbles",
+            "happy",
+            "I already cleared a shelf! My roommate moved the spoon and everything! ...She was very upset about the spoon. But you're worth it!",
+            "end",
+        ))
+        .node(emotion_node(
+            "ending_meh",
+            "bubbles",
+            "shy",
+            "It's, um, my best one. But that's okay! You can just hold onto it. For now. No pressure! *laughs a little too loudly*",
+            "end",
+        ))
+        .node(DialogueNode::end("end"))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  MARINA - Romantic (Unlocked at Romantic Interest)
+// ═══════════════════════════════════════════════════════════════════════════
+
+fn build_marina_romantic() -> BuiltinDialogue {
+    BuiltinDialogue::new("start")
+        .title("Date with Marina")
+        .speaker(Speaker::new("marina", "Marina"))
+        .speaker(Speaker::new("player", "You"))
+        .node(text_node(
+            "start",
+            "marina",
+            "No race tonight. No stopwatch. I left it at home. ...Don't look at me like that. I'm capable of sitting still. Probably.",
+            "q1",
+        ))
+        .node(DialogueNode::Choice {
+            id: "q1".into(),
+            prompt: Some("Marina floats beside you under the moon, deliberately not swimming anywhere.".into()),
+            speaker: None,
+            choices: vec![
+                Choice::new("You don't have to prove anything to me.", "q1_sincere")
+                    .sets("affection", 4_i32),
+                Choice::new("I'm timing how long you last, by the way.", "q1_tease")
+                    .sets("affection", 3_i32),
+                Choice::new("So... what do we do now?", "q1_awkward")
+                    .sets("affection", 1_i32),
+            ],
+        })
+        .node(text_node(
+            "q1_sincere",
+            "marina",
+            "*long pause* ...Everyone wants the fastest fish on the reef. You're the first one who seems to want the fish. That's... harder to outswim.",
+            "q2",
+        ))
+        .node(text_node(
+            "q1_tease",
+            "marina",
+            "Of course you are. *smirks* Then I'll just have to set the record for longest sit. Nobody beats me. Not even at this.",
+            "q2",
+        ))
+        .node(text_node(
+            "q1_awkward",
+            "marina",
+            "We... look at the moon? I read that's what couples do. It's very slow. I hate it. Stay anyway.",
+            "q2",
+        ))
+        .node(DialogueNode::Choice {
+            id: "q2".into(),
+            prompt: Some("Marina's sword gently taps yours. She pretends it was an accident.".into()),
+            speaker: None,
+            choices: vec![
+                Choice::new("*tap back*", "ending_good")
+                    .sets("affection", 6_i32),
+                Choice::new("Was that a challenge? Because I accept.", "ending_great")
+                    .sets("affection", 5_i32),
+                Choice::new("Watch it, that thing is pointy.", "ending_meh")
+                    .sets("affection", 1_i32),
+            ],
+        })
+        .node(text_node(
+            "ending_good",
+            "marina",
+            "...Hmph. *she doesn't move away* Next full moon. Same spot. I'll be early. I'm always early. Don't make me wait.",
+            "end",
+        ))
+        .node(emotion_node(
+            "ending_great",
+            "marina",
+            "happy",
+            "*laughs, really laughs* Fine. Race you to the kelp line and back. Loser plans the next date. ...I might lose on purpose. Don't tell anyone.",
+            "end",
+        ))
+        .node(emotion_node(
+            "ending_meh",
+            "marina",
+            "angry",
+            "It's a SWORD. It's supposed to be pointy. *sheathes her dignity* ...The moon's nice, at least.",
+            "end",
+        ))
+        .node(DialogueNode::end("end"))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  GILL - Romantic (Unlocked at Romantic Interest)
+// ═══════════════════════════════════════════════════════════════════════════
+
+fn build_gill_romantic() -> BuiltinDialogue {
+    BuiltinDialogue::new("start")
+        .title("Date with Gill")
+        .speaker(Speaker::new("gill", "Gill"))
+        .speaker(Speaker::new("player", "You"))
+        .node(text_node(
+            "start",
+            "gill",
+            "I cleaned the captain's quarters. For us. I moved forty-seven barnacles by hand. Well, by fin. They were not happy. I apologized to each one individually.",
+            "q1",
+        ))
+        .node(DialogueNode::Choice {
+            id: "q1".into(),
+            prompt: Some("Gill has arranged sea glass in a heart on the old captain's desk.".into()),
+            speaker: None,
+            choices: vec![
+                Choice::new("Gill, this is beautiful. You did all this for me?", "q1_touched")
+                    .sets("affection", 4_i32),
+                Choice::new("The barnacles will get over it. I'm glad you did.", "q1_support")
+                    .sets("affection", 3_i32),
+                Choice::new("Is that a heart or a lopsided circle?", "q1_tease")
+                    .sets("affection", 1_i32),
+            ],
+        })
+        .node(text_node(
+            "q1_touched",
+            "gill",
+            "*doesn't puff up at all* ...Huh. I'm not puffing. I always puff when I'm nervous. I guess I'm not nervous with you anymore. That's the scariest nice thing that's ever happened to me.",
+            "q2",
+        ))
+        .node(text_node(
+            "q1_support",
+            "gill",
+            "You're right. Barnacles hold grudges, but they can't move, so really it's their problem. *tiny smile* I wrote a post about you. I didn't publish it. Some things are just for us.",
+            "q2",
+        ))
+        .node(text_node(
+            "q1_tease",
+            "gill",
+            "It's a HEART. It's an anatomically accurate heart! ...No, you're right, it's a circle. *puffs a little* I'll fix it. I'll fix it right now.",
+            "q2",
+        ))
+        .node(DialogueNode::Choice {
+            id: "q2".into(),
+            prompt: Some("The shipwreck creaks softly. Gill looks at you without looking away for once.".into()),
+            speaker: None,
+            choices: vec![
+                Choice::new("I'm really happy I met you, Gill.", "ending_good")
+                    .sets("affection", 6_i32),
+                Choice::new("Publish the post. Let the whole reef know.", "ending_great")
+                    .sets("affection", 5_i32),
+                Choice::new("Can we go somewhere less creaky?", "ending_meh")
+                    .sets("affection", 1_i32),
+            ],
+        })
+        .node(text_node(
+            "ending_good",
+            "gill",
+            "Me too. Like, really. Not in a 3 AM way. In a sunlit, normal, calm way. I didn't know I could feel calm. *slowly drifts closer*",
+            "end",
+        ))
+        .node(emotion_node(
+            "ending_great",
+            "gill",
+            "happy",
+            "*hits post* It's out there. It's real. Four hundred likes in a minute. Gerald the sea cucumber commented 'finally.' I don't know who Gerald is but I appreciate him.",
+            "end",
+        ))
+        .node(emotion_node(
+            "ending_meh",
+            "gill",
+            "angry",
+            "Oh. Yeah. Of course. The creaking. *deflates* It's fine. I'll find a quieter wreck next time. There are... so many wrecks.",
+            "end",
+        ))
+        .node(DialogueNode::end("end"))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  GROUP DATES - Two fish at once, reacting to each other
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Build the dialogue for a group date with two fish.
+///
+/// Built-in pairs have their own scripts, whichever order they're picked in.
+/// A plugin fish can bring a script for a partner in its `group_dialogues` map,
+/// keyed by the partner's ID. Any other pair gets an awkward generic date.
+pub fn build_group_dialogue(
+    first: &FishId,
+    second: &FishId,
+    player: &PlayerState,
+    registry: &FishRegistry,
+) -> DialogueTree {
+    let builtin = match (first, second) {
+        (FishId::Bubbles, FishId::Marina) | (FishId::Marina, FishId::Bubbles) => {
+            Some(build_bubbles_marina_group())
+        }
+        (FishId::Bubbles, FishId::Gill) | (FishId::Gill, FishId::Bubbles) => {
+            Some(build_bubbles_gill_group())
+        }
+        (FishId::Marina, FishId::Gill) | (FishId::Gill, FishId::Marina) => {
+            Some(build_marina_gill_group())
+        }
+        _ => None,
+    };
+    if let Some(dialogue) = builtin {
+        return dialogue.build_unchecked();
+    }
+
+    let plugin_script = |fish: &FishId, partner: &FishId| match fish {
+        FishId::Plugin(id) => registry.get(id)?.group_dialogues.get(&partner.export_id()),
+        _ => None,
+    };
+    if let Some(script) = plugin_script(first, second).or_else(|| plugin_script(second, first)) {
+        return script.to_dialogue_tree(&player.memory_flags);
+    }
+    let first_name = first.name_with_registry(registry);
+    let second_name = second.name_with_registry(registry);
+    generic_group_date(&first_name, &second_name).build_unchecked()
+}
+
+fn build_bubbles_marina_group() -> BuiltinDialogue {
+    BuiltinDialogue::new("start")
+        .title("Group Date: Bubbles & Marina")
+        .speaker(Speaker::new("bubbles", "Bubbles"))
+        .speaker(Speaker::new("marina", "Marina"))
+        .speaker(Speaker::new("player", "You"))
+        .node(text_node(
+            "start",
+            "bubbles",
+            "OMG a GROUP date! Hi! Hi Marina! Hi! I brought snacks! They're mostly glitter but SOME of them are snacks!",
+            "marina_reacts",
+        ))
+        .node(emotion_node(
+            "marina_reacts",
+            "marina",
+            "angry",
+            "...You didn't mention she'd be here. She's been vibrating since we sat down. The table is vibrating.",
+            "q1",
+        ))
+        .node(DialogueNode::Choice {
+            id: "q1".into(),
+            prompt: Some("Both fish turn to you.".into()),
+            speaker: None,
+            choices: vec![
+                Choice::new("Bubbles, maybe take it down a notch?", "q1_calm")
+                    .sets("affection", 2_i32),
+                Choice::new("Marina, live a little!", "q1_loosen")
+                    .sets("affection", 2_i32),
+                Choice::new("Why don't you two race for the last snack?", "q1_race")
+                    .sets("affection", 4_i32),
+            ],
+        })
+        .node(emotion_node(
+            "q1_calm",
+            "bubbles",
+            "shy",
+            "Taking it down a notch! This is me at a notch! ...Okay that was still loud. I'm trying!",
+            "marina_softens",
+        ))
+        .node(text_node(
+            "marina_softens",
+            "marina",
+            "Hmph. That's... actually a decent effort. Fine. You can stay, glitter fish.",
+            "q2",
+        ))
+        .node(emotion_node(
+            "q1_loosen",
+            "marina",
+            "angry",
+            "I live plenty. I live at sixty knots. Living a LITTLE is for fish who can't keep up.",
+            "bubbles_cheers",
+        ))
+        .node(text_node(
+            "bubbles_cheers",
+            "bubbles",
+            "SIXTY KNOTS?! Marina that's so COOL. Can you teach me? I can do like... four. Four and a half downhill.",
+            "q2",
+        ))
+        .node(emotion_node(
+            "q1_race",
+            "marina",
+            "happy",
+            "Now THAT'S a date. Three, two-- she's already gone. She left before one. That's cheating, Bubbles!",
+            "bubbles_wins",
+        ))
+        .node(emotion_node(
+            "bubbles_wins",
+            "bubbles",
+            "happy",
+            "I WON! I won the glitter snack! ...Marina do you want half? Winners share. That's the rule. I made it up just now.",
+            "q2",
+        ))
+        .node(DialogueNode::Choice {
+            id: "q2".into(),
+            prompt: Some("The evening winds down.".into()),
+            speaker: None,
+            choices: vec![
+                Choice::new("You two are more alike than you think.", "ending_alike")
+                    .sets("affection", 4_i32),
+                Choice::new("Let's never do this again.", "ending_never"),
+            ],
+        })
+        .node(text_node(
+            "ending_alike",
+            "marina",
+            "...Don't say that where anyone can hear. But maybe. Maybe a little. Same time next week?",
+            "end",
+        ))
+        .node(emotion_node(
+            "ending_never",
+            "bubbles",
+            "shy",
+            "Aww. Well I had fun! Marina had fun too, she just does it on the inside. Deep, deep on the inside.",
+            "end",
+        ))
+        .node(DialogueNode::end("end"))
+}
+
+fn build_bubbles_gill_group() -> BuiltinDialogue {
+    BuiltinDialogue::new("start")
+        .title("Group Date: Bubbles & Gill")
+        .speaker(Speaker::new("bubbles", "Bubbles"))
+        .speaker(Speaker::new("gill", "Gill"))
+        .speaker(Speaker::new("player", "You"))
+        .node(text_node(
+            "start",
+            "bubbles",
+            "GILL! Gill is here! Gill, do the thing! The puffy thing! Please please please!",
+            "gill_reacts",
+        ))
+        .node(emotion_node(
+            "gill_reacts",
+            "gill",
+            "shy",
+            "It's not a thing, it's a stress response. And now I'm stressed about being asked to do it. Oh no. Oh no it's happening.",
+            "q1",
+        ))
+        .node(DialogueNode::Choice {
+            id: "q1".into(),
+            prompt: Some("Gill is slowly inflating. Bubbles is clapping.".into()),
+            speaker: None,
+            choices: vec![
+                Choice::new("Breathe with me, Gill. In... and out.", "q1_soothe")
+                    .sets("affection", 4_i32),
+                Choice::new("Bubbles, give Gill some space.", "q1_space")
+                    .sets("affection", 2_i32),
+                Choice::new("*clap along with Bubbles*", "q1_clap")
+                    .sets("affection", 1_i32),
+            ],
+        })
+        .node(emotion_node(
+            "q1_soothe",
+            "gill",
+            "happy",
+            "In... and out. Okay. Okay! I'm deflating. That's never worked before. You're very calming. Like a sea cucumber. That's a compliment.",
+            "bubbles_whispers",
+        ))
+        .node(text_node(
+            "bubbles_whispers",
+            "bubbles",
+            "*whispering* in... and out... I'm doing it too! I've never been this calm! I hate it! I love it!",
+            "q2",
+        ))
+        .node(emotion_node(
+            "q1_space",
+            "bubbles",
+            "shy",
+            "Oh! Right! Space! I'm giving space! I'm over here now! This is me being far away! Hi Gill from far away!",
+            "gill_thanks",
+        ))
+        .node(text_node(
+            "gill_thanks",
+            "gill",
+            "...Thank you. Both of you. She means well. It's just a lot of well at once.",
+            "q2",
+        ))
+        .node(emotion_node(
+            "q1_clap",
+            "gill",
+            "angry",
+            "Don't ENCOURAGE it! I'm a sphere now. I'm a sphere and I'm stuck to the booth. This is my life now.",
+            "q2",
+        ))
+        .node(DialogueNode::Choice {
+            id: "q2".into(),
+            prompt: Some("The night is almost over.".into()),
+            speaker: None,
+            choices: vec![
+                Choice::new("Same time next week? All three of us?", "ending_again")
+                    .sets("affection", 4_i32),
+                Choice::new("I think I'll take you out one at a time.", "ending_solo")
+                    .sets("affection", 2_i32),
+            ],
+        })
+        .node(text_node(
+            "ending_again",
+            "bubbles",
+            "YES! Gill, say yes! ...Gill's nodding! I think! It's hard to tell when he's round!",
+            "end",
+        ))
+        .node(text_node(
+            "ending_solo",
+            "gill",
+            "That's... probably wise. For my blood pressure. Do fish have blood pressure? I'm going to worry about that now.",
+            "end",
+        ))
+        .node(DialogueNode::end("end"))
+}
+
+fn build_marina_gill_group() -> BuiltinDialogue {
+    BuiltinDialogue::new("start")
+        .title("Group Date: Marina & Gill")
+        .speaker(Speaker::new("marina", "Marina"))
+        .speaker(Speaker::new("gill", "Gill"))
+        .speaker(Speaker::new("player", "You"))
+        .node(text_node(
+            "start",
+            "marina",
+            "So this is the pufferfish. I thought he'd be taller.",
+            "gill_reacts",
+        ))
+        .node(emotion_node(
+            "gill_reacts",
+            "gill",
+            "shy",
+            "I'm exactly as tall as I need to be. Also is that sword real? Is it sharp? Please keep it pointed at the ceiling.",
+            "q1",
+        ))
+        .node(DialogueNode::Choice {
+            id: "q1".into(),
+            prompt: Some("Marina's sword hovers very close to Gill.".into()),
+            speaker: None,
+            choices: vec![
+                Choice::new("Marina, maybe sit on the other side?", "q1_move")
+                    .sets("affection", 3_i32),
+                Choice::new("Gill, tell Marina about your shipwreck.", "q1_wreck")
+                    .sets("affection", 4_i32),
+                Choice::new("Who would win in a fight, do you think?", "q1_fight")
+                    .sets("affection", 1_i32),
+            ],
+        })
+        .node(text_node(
+            "q1_move",
+            "marina",
+            "Fine. But only because this side has the better view. Not because of the puffer. Obviously.",
+            "gill_relieved",
+        ))
+        .node(emotion_node(
+            "gill_relieved",
+            "gill",
+            "happy",
+            "Thank you. I was about ninety percent sure I'd pop. I'm down to sixty. Sixty is great for me.",
+            "q2",
+        ))
+        .node(emotion_node(
+            "q1_wreck",
+            "gill",
+            "happy",
+            "Oh! It's a fishing trawler from 1962! The hull's mostly intact and there's a room full of old maps...",
+            "marina_interested",
+        ))
+        .node(text_node(
+            "marina_interested",
+            "marina",
+            "...Maps of where? The eastern trench? I've been trying to chart a faster route through there for YEARS. Show me.",
+            "q2",
+        ))
+        .node(emotion_node(
+            "q1_fight",
+            "marina",
+            "happy",
+            "Me. Obviously. I have a sword.",
+            "gill_fight",
+        ))
+        .node(emotion_node(
+            "gill_fight",
+            "gill",
+            "angry",
+            "I'm POISONOUS, Marina. Nobody wins. That's the whole point of me. Can we please talk about anything else?",
+            "q2",
+        ))
+        .node(DialogueNode::Choice {
+            id: "q2".into(),
+            prompt: Some("The evening is wrapping up.".into()),
+            speaker: None,
+            choices: vec![
+                Choice::new("I'm glad you two got along.", "ending_glad")
+                    .sets("affection", 4_i32),
+                Choice::new("Well, nobody got stabbed or poisoned.", "ending_survived")
+                    .sets("affection", 2_i32),
+            ],
+        })
+        .node(text_node(
+            "ending_glad",
+            "marina",
+            "Got along is a strong phrase. But he can come next time. And bring the maps.",
+            "end",
+        ))
+        .node(text_node(
+            "ending_survived",
+            "gill",
+            "Honestly? Best outcome I could've hoped for. Ten out of ten. I'm going to go lie down.",
+            "end",
+        ))
+        .node(DialogueNode::end("end"))
+}
+
+/// Group date for a pair without a script of their own. Neither fish
+/// quite knows why the other one is here.
+fn generic_group_date(first: &str, second: &str) -> BuiltinDialogue {
+    let narrate = |id: &str, template: &str, next: &str| DialogueNode::Text {
+        id: id.into(),
+        speaker: None,
+        emotion: None,
+        text: tf(template, &[&first, &second]),
+        text_key: Some(template.into()),
+        next_node: Some(next.into()),
+        actions: Vec::new(),
+        voice_clip: None,
+    };
+    BuiltinDialogue::new("start")
+        .title(&format!("Group Date: {} & {}", first, second))
+        .speaker(Speaker::new("player", "You"))
+        .node(narrate(
+            "start",
+            "{} and {} both showed up. Neither of them was told about the other.",
+            "stare",
+        ))
+        .node(narrate(
+            "stare",
+            "{} stares at {}. The silence stretches on. Somewhere, a bubble pops.",
+            "q1",
+        ))
+        .node(DialogueNode::Choice {
+            id: "q1".into(),
+            prompt: Some("This is very awkward. What do you do?".into()),
+            speaker: None,
+            choices: vec![
+                Choice::new("So! How about this water, huh?", "small_talk")
+                    .sets("affection", 2_i32),
+                Choice::new("Introduce them properly.", "introduce")
+                    .sets("affection", 3_i32),
+                Choice::new("*pretend to check your phone*", "phone"),
+            ],
+        })
+        .node(narrate(
+            "small_talk",
+            "{} agrees the water is wet. {} agrees too. It's a start.",
+            "ending",
+        ))
+        .node(narrate(
+            "introduce",
+            "{} and {} shake fins. They discover they both hate seagulls. A friendship blooms.",
+            "ending",
+        ))
+        .node(narrate(
+            "phone",
+            "You don't have a phone. You're underwater. {} and {} watch you hold a rock to your face.",
+            "ending",
+        ))
+        .node(narrate(
+            "ending",
+            "The date ends. {} and {} swim off in opposite directions, but they both glance back.",
+            "end",
+        ))
+        .node(DialogueNode::end("end"))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  MILESTONES - Short scenes for reaching a relationship tier
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Build the scene for a fish reaching a milestone tier for the first time.
+///
+/// Plugin fish use their `milestone_dialogues` entry for the tier, falling
+/// back to a generic scene like fish without one.
+pub fn build_milestone(event: &MilestoneEvent, player: &PlayerState, registry: &FishRegistry) -> DialogueTree {
+    let lines = match &event.fish_id {
+        FishId::Bubbles => bubbles_milestone_lines(event.tier),
+        FishId::Marina => marina_milestone_lines(event.tier),
+        FishId::Gill => gill_milestone_lines(event.tier),
+        FishId::Plugin(plugin_id) => {
+            let scene = registry
+                .get(plugin_id)
+                .and_then(|fish| fish.milestone_dialogues.get(&event.tier));
+            if let Some(scene) = scene {
+                return scene.to_dialogue_tree(&player.memory_flags);
+            }
+            let name = event.fish_id.name_with_registry(registry);
+            return generic_milestone(&name, event.tier).build_unchecked();
+        }
+    };
+    milestone_scene(event.fish_id.name(), event.tier, lines).build_unchecked()
+}
+
+/// Milestone scene for a built-in fish from its (emotion, first line,
+/// second line).
+fn milestone_scene(name: &str, tier: i32, lines: (&str, &str, &str)) -> BuiltinDialogue {
+    let speaker_id = name.to_lowercase();
+    BuiltinDialogue::new("start")
+        .title(&format!("{} - {}", name, milestone_label(tier)))
+        .speaker(Speaker::new(&speaker_id, name))
+        .speaker(Speaker::new("player", "You"))
+        .node(emotion_node("start", &speaker_id, lines.0, lines.1, "next"))
+        .node(text_node("next", &speaker_id, lines.2, "end"))
+        .node(DialogueNode::end("end"))
+}
+
+/// Scene for fish without one of their own.
+fn generic_milestone(name: &str, tier: i32) -> BuiltinDialogue {
+    let speaker_id = name.to_lowercase();
+    let template = "{} swims a little closer than before. You're now: {}!";
+    BuiltinDialogue::new("start")
+        .title(&format!("{} - {}", name, milestone_label(tier)))
+        .speaker(Speaker::new(&speaker_id, name))
+        .node(DialogueNode::Text {
+            id: "start".into(),
+            speaker: None,
+            emotion: None,
+            text: tf(template, &[&name, &t(milestone_label(tier))]),
+            text_key: Some(template.into()),
+            next_node: Some("end".into()),
+            actions: Vec::new(),
+            voice_clip: None,
+        })
+        .node(DialogueNode::end("end"))
+}
+
+/// (emotion, first line, second line) for each of Bubbles' milestones.
+fn bubbles_milestone_lines(tier: i32) -> (&'static str, &'static str, &'static str) {
+    match tier {
+        6 => (
+            "happy",
+            "Wait wait wait. We hang out. We talk. You laugh at my puns. You know what that makes us?",
+            "FRIENDS! Official ones! I'm telling the whole reef. I'm telling the anemone first. She's going to be SO jealous.",
+        ),
+        16 => (
+            "happy",
+            "I made you something! It's a friendship bracelet! It's made of kelp! It will dissolve in about a day!",
+            "That's okay though. I'll make you a new one every day. That's what close friends do. I looked it up. I didn't look it up.",
+        ),
+        26 => (
+            "shy",
+            "So, um. My fins keep doing a thing when you're around. A fluttery thing. I asked the doctor fish about it.",
+            "He said it's called 'having a crush.' I said 'on WHO?' and he just looked at me. ...It's you. The crush is on you.",
+        ),
+        _ => (
+            "happy",
+            "I used to think the brightest thing in the ocean was the sun coming through the water.",
+            "Then I met you. Okay, that was cheesy. But I mean it! Every bubble of it! Forever and ever, okay?",
+        ),
+    }
+}
+
+/// (emotion, first line, second line) for each of Marina's milestones.
+fn marina_milestone_lines(tier: i32) -> (&'static str, &'static str, &'static str) {
+    match tier {
+        6 => (
+            "happy",
+            "You keep showing up. Most fish give up after I beat them in a race twice.",
+            "Fine. I'll admit it. You're... a friend. Don't make it weird.",
+        ),
+        16 => (
+            "happy",
+            "I let you draft behind me in the current today. I don't let ANYONE draft behind me.",
+            "That's a sign of trust, in case you didn't know. Which you didn't. Because I've never done it before.",
+        ),
+        26 => (
+            "shy",
+            "I lost a race this morning. I was thinking about you and swam straight into a rock.",
+            "This is your fault, and I expect you to take responsibility. ...By going out with me again. Obviously.",
+        ),
+        _ => (
+            "happy",
+            "I've spent my whole life trying to be the fastest thing in the sea.",
+            "Turns out I was just trying to get somewhere. I think it was here. With you. Don't you dare tell anyone I said that.",
+        ),
+    }
+}
+
+/// (emotion, first line, second line) for each of Gill's milestones.
+fn gill_milestone_lines(tier: i32) -> (&'static str, &'static str, &'static str) {
+    match tier {
+        6 => (
+            "shy",
+            "I-I noticed I didn't puff up at all when you said hi today. Not even a little.",
+            "I think that means we're friends? If... if that's okay with you. *tiny puff*",
+        ),
+        16 => (
+            "happy",
+            "I wrote down three things I like about you. Then I wrote down thirty more. I ran out of sand to write on.",
+            "Close friends share things, right? So... here. You can read them. Maybe not all at once.",
+        ),
+        26 => (
+            "shy",
+            "*puffs up completely* S-sorry! It happens when my heart does the thing. It's been doing the thing a lot.",
+            "It only does the thing around you. I think... I think that means something. *deflates slowly*",
+        ),
+        _ => (
+            "happy",
+            "I used to hide in the rocks all day. It felt safe.",
+            "Now being with you feels safe. That's... a much nicer place to live. Can I stay?",
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::MILESTONE_TIERS;
+    use crate::data::dialogue_check::{DialogueIssue, validate_links};
+
+    impl BuiltinDialogue {
+        fn issues(&self) -> Vec<DialogueIssue> {
+            validate_links(&self.start, &self.links)
+        }
+    }
+
+    /// Check every built-in date and milestone scene, printing each problem
+    /// found. Returns how many there were.
+    fn check_builtin_dialogues() -> usize {
+        let player = PlayerState::default();
+        let mut dialogues = vec![
+            build_bubbles_date1(),
+            build_bubbles_date2(),
+            build_bubbles_date3(),
+            build_bubbles_romantic(),
+            build_marina_date1(),
+            build_marina_date2(),
+            build_marina_date3(&player),
+            build_marina_romantic(),
+            build_gill_date1(),
+            build_gill_date2(),
+            build_gill_date3(),
+            build_gill_romantic(),
+            build_bubbles_marina_group(),
+            build_bubbles_gill_group(),
+            build_marina_gill_group(),
+            generic_group_date("Fish", "Fish"),
+        ];
+        for tier in MILESTONE_TIERS {
+            dialogues.push(milestone_scene(FishId::Bubbles.name(), tier, bubbles_milestone_lines(tier)));
+            dialogues.push(milestone_scene(FishId::Marina.name(), tier, marina_milestone_lines(tier)));
+            dialogues.push(milestone_scene(FishId::Gill.name(), tier, gill_milestone_lines(tier)));
+            dialogues.push(generic_milestone("Fish", tier));
+        }
+
+        let mut count = 0;
+        for dialogue in &dialogues {
+            for issue in dialogue.issues() {
+                eprintln!("Built-in dialogue \"{}\": {}", dialogue.title, issue);
+                count += 1;
+            }
+        }
+        count
+    }
+
+    #[test]
+    fn builtin_dialogues_are_well_formed() {
+        assert_eq!(check_builtin_dialogues(), 0);
+    }
 }
@@ -1,9 +1,11 @@
 //! Game data types and state management.
 
 pub mod dialogues;
+pub mod dialogue_check;
 pub mod save;
+pub mod tiers;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use serde::{Deserialize, Serialize};
 
@@ -42,6 +44,16 @@ impl FishId {
         matches!(self, FishId::Plugin(_))
     }
 
+    /// Plain string ID used in exported stats.
+    pub fn export_id(&self) -> String {
+        match self {
+            FishId::Bubbles => "bubbles".to_string(),
+            FishId::Marina => "marina".to_string(),
+            FishId::Gill => "gill".to_string(),
+            FishId::Plugin(id) => id.clone(),
+        }
+    }
+
     pub fn name_with_registry(&self, registry: &FishRegistry) -> String {
         match self {
             FishId::Bubbles => "Bubbles".to_string(),
@@ -78,6 +90,19 @@ impl FishId {
         }
     }
 
+    /// Full encyclopedia entry, in paragraphs separated by blank lines.
+    pub fn encyclopedia_with_registry(&self, registry: &FishRegistry) -> String {
+        match self {
+            FishId::Bubbles => crate::ascii_art::BUBBLES_LORE.to_string(),
+            FishId::Marina => crate::ascii_art::MARINA_LORE.to_string(),
+            FishId::Gill => crate::ascii_art::GILL_LORE.to_string(),
+            FishId::Plugin(id) => registry
+                .get(id)
+                .map(|f| f.encyclopedia.clone())
+                .unwrap_or_else(|| "Little is known about this fish.".to_string()),
+        }
+    }
+
     /// Difficulty of catching this fish (0.0 = easy, 1.0 = hard).
     pub fn difficulty_with_registry(&self, registry: &FishRegistry) -> f32 {
         match self {
@@ -91,6 +116,34 @@ impl FishId {
         }
     }
 
+    /// How this fish fights on the line.
+    pub fn personality_with_registry(&self, registry: &FishRegistry) -> FishPersonality {
+        match self {
+            FishId::Bubbles => FishPersonality::Steady,
+            FishId::Marina => FishPersonality::Darter,
+            FishId::Gill => FishPersonality::Lurker,
+            FishId::Plugin(id) => registry.get(id).map(|f| f.personality).unwrap_or_default(),
+        }
+    }
+
+    /// Reeling zone half-width a plugin fish asks for, if it sets one.
+    pub fn center_zone_with_registry(&self, registry: &FishRegistry) -> Option<f32> {
+        match self {
+            FishId::Plugin(id) => registry.get(id).and_then(|f| f.center_zone),
+            _ => None,
+        }
+    }
+
+    /// The gift item this fish loves, if any.
+    pub fn favorite_gift_with_registry(&self, registry: &FishRegistry) -> Option<String> {
+        match self {
+            FishId::Bubbles => Some("Seashell".to_string()),
+            FishId::Marina => Some("Pearl".to_string()),
+            FishId::Gill => Some("Sea Glass".to_string()),
+            FishId::Plugin(id) => registry.get(id).and_then(|f| f.favorite_gift.clone()),
+        }
+    }
+
     /// Which pond index this fish appears in.
     pub fn pond_index(&self) -> usize {
         match self {
@@ -102,16 +155,24 @@ impl FishId {
         }
     }
 
+    /// Which pond index this fish appears in, including plugin ponds.
+    pub fn pond_index_with_registry(&self, registry: &FishRegistry) -> Option<usize> {
+        match self {
+            FishId::Plugin(id) => registry.pond_index_of(id),
+            _ => Some(self.pond_index()),
+        }
+    }
+
     /// The fish's color for rendering.
     pub fn color_with_registry(&self, registry: &FishRegistry) -> [f32; 4] {
         match self {
-            FishId::Bubbles => crate::render::Colors::ORANGE,
-            FishId::Marina => crate::render::Colors::LIGHT_BLUE,
-            FishId::Gill => crate::render::Colors::GREEN,
+            FishId::Bubbles => crate::render::Colors::orange(),
+            FishId::Marina => crate::render::Colors::light_blue(),
+            FishId::Gill => crate::render::Colors::green(),
             FishId::Plugin(id) => registry
                 .get(id)
                 .map(|f| f.color)
-                .unwrap_or(crate::render::Colors::WHITE),
+                .unwrap_or_else(crate::render::Colors::white),
         }
     }
 
@@ -155,16 +216,16 @@ impl FishId {
 
     pub fn color(&self) -> [f32; 4] {
         match self {
-            FishId::Bubbles => crate::render::Colors::ORANGE,
-            FishId::Marina => crate::render::Colors::LIGHT_BLUE,
-            FishId::Gill => crate::render::Colors::GREEN,
-            FishId::Plugin(_) => crate::render::Colors::WHITE,
+            FishId::Bubbles => crate::render::Colors::orange(),
+            FishId::Marina => crate::render::Colors::light_blue(),
+            FishId::Gill => crate::render::Colors::green(),
+            FishId::Plugin(_) => crate::render::Colors::white(),
         }
     }
 }
 
-/// Size of a caught fish.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+/// Size of a caught fish, ordered smallest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum FishSize {
     Small,
     Medium,
@@ -179,6 +240,132 @@ impl FishSize {
             FishSize::Large => "Large",
         }
     }
+
+    /// Gift item found alongside a catch of this size.
+    pub fn gift_item(&self) -> &'static str {
+        match self {
+            FishSize::Small => "Sea Glass",
+            FishSize::Medium => "Seashell",
+            FishSize::Large => "Pearl",
+        }
+    }
+}
+
+/// How a hooked fish fights in the minigame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FishPersonality {
+    /// Mixed tugs, surges and drifts. Fish without a declared personality.
+    #[default]
+    Steady,
+    /// Frequent short surges.
+    Darter,
+    /// Long stillness, then one big pull.
+    Lurker,
+    /// Rapid direction swaps.
+    Thrasher,
+}
+
+impl FishPersonality {
+    pub const ALL: [FishPersonality; 4] = [
+        FishPersonality::Steady,
+        FishPersonality::Darter,
+        FishPersonality::Lurker,
+        FishPersonality::Thrasher,
+    ];
+
+    /// Name used by plugin scripts, e.g. `personality: "darter"`.
+    pub fn key(&self) -> &'static str {
+        match self {
+            FishPersonality::Steady => "steady",
+            FishPersonality::Darter => "darter",
+            FishPersonality::Lurker => "lurker",
+            FishPersonality::Thrasher => "thrasher",
+        }
+    }
+
+    /// Look up a personality by its script name, ignoring case.
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|p| p.key().eq_ignore_ascii_case(key.trim()))
+    }
+
+    /// Name shown in the encyclopedia.
+    pub fn label(&self) -> &'static str {
+        match self {
+            FishPersonality::Steady => "Steady",
+            FishPersonality::Darter => "Darter",
+            FishPersonality::Lurker => "Lurker",
+            FishPersonality::Thrasher => "Thrasher",
+        }
+    }
+}
+
+/// Difficulty chosen when starting a new game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GameMode {
+    /// More affection per gain, and the fishing line never snaps.
+    Casual,
+    #[default]
+    Normal,
+    /// Less affection per gain, and fish fight harder.
+    Hardcore,
+}
+
+impl GameMode {
+    pub const ALL: [GameMode; 3] = [GameMode::Casual, GameMode::Normal, GameMode::Hardcore];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            GameMode::Casual => "Casual",
+            GameMode::Normal => "Normal",
+            GameMode::Hardcore => "Hardcore",
+        }
+    }
+
+    /// One-line summary shown when picking a mode.
+    pub fn description(&self) -> &'static str {
+        match self {
+            GameMode::Casual => "Fish warm up faster and lines never snap.",
+            GameMode::Normal => "The fish dating experience as intended.",
+            GameMode::Hardcore => "Fish fight harder and are slower to fall for you.",
+        }
+    }
+
+    /// Scale an affection gain for this mode. Losses are left alone.
+    pub fn scale_gain(&self, amount: i32) -> i32 {
+        let multiplier = match self {
+            GameMode::Casual => 1.5,
+            GameMode::Normal => return amount,
+            GameMode::Hardcore => 0.75,
+        };
+        if amount <= 0 {
+            return amount;
+        }
+        ((amount as f32 * multiplier).round() as i32).max(1)
+    }
+
+    /// Multiplier on how hard fish pull in the minigame.
+    pub fn aggression_multiplier(&self) -> f32 {
+        match self {
+            GameMode::Hardcore => 1.3,
+            _ => 1.0,
+        }
+    }
+
+    /// Whether drifting to the edge of the meter snaps the line.
+    pub fn line_snaps(&self) -> bool {
+        *self != GameMode::Casual
+    }
+
+    /// Whether the fish briefly slows down when the line is about to snap.
+    pub fn clutch_time(&self) -> bool {
+        *self != GameMode::Hardcore
+    }
+
+    /// Whether fish that keep getting away ease up, unless the settings
+    /// say otherwise.
+    pub fn assist_by_default(&self) -> bool {
+        *self != GameMode::Hardcore
+    }
 }
 
 /// A fish the player has caught.
@@ -187,23 +374,127 @@ pub struct CaughtFish {
     pub id: FishId,
     pub caught_at: String,
     pub size: FishSize,
+    /// Rare color variant.
+    #[serde(default)]
+    pub shiny: bool,
+    /// Day of the catch. 0 for catches from saves that didn't record it.
+    #[serde(default)]
+    pub caught_on_day: u32,
 }
 
-/// Relationship level descriptions.
-pub fn relationship_label(score: i32) -> &'static str {
-    match score {
-        ..=0 => "Stranger",
-        1..=5 => "Acquaintance",
-        6..=15 => "Friend",
-        16..=25 => "Close Friend",
-        26..=40 => "Romantic Interest",
-        41.. => "Soulmate",
+/// Average affection per date choice at or above which chemistry is High.
+const HIGH_CHEMISTRY: f32 = 4.0;
+/// Average affection per date choice below which chemistry is Low.
+const LOW_CHEMISTRY: f32 = 2.5;
+
+/// How well the player's date choices have landed with a fish.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Chemistry {
+    /// No date choices made with this fish yet.
+    Unknown,
+    Low,
+    Medium,
+    High,
+}
+
+impl Chemistry {
+    /// Chemistry for an average affection per choice.
+    pub fn from_average(average: f32) -> Self {
+        if average >= HIGH_CHEMISTRY {
+            Chemistry::High
+        } else if average < LOW_CHEMISTRY {
+            Chemistry::Low
+        } else {
+            Chemistry::Medium
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Chemistry::Unknown => "Unknown",
+            Chemistry::Low => "Low",
+            Chemistry::Medium => "Medium",
+            Chemistry::High => "High",
+        }
     }
 }
 
+/// Relationship level descriptions, from the active [`tiers`].
+pub fn relationship_label(score: i32) -> &'static str {
+    tiers::current().label(score)
+}
+
+/// Lowest score of the relationship tier `score` is in. Decay never drops
+/// a fish below this.
+pub fn relationship_floor(score: i32) -> i32 {
+    tiers::current().floor(score)
+}
+
+/// Milestone scenes, named by the built-in tier floors they celebrate:
+/// Friend, Close Friend, Romantic Interest and Soulmate. The score each one
+/// plays at comes from the active [`tiers`].
+pub const MILESTONE_TIERS: [i32; 4] = [6, 16, 26, 41];
+
+/// Label of the tier the milestone scene `tier` celebrates, under the
+/// active [`tiers`].
+pub fn milestone_label(tier: i32) -> &'static str {
+    let tiers = tiers::current();
+    tiers.label(tiers.milestone_score(tier).unwrap_or(tier))
+}
+
+/// Key naming a milestone tier in plugin scripts, e.g. `close_friend`.
+/// Named after the built-in tiers, so scripts keep working with custom ones.
+pub fn milestone_key(tier: i32) -> String {
+    tiers::RelationshipTiers::default().label(tier).to_lowercase().replace(' ', "_")
+}
+
+/// A fish reaching a relationship tier for the first time, waiting to be
+/// shown as a short scene.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MilestoneEvent {
+    pub fish_id: FishId,
+    /// The milestone scene to play, one of `MILESTONE_TIERS`.
+    pub tier: i32,
+}
+
+/// Days a fish can go without a date before affection starts to decay.
+pub const DECAY_GRACE_DAYS: u32 = 7;
+/// Affection lost per day once a fish has been neglected past the grace period.
+pub const DECAY_PER_DAY: i32 = 1;
+
+/// Largest affection bonus a perfect-catch streak can grant.
+pub const MAX_STREAK_BONUS: i32 = 5;
+
+/// Current save format version. Bump when `PlayerState` changes shape and
+/// add a matching step to `save::migrate`.
+pub const SAVE_VERSION: u32 = 4;
+
+/// Name the player goes by until they pick one.
+pub const DEFAULT_PLAYER_NAME: &str = "You";
+
+/// Longest name accepted at New Game, in characters.
+pub const MAX_PLAYER_NAME_LEN: usize = 16;
+
+/// Fishing trips and dates the player can go on each day.
+pub const ACTIONS_PER_DAY: u32 = 3;
+
+/// Affection the closest fish gets on the first launch of a new day.
+pub const DAILY_LOGIN_BONUS: i32 = 1;
+
+fn default_actions() -> u32 {
+    ACTIONS_PER_DAY
+}
+
+fn default_player_name() -> String {
+    DEFAULT_PLAYER_NAME.to_string()
+}
+
 /// The complete player state.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerState {
+    /// Save format version this state was written with.
+    #[serde(default)]
+    pub version: u32,
     pub fish_collection: Vec<CaughtFish>,
     pub relationship_scores: HashMap<FishId, i32>,
     pub date_counts: HashMap<FishId, u32>,
@@ -212,17 +503,136 @@ pub struct PlayerState {
     /// Locally tracked achievement unlocks.
     #[serde(default)]
     pub achievements: UnlockedAchievements,
+    /// Total real time spent in-game, in seconds.
+    #[serde(default)]
+    pub playtime_seconds: f64,
+    /// Number of fish released back into the water.
+    #[serde(default)]
+    pub released_count: u32,
+    /// Consecutive Large catches. Resets on any smaller catch or lost fish.
+    #[serde(default)]
+    pub perfect_streak: u32,
+    /// Day each fish was last taken on a date.
+    #[serde(default)]
+    pub last_date_day: HashMap<FishId, u32>,
+    /// Last day relationship decay was applied, so each day only decays once.
+    #[serde(default)]
+    pub last_decay_day: u32,
+    /// Whether the fishing tutorial has been shown automatically.
+    #[serde(default)]
+    pub seen_tutorial: bool,
+    /// Difficulty picked at New Game.
+    #[serde(default)]
+    pub game_mode: GameMode,
+    /// Named flags set by dialogue choices, remembered across dates.
+    #[serde(default)]
+    pub memory_flags: HashSet<String>,
+    /// Hooked fights per fish, landed or not.
+    #[serde(default)]
+    pub catch_attempts: HashMap<FishId, u32>,
+    /// Hooked fights per fish that ended with the fish landed.
+    #[serde(default)]
+    pub catch_successes: HashMap<FishId, u32>,
+    /// The most recent fish that got away, and the day it happened.
+    #[serde(default)]
+    pub last_got_away: Option<(FishId, u32)>,
+    /// Fights lost in a row per fish this session, for the fishing assist.
+    #[serde(skip)]
+    pub recent_failures: HashMap<FishId, u32>,
+    /// Name picked at New Game, shown for the player's dialogue lines.
+    #[serde(default = "default_player_name")]
+    pub player_name: String,
+    /// Fishing trips and dates left today. Resting refills them.
+    #[serde(default = "default_actions")]
+    pub actions_remaining: u32,
+    /// Real-world date (`YYYY-MM-DD`, UTC) the save was last opened on.
+    #[serde(default)]
+    pub last_played_date: String,
+    /// Consecutive real-world days the save has been opened.
+    #[serde(default)]
+    pub login_streak: u32,
+    /// Milestone scenes earned but not shown yet, oldest first.
+    #[serde(default)]
+    pub pending_events: Vec<MilestoneEvent>,
+    /// Highest milestone tier each fish has triggered, so none repeat.
+    #[serde(default)]
+    pub milestones_reached: HashMap<FishId, i32>,
+    /// Affection from date choices per fish, before gift bonuses.
+    #[serde(default)]
+    pub choice_affection: HashMap<FishId, i32>,
+    /// Date choices made per fish.
+    #[serde(default)]
+    pub choice_counts: HashMap<FishId, u32>,
+    /// Fish landed at least once, even if later released. Others show as
+    /// silhouettes in the collection.
+    #[serde(default)]
+    pub discovered: HashSet<FishId>,
+}
+
+/// What the first launch on a new real-world day gave the player.
+#[derive(Debug, Clone)]
+pub struct DailyWelcome {
+    pub streak: u32,
+    /// Fish that got the daily affection bonus, if the player knows any.
+    pub bonus_fish: Option<FishId>,
+}
+
+/// Today's and yesterday's real-world dates as `YYYY-MM-DD`, in UTC.
+pub fn today_and_yesterday() -> (String, String) {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let today = (secs / 86_400) as i64;
+    (calendar_date(today), calendar_date(today - 1))
+}
+
+/// Format days since 1970-01-01 as a `YYYY-MM-DD` date.
+fn calendar_date(days: i64) -> String {
+    // Civil-from-days: count in 400-year eras starting on 0000-03-01
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
 }
 
 impl Default for PlayerState {
     fn default() -> Self {
         Self {
+            version: SAVE_VERSION,
             fish_collection: Vec::new(),
             relationship_scores: HashMap::new(),
             date_counts: HashMap::new(),
             current_day: 1,
             dates_completed: 0,
             achievements: UnlockedAchievements::default(),
+            playtime_seconds: 0.0,
+            released_count: 0,
+            perfect_streak: 0,
+            last_date_day: HashMap::new(),
+            last_decay_day: 0,
+            seen_tutorial: false,
+            game_mode: GameMode::Normal,
+            memory_flags: HashSet::new(),
+            catch_attempts: HashMap::new(),
+            catch_successes: HashMap::new(),
+            last_got_away: None,
+            recent_failures: HashMap::new(),
+            player_name: default_player_name(),
+            actions_remaining: ACTIONS_PER_DAY,
+            last_played_date: String::new(),
+            login_streak: 0,
+            pending_events: Vec::new(),
+            milestones_reached: HashMap::new(),
+            choice_affection: HashMap::new(),
+            choice_counts: HashMap::new(),
+            discovered: HashSet::new(),
         }
     }
 }
@@ -232,17 +642,90 @@ impl PlayerState {
         self.fish_collection.iter().any(|f| f.id == *fish_id)
     }
 
+    /// Whether `fish_id` has ever been landed.
+    pub fn is_discovered(&self, fish_id: &FishId) -> bool {
+        self.discovered.contains(fish_id)
+    }
+
+    /// Remember that `fish_id` has been landed.
+    pub fn discover(&mut self, fish_id: FishId) {
+        if self.discovered.insert(fish_id.clone()) {
+            tracing::info!("Discovered {}", fish_id.name());
+        }
+    }
+
     pub fn catch_count(&self, fish_id: &FishId) -> usize {
         self.fish_collection.iter().filter(|f| f.id == *fish_id).count()
     }
 
+    /// Whether a dialogue choice has set the memory flag `name`.
+    pub fn has_flag(&self, name: &str) -> bool {
+        self.memory_flags.contains(name)
+    }
+
+    /// Remember a dialogue choice for later dates.
+    pub fn set_flag(&mut self, name: &str) {
+        self.memory_flags.insert(name.to_string());
+    }
+
+    /// Largest size of `fish_id` ever kept.
+    pub fn best_size(&self, fish_id: &FishId) -> Option<FishSize> {
+        self.fish_collection
+            .iter()
+            .filter(|f| f.id == *fish_id)
+            .map(|f| f.size)
+            .max()
+    }
+
+    /// Percent of known fish caught at least once. Plugin fish only count
+    /// while their plugin is loaded.
+    pub fn completion_percent(&self, registry: &FishRegistry) -> f32 {
+        let all = FishId::all_with_plugins(registry);
+        if all.is_empty() {
+            return 0.0;
+        }
+        let caught = all.iter().filter(|f| self.has_caught(f)).count();
+        caught as f32 / all.len() as f32 * 100.0
+    }
+
     pub fn relationship(&self, fish_id: &FishId) -> i32 {
         self.relationship_scores.get(fish_id).copied().unwrap_or(0)
     }
 
+    /// Change affection with a fish. Gains are scaled by the game mode.
     pub fn add_affection(&mut self, fish_id: FishId, amount: i32) {
-        let score = self.relationship_scores.entry(fish_id).or_insert(0);
+        let amount = self.game_mode.scale_gain(amount);
+        let score = self.relationship_scores.entry(fish_id.clone()).or_insert(0);
+        let before = *score;
         *score = (*score + amount).max(0);
+        let after = *score;
+        self.queue_milestone(fish_id, before, after);
+    }
+
+    /// Queue a milestone scene if going from `before` to `after` crossed
+    /// into a tier this fish hasn't celebrated yet. Several tiers crossed
+    /// at once only play the highest.
+    fn queue_milestone(&mut self, fish_id: FishId, before: i32, after: i32) {
+        let Some((tier, _)) = tiers::current()
+            .milestones()
+            .into_iter()
+            .rev()
+            .find(|&(_, score)| before < score && after >= score)
+        else {
+            return;
+        };
+        let reached = self.milestones_reached.get(&fish_id).copied().unwrap_or(0);
+        if tier <= reached {
+            return;
+        }
+        tracing::info!("{} reached the {} milestone", fish_id.name(), milestone_label(tier));
+        self.milestones_reached.insert(fish_id.clone(), tier);
+        self.pending_events.push(MilestoneEvent { fish_id, tier });
+    }
+
+    /// Take the oldest milestone scene waiting to be shown.
+    pub fn take_pending_event(&mut self) -> Option<MilestoneEvent> {
+        (!self.pending_events.is_empty()).then(|| self.pending_events.remove(0))
     }
 
     pub fn date_count(&self, fish_id: &FishId) -> u32 {
@@ -254,17 +737,315 @@ impl PlayerState {
         *count += 1;
     }
 
-    pub fn add_catch(&mut self, fish_id: FishId, pond_name: &str, size: FishSize) {
+    /// Add a finished date's choices to the running chemistry totals.
+    pub fn record_choices(&mut self, fish_id: FishId, affection: i32, count: u32) {
+        if count == 0 {
+            return;
+        }
+        *self.choice_affection.entry(fish_id.clone()).or_insert(0) += affection;
+        *self.choice_counts.entry(fish_id).or_insert(0) += count;
+    }
+
+    /// How well date choices have gone with `fish_id` so far.
+    pub fn chemistry(&self, fish_id: &FishId) -> Chemistry {
+        let count = self.choice_counts.get(fish_id).copied().unwrap_or(0);
+        if count == 0 {
+            return Chemistry::Unknown;
+        }
+        let affection = self.choice_affection.get(fish_id).copied().unwrap_or(0);
+        Chemistry::from_average(affection as f32 / count as f32)
+    }
+
+    /// Remember that `fish_id` was dated on the current day.
+    pub fn record_date_day(&mut self, fish_id: FishId) {
+        self.last_date_day.insert(fish_id, self.current_day);
+    }
+
+    /// Use up one of today's actions. Returns false if none were left.
+    pub fn spend_action(&mut self) -> bool {
+        if self.actions_remaining == 0 {
+            return false;
+        }
+        self.actions_remaining -= 1;
+        true
+    }
+
+    /// Note that the save was opened on `today`. On the first launch of a
+    /// new day this grows or restarts the login streak and gives the closest
+    /// fish a little affection. The first launch ever just starts the streak.
+    pub fn record_login(&mut self, today: &str, yesterday: &str) -> Option<DailyWelcome> {
+        if self.last_played_date == today {
+            return None;
+        }
+        let first_launch = self.last_played_date.is_empty();
+        self.login_streak = if self.last_played_date == yesterday {
+            self.login_streak + 1
+        } else {
+            1
+        };
+        self.last_played_date = today.to_string();
+        if first_launch {
+            return None;
+        }
+
+        let bonus_fish = self.closest_fish().map(|(fish_id, _)| fish_id);
+        if let Some(fish_id) = &bonus_fish {
+            self.add_affection(fish_id.clone(), DAILY_LOGIN_BONUS);
+        }
+        Some(DailyWelcome {
+            streak: self.login_streak,
+            bonus_fish,
+        })
+    }
+
+    /// Sleep until tomorrow: the day advances and today's actions refill.
+    pub fn rest(&mut self) {
+        self.current_day += 1;
+        self.actions_remaining = ACTIONS_PER_DAY;
+        self.apply_decay(self.current_day);
+    }
+
+    /// Lower affection for fish that haven't been dated in over
+    /// `DECAY_GRACE_DAYS` days, one `DECAY_PER_DAY` per overdue day since
+    /// decay was last applied. A fish never decays out of its current
+    /// relationship tier.
+    pub fn apply_decay(&mut self, current_day: u32) {
+        for (fish_id, &last_date) in &self.last_date_day {
+            let decay_from = (last_date + DECAY_GRACE_DAYS).max(self.last_decay_day);
+            let overdue = current_day.saturating_sub(decay_from) as i32;
+            if overdue == 0 {
+                continue;
+            }
+            if let Some(score) = self.relationship_scores.get_mut(fish_id) {
+                let floor = relationship_floor(*score);
+                let decayed = (*score - overdue * DECAY_PER_DAY).max(floor);
+                if decayed < *score {
+                    tracing::info!("{} lost {} affection from neglect", fish_id.name(), *score - decayed);
+                    *score = decayed;
+                }
+            }
+        }
+        self.last_decay_day = self.last_decay_day.max(current_day);
+    }
+
+    pub fn add_catch(&mut self, fish_id: FishId, pond_name: &str, size: FishSize, shiny: bool) {
         self.fish_collection.push(CaughtFish {
             id: fish_id,
             caught_at: pond_name.to_string(),
             size,
+            shiny,
+            caught_on_day: self.current_day,
         });
     }
 
+    /// Indices of Small catches that can go without emptying a species. A
+    /// species with nothing bigger keeps one Small, a shiny if it has one.
+    fn releasable_smalls(&self) -> HashSet<usize> {
+        let mut keepers: HashMap<&FishId, usize> = HashMap::new();
+        for (i, fish) in self.fish_collection.iter().enumerate() {
+            if fish.size != FishSize::Small {
+                keepers.insert(&fish.id, i);
+                continue;
+            }
+            let keeper = keepers.entry(&fish.id).or_insert(i);
+            let current = &self.fish_collection[*keeper];
+            if current.size == FishSize::Small && !current.shiny && fish.shiny {
+                *keeper = i;
+            }
+        }
+
+        let kept: HashSet<usize> = keepers.into_values().collect();
+        self.fish_collection
+            .iter()
+            .enumerate()
+            .filter(|(i, fish)| fish.size == FishSize::Small && !kept.contains(i))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Number of catches `release_smalls` would let go.
+    pub fn releasable_small_count(&self) -> usize {
+        self.releasable_smalls().len()
+    }
+
+    /// Release every Small catch except the last of each species, so the
+    /// dex stays complete. Returns how many were released.
+    pub fn release_smalls(&mut self) -> usize {
+        let releasable = self.releasable_smalls();
+        let mut i = 0;
+        self.fish_collection.retain(|_| {
+            let keep = !releasable.contains(&i);
+            i += 1;
+            keep
+        });
+        self.released_count += releasable.len() as u32;
+        releasable.len()
+    }
+
+    /// Record the end of a fight with `fish_id` on the current day.
+    pub fn record_catch_attempt(&mut self, fish_id: &FishId, landed: bool) {
+        *self.catch_attempts.entry(fish_id.clone()).or_insert(0) += 1;
+        if landed {
+            *self.catch_successes.entry(fish_id.clone()).or_insert(0) += 1;
+            self.recent_failures.remove(fish_id);
+        } else {
+            self.last_got_away = Some((fish_id.clone(), self.current_day));
+            *self.recent_failures.entry(fish_id.clone()).or_insert(0) += 1;
+        }
+    }
+
+    /// Percentage of fights with `fish_id` that were won, if there were any.
+    pub fn catch_rate(&self, fish_id: &FishId) -> Option<f32> {
+        let attempts = self.catch_attempts.get(fish_id).copied().unwrap_or(0);
+        let successes = self.catch_successes.get(fish_id).copied().unwrap_or(0);
+        (attempts > 0).then(|| successes as f32 / attempts as f32 * 100.0)
+    }
+
+    /// Total fights won and fought, across every fish.
+    pub fn catch_totals(&self) -> (u32, u32) {
+        (
+            self.catch_successes.values().sum(),
+            self.catch_attempts.values().sum(),
+        )
+    }
+
+    /// Number of shiny fish in the collection.
+    pub fn shiny_count(&self) -> usize {
+        self.fish_collection.iter().filter(|f| f.shiny).count()
+    }
+
+    /// Whether the collection holds a shiny of this fish.
+    pub fn has_shiny(&self, fish_id: &FishId) -> bool {
+        self.fish_collection.iter().any(|f| f.shiny && f.id == *fish_id)
+    }
+
+    /// Number of Small, Medium and Large fish in the collection.
+    pub fn size_counts(&self) -> [usize; 3] {
+        let mut counts = [0; 3];
+        for fish in &self.fish_collection {
+            let slot = match fish.size {
+                FishSize::Small => 0,
+                FishSize::Medium => 1,
+                FishSize::Large => 2,
+            };
+            counts[slot] += 1;
+        }
+        counts
+    }
+
+    /// Catches per species in registry order, skipping species never caught.
+    pub fn species_breakdown(&self, registry: &FishRegistry) -> Vec<(String, usize)> {
+        let mut breakdown: Vec<(String, usize)> = Vec::new();
+        for fish_id in FishId::all_with_plugins(registry) {
+            let count = self.catch_count(&fish_id);
+            if count == 0 {
+                continue;
+            }
+            let species = fish_id.species_with_registry(registry);
+            match breakdown.iter_mut().find(|(s, _)| *s == species) {
+                Some((_, total)) => *total += count,
+                None => breakdown.push((species, count)),
+            }
+        }
+        breakdown
+    }
+
+    /// Highest relationship score with any fish.
+    pub fn highest_affection(&self) -> i32 {
+        self.relationship_scores.values().copied().max().unwrap_or(0)
+    }
+
+    /// Number of different plugin fish caught at least once.
+    pub fn plugin_fish_discovered(&self) -> usize {
+        let mut seen: Vec<&FishId> = Vec::new();
+        for fish in self.fish_collection.iter().filter(|f| f.id.is_plugin()) {
+            if !seen.contains(&&fish.id) {
+                seen.push(&fish.id);
+            }
+        }
+        seen.len()
+    }
+
+    /// The specimen `consume_duplicate` would give up: a Small or Medium,
+    /// non-shiny and Small first, and never the last one of its species.
+    fn spare_index(&self, fish_id: &FishId) -> Option<usize> {
+        if self.catch_count(fish_id) < 2 {
+            return None;
+        }
+        self.fish_collection
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| f.id == *fish_id && f.size != FishSize::Large)
+            .min_by_key(|(_, f)| (f.shiny, f.size))
+            .map(|(i, _)| i)
+    }
+
+    /// Size of the spare `fish_id` that could be offered on a date, if any.
+    pub fn spare_size(&self, fish_id: &FishId) -> Option<FishSize> {
+        self.spare_index(fish_id).map(|i| self.fish_collection[i].size)
+    }
+
+    /// Give up one spare `fish_id` from the collection. Returns false if
+    /// there isn't one, so the last of a species always stays.
+    pub fn consume_duplicate(&mut self, fish_id: &FishId) -> bool {
+        match self.spare_index(fish_id) {
+            Some(i) => {
+                self.fish_collection.remove(i);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Gift items collected from catches, in size order without duplicates.
+    pub fn gift_items(&self) -> Vec<&'static str> {
+        let mut items = Vec::new();
+        for size in [FishSize::Small, FishSize::Medium, FishSize::Large] {
+            if self
+                .fish_collection
+                .iter()
+                .any(|f| f.size.gift_item() == size.gift_item())
+            {
+                items.push(size.gift_item());
+            }
+        }
+        items
+    }
+
+    /// Update the perfect-catch streak for a new catch. Returns the bonus
+    /// affection earned, which grows by one per extra Large catch in a row.
+    pub fn record_catch_streak(&mut self, size: FishSize) -> i32 {
+        if matches!(size, FishSize::Large) {
+            self.perfect_streak += 1;
+            (self.perfect_streak as i32 - 1).clamp(0, MAX_STREAK_BONUS)
+        } else {
+            self.perfect_streak = 0;
+            0
+        }
+    }
+
+    /// Total playtime formatted as `HH:MM:SS`.
+    pub fn playtime_label(&self) -> String {
+        let total = self.playtime_seconds as u64;
+        format!("{:02}:{:02}:{:02}", total / 3600, (total / 60) % 60, total % 60)
+    }
+
     /// Check if the player has won (soulmate with any fish).
     pub fn has_won(&self) -> bool {
-        self.relationship_scores.values().any(|&s| s >= 41)
+        let threshold = tiers::current().win_threshold();
+        self.relationship_scores.values().any(|&s| s >= threshold)
+    }
+
+    /// Every fish the player has reached soulmate status with, closest first.
+    pub fn soulmates(&self) -> Vec<(FishId, i32)> {
+        let mut soulmates: Vec<(FishId, i32)> = self
+            .relationship_scores
+            .iter()
+            .filter(|(_, score)| **score >= tiers::current().win_threshold())
+            .map(|(id, score)| (id.clone(), *score))
+            .collect();
+        soulmates.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.name().cmp(b.0.name())));
+        soulmates
     }
 
     /// Get the fish the player is closest to (if any).
@@ -274,4 +1055,116 @@ impl PlayerState {
             .max_by_key(|(_, score)| **score)
             .map(|(id, score)| (id.clone(), *score))
     }
+
+    /// Computed stats for `--export-stats`, one entry per known fish.
+    pub fn export_summary(&self, registry: &FishRegistry) -> StatsSummary {
+        let fish = FishId::all_with_plugins(registry)
+            .into_iter()
+            .map(|fish_id| FishSummary {
+                id: fish_id.export_id(),
+                name: fish_id.name_with_registry(registry),
+                caught: self.catch_count(&fish_id),
+                attempts: self.catch_attempts.get(&fish_id).copied().unwrap_or(0),
+                landed: self.catch_successes.get(&fish_id).copied().unwrap_or(0),
+                catch_rate: self.catch_rate(&fish_id),
+                best_size: self.best_size(&fish_id),
+                shiny: self.has_shiny(&fish_id),
+                affection: self.relationship(&fish_id),
+                relationship: relationship_label(self.relationship(&fish_id)),
+                dates: self.date_count(&fish_id),
+            })
+            .collect();
+
+        let mut achievements: Vec<String> = self.achievements.ids.iter().cloned().collect();
+        achievements.sort();
+
+        StatsSummary {
+            format_version: STATS_FORMAT_VERSION,
+            player_name: self.player_name.clone(),
+            game_mode: self.game_mode,
+            day: self.current_day,
+            playtime_seconds: self.playtime_seconds,
+            dates_completed: self.dates_completed,
+            fish_released: self.released_count,
+            dex_percent: self.completion_percent(registry),
+            achievements,
+            fish,
+        }
+    }
+}
+
+/// Version of the `--export-stats` format. Bump when a field changes
+/// meaning or goes away; new fields don't need a bump.
+pub const STATS_FORMAT_VERSION: u32 = 1;
+
+/// Summary of a save written by `--export-stats`. Unlike the save file this
+/// is only ever written, so its shape stays stable for scripts reading it.
+#[derive(Debug, Serialize)]
+pub struct StatsSummary {
+    pub format_version: u32,
+    pub player_name: String,
+    pub game_mode: GameMode,
+    pub day: u32,
+    pub playtime_seconds: f64,
+    pub dates_completed: u32,
+    pub fish_released: u32,
+    /// Share of known species caught at least once, 0 to 100.
+    pub dex_percent: f32,
+    /// Unlocked achievement IDs, sorted.
+    pub achievements: Vec<String>,
+    pub fish: Vec<FishSummary>,
+}
+
+/// Per-fish entry in a `StatsSummary`.
+#[derive(Debug, Serialize)]
+pub struct FishSummary {
+    /// `bubbles`, `marina`, `gill`, or the plugin's fish ID.
+    pub id: String,
+    pub name: String,
+    /// Fish of this species in the collection now.
+    pub caught: usize,
+    /// Fights with this fish, landed or not.
+    pub attempts: u32,
+    pub landed: u32,
+    /// Percentage of fights landed, `null` before the first fight.
+    pub catch_rate: Option<f32>,
+    pub best_size: Option<FishSize>,
+    pub shiny: bool,
+    pub affection: i32,
+    pub relationship: &'static str,
+    pub dates: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A player who last dated Bubbles on day 1, with `score` affection.
+    fn dated_on_day_one(score: i32) -> PlayerState {
+        let mut player = PlayerState::default();
+        player.relationship_scores.insert(FishId::Bubbles, score);
+        player.last_date_day.insert(FishId::Bubbles, 1);
+        player.last_decay_day = 1;
+        player
+    }
+
+    #[test]
+    fn neglect_decays_affection_after_the_grace_period() {
+        let mut player = dated_on_day_one(40);
+        player.apply_decay(20);
+        // Decay starts on day 1 + DECAY_GRACE_DAYS
+        let overdue = (20 - (1 + DECAY_GRACE_DAYS)) as i32;
+        assert_eq!(player.relationship(&FishId::Bubbles), 40 - overdue * DECAY_PER_DAY);
+
+        // Checking again the same day takes nothing more
+        player.apply_decay(20);
+        assert_eq!(player.relationship(&FishId::Bubbles), 40 - overdue * DECAY_PER_DAY);
+    }
+
+    #[test]
+    fn decay_stops_at_the_tier_floor() {
+        let mut player = dated_on_day_one(30);
+        player.apply_decay(20);
+        assert_eq!(player.relationship(&FishId::Bubbles), relationship_floor(30));
+    }
 }
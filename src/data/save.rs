@@ -1,37 +1,284 @@
 //! Save/load functionality using JSON files.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use crate::data::PlayerState;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
 
-fn save_path() -> PathBuf {
+use crate::data::{FishId, PlayerState, SAVE_VERSION};
+use crate::plugins::FishRegistry;
+
+/// Number of save slots.
+pub const SLOT_COUNT: usize = 3;
+
+/// Directory holding the save file and other per-user data.
+pub fn data_dir() -> PathBuf {
     let dir = dirs_next::data_local_dir()
         .unwrap_or_else(|| PathBuf::from("."))
         .join("cult-papa-fish-dating-simulator");
     std::fs::create_dir_all(&dir).ok();
-    dir.join("save.json")
+    dir
+}
+
+fn save_path(slot: usize) -> PathBuf {
+    data_dir().join(format!("save_{}.json", slot))
+}
+
+/// Move a single-slot `save.json` from older versions into slot 0.
+pub fn migrate_legacy_save() {
+    let legacy = data_dir().join("save.json");
+    let slot0 = save_path(0);
+    if legacy.exists() && !slot0.exists() {
+        match std::fs::rename(&legacy, &slot0) {
+            Ok(()) => tracing::info!("Moved legacy save to {}", slot0.display()),
+            Err(e) => tracing::warn!("Failed to move legacy save: {}", e),
+        }
+    }
 }
 
 /// Save the player state to disk.
-pub fn save_game(state: &PlayerState) -> Result<(), String> {
-    let path = save_path();
+pub fn save_game(slot: usize, state: &PlayerState) -> Result<(), String> {
+    let path = save_path(slot);
     let json = serde_json::to_string_pretty(state).map_err(|e| e.to_string())?;
     std::fs::write(&path, json).map_err(|e| e.to_string())?;
     tracing::info!("Game saved to {}", path.display());
     Ok(())
 }
 
-/// Load the player state from disk.
-pub fn load_game() -> Option<PlayerState> {
-    let path = save_path();
-    if !path.exists() {
+/// Write the stats summary of the save in `slot` to `path` as JSON.
+pub fn export_stats(slot: usize, registry: &FishRegistry, path: &Path) -> Result<(), String> {
+    let state = load_game(slot).ok_or_else(|| format!("no save in slot {}", slot + 1))?;
+    let summary = state.export_summary(registry);
+    let json = serde_json::to_string_pretty(&summary).map_err(|e| e.to_string())?;
+    std::fs::write(path, json).map_err(|e| format!("{}: {}", path.display(), e))?;
+    tracing::info!("Stats for slot {} exported to {}", slot + 1, path.display());
+    Ok(())
+}
+
+/// Load the player state from disk, migrating older save formats.
+///
+/// A save that can't be read or parsed is copied to `save_N.json.bak` so
+/// progress isn't silently lost when we fall back to a new game.
+pub fn load_game(slot: usize) -> Option<PlayerState> {
+    if !save_exists(slot) {
         return None;
     }
-    let json = std::fs::read_to_string(&path).ok()?;
-    serde_json::from_str(&json).ok()
+    let path = save_path(slot);
+    match read_save(&path) {
+        Ok(state) => Some(state),
+        Err(e) => {
+            tracing::error!("Failed to load save {}: {}", path.display(), e);
+            backup_broken_save(&path);
+            None
+        }
+    }
+}
+
+/// Read and migrate the save at `path` in memory, without touching
+/// anything on disk.
+fn read_save(path: &Path) -> Result<PlayerState, String> {
+    let json = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    parse_save(&json)
+}
+
+/// Parse save JSON of any supported version.
+fn parse_save(json: &str) -> Result<PlayerState, String> {
+    serde_json::from_str::<Value>(json)
+        .map_err(|e| e.to_string())
+        .and_then(migrate)
+        .and_then(|value| serde_json::from_value(value).map_err(|e| e.to_string()))
+}
+
+/// Upgrade raw save JSON from any older version to `SAVE_VERSION`.
+fn migrate(mut value: Value) -> Result<Value, String> {
+    let obj = value
+        .as_object_mut()
+        .ok_or_else(|| "save is not a JSON object".to_string())?;
+    let version = obj.get("version").and_then(Value::as_u64).unwrap_or(0) as u32;
+
+    if version > SAVE_VERSION {
+        return Err(format!(
+            "save version {} is newer than supported version {}",
+            version, SAVE_VERSION
+        ));
+    }
+
+    if version < 1 {
+        // v0 -> v1: fill in fields added after the first release
+        obj.entry("achievements").or_insert_with(|| json!({ "ids": [] }));
+        obj.entry("playtime_seconds").or_insert_with(|| json!(0.0));
+        obj.entry("released_count").or_insert_with(|| json!(0));
+        if let Some(fish) = obj.get_mut("fish_collection").and_then(Value::as_array_mut) {
+            for caught in fish.iter_mut().filter_map(Value::as_object_mut) {
+                caught.entry("shiny").or_insert_with(|| json!(false));
+            }
+        }
+        tracing::info!("Migrated save from version 0 to 1");
+    }
+
+    if version < 2 {
+        // v1 -> v2: start the decay clock today for every fish already dated
+        let today = obj.get("current_day").cloned().unwrap_or_else(|| json!(1));
+        let last_date_day: serde_json::Map<String, Value> = obj
+            .get("date_counts")
+            .and_then(Value::as_object)
+            .map(|counts| counts.keys().map(|id| (id.clone(), today.clone())).collect())
+            .unwrap_or_default();
+        obj.entry("last_date_day").or_insert_with(|| Value::Object(last_date_day));
+        obj.entry("last_decay_day").or_insert(today);
+        tracing::info!("Migrated save from version 1 to 2");
+    }
+
+    if version < 3 {
+        // v2 -> v3: players who have already caught something skip the tutorial
+        let has_caught = obj
+            .get("fish_collection")
+            .and_then(Value::as_array)
+            .is_some_and(|fish| !fish.is_empty());
+        obj.entry("seen_tutorial").or_insert(json!(has_caught));
+        tracing::info!("Migrated save from version 2 to 3");
+    }
+
+    if version < 4 {
+        // v3 -> v4: everything in the collection counts as discovered
+        let discovered: Vec<Value> = obj
+            .get("fish_collection")
+            .and_then(Value::as_array)
+            .map(|fish| fish.iter().filter_map(|f| f.get("id").cloned()).collect())
+            .unwrap_or_default();
+        obj.entry("discovered").or_insert(Value::Array(discovered));
+        tracing::info!("Migrated save from version 3 to 4");
+    }
+
+    obj.insert("version".to_string(), json!(SAVE_VERSION));
+    Ok(value)
+}
+
+/// Copy an unreadable save aside so it can be recovered by hand.
+fn backup_broken_save(path: &Path) {
+    let backup = path.with_extension("json.bak");
+    match std::fs::copy(path, &backup) {
+        Ok(_) => tracing::warn!("Backed up unreadable save to {}", backup.display()),
+        Err(e) => tracing::error!("Failed to back up save to {}: {}", backup.display(), e),
+    }
+}
+
+/// Check if a slot holds a save. Deleted slots leave an empty marker file.
+pub fn save_exists(slot: usize) -> bool {
+    std::fs::metadata(save_path(slot)).is_ok_and(|m| m.len() > 0)
+}
+
+/// Clear a slot by overwriting it with an empty marker.
+pub fn delete_slot(slot: usize) -> Result<(), String> {
+    std::fs::write(save_path(slot), "").map_err(|e| e.to_string())?;
+    tracing::info!("Deleted save slot {}", slot);
+    Ok(())
+}
+
+/// Summary of a save slot for the slot picker.
+#[derive(Debug, Clone, Default)]
+pub struct SlotInfo {
+    /// Whether the slot holds a save.
+    pub occupied: bool,
+    pub day: u32,
+    pub fish_count: usize,
+    /// Fish with the highest relationship score, if any.
+    pub closest_fish: Option<FishId>,
+}
+
+/// Summarize every save slot. Read-only: a save that can't be read shows
+/// as empty here and is only backed up once the slot is actually loaded.
+pub fn list_slots() -> [SlotInfo; SLOT_COUNT] {
+    std::array::from_fn(|slot| {
+        let state = save_exists(slot).then(|| read_save(&save_path(slot)).ok()).flatten();
+        match state {
+            Some(state) => SlotInfo {
+                occupied: true,
+                day: state.current_day,
+                fish_count: state.fish_collection.len(),
+                closest_fish: state.closest_fish().map(|(id, _)| id),
+            },
+            None => SlotInfo::default(),
+        }
+    })
+}
+
+/// Marker for a date in progress, so a crash doesn't lose what it earned.
+///
+/// The dialogue position isn't kept: a resumed date starts from the top with
+/// the affection earned so far carried over.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DateResume {
+    /// Save slot the date was started from.
+    pub slot: usize,
+    pub fish_id: FishId,
+    /// Dates with this fish before this one, to spot a stale marker.
+    pub date_number: u32,
+    /// Affection from choices so far, before the gift bonus.
+    pub affection: i32,
+    /// Whether the fish was given its favorite gift before the date.
+    pub favorite_gift: bool,
+}
+
+fn resume_path() -> PathBuf {
+    data_dir().join("resume.json")
+}
+
+/// Write the in-progress date marker.
+pub fn save_resume(resume: &DateResume) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(resume).map_err(|e| e.to_string())?;
+    std::fs::write(resume_path(), json).map_err(|e| e.to_string())
+}
+
+/// The in-progress date marker, if a date was interrupted.
+pub fn load_resume() -> Option<DateResume> {
+    let json = std::fs::read_to_string(resume_path()).ok()?;
+    serde_json::from_str(&json)
+        .inspect_err(|e| tracing::warn!("Ignoring unreadable resume.json: {}", e))
+        .ok()
+}
+
+/// Remove the in-progress date marker once the date is over.
+pub fn clear_resume() {
+    let path = resume_path();
+    if path.exists()
+        && let Err(e) = std::fs::remove_file(&path)
+    {
+        tracing::warn!("Failed to remove {}: {}", path.display(), e);
+    }
 }
 
-/// Check if a save file exists.
-pub fn save_exists() -> bool {
-    save_path().exists()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A save from the first release: no `version`, no `achievements`.
+    const V0_SAVE: &str = r#"{
+        "fish_collection": [
+            { "id": "Bubbles", "caught_at": "Sunny Shallows", "size": "Medium" }
+        ],
+        "relationship_scores": { "Bubbles": 7 },
+        "date_counts": { "Bubbles": 2 },
+        "current_day": 5,
+        "dates_completed": 2
+    }"#;
+
+    #[test]
+    fn v0_save_migrates() {
+        let state = parse_save(V0_SAVE).expect("v0 save loads");
+        assert_eq!(state.version, SAVE_VERSION);
+        assert!(state.achievements.ids.is_empty());
+        assert_eq!(state.relationship(&FishId::Bubbles), 7);
+        assert!(!state.fish_collection[0].shiny);
+        assert_eq!(state.last_date_day.get(&FishId::Bubbles), Some(&5));
+        assert_eq!(state.last_decay_day, 5);
+        assert!(state.seen_tutorial);
+        assert!(state.discovered.contains(&FishId::Bubbles));
+    }
+
+    #[test]
+    fn newer_save_is_rejected() {
+        let json = format!(r#"{{ "version": {} }}"#, SAVE_VERSION + 1);
+        assert!(parse_save(&json).is_err());
+    }
 }
@@ -0,0 +1,208 @@
+//! Relationship tiers: the affection score each label starts at, and the
+//! score that wins the game.
+//!
+//! The built-in tiers run from Stranger to Soulmate. A
+//! `relationship_tiers.json` file can replace them for a longer or shorter
+//! progression, or to rename the tiers:
+//!
+//! ```json
+//! { "tiers": [[0, "Stranger"], [1, "Acquaintance"], [10, "Pal"]], "win_threshold": 10 }
+//! ```
+//!
+//! The first tier also covers every score below it. The top four tiers
+//! past the first two play the Friend, Close Friend, Romantic Interest and
+//! Soulmate milestone scenes, and the one playing Romantic Interest unlocks
+//! romantic dates. With fewer tiers the lowest of those scenes are skipped.
+
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+use crate::data::{MILESTONE_TIERS, save};
+
+/// The active tiers, set once at startup.
+static TIERS: OnceLock<RelationshipTiers> = OnceLock::new();
+
+/// Name of the tiers file, looked for in the working directory and then the
+/// data directory.
+const TIERS_FILE: &str = "relationship_tiers.json";
+
+/// Built-in tiers as (lowest score, label).
+const DEFAULT_TIERS: &[(i32, &str)] = &[
+    (0, "Stranger"),
+    (1, "Acquaintance"),
+    (6, "Friend"),
+    (16, "Close Friend"),
+    (26, "Romantic Interest"),
+    (41, "Soulmate"),
+];
+
+/// Built-in score for winning: reaching Soulmate with any fish.
+const DEFAULT_WIN_THRESHOLD: i32 = 41;
+
+/// The milestone scene whose tier unlocks romantic dates.
+const ROMANTIC_MILESTONE: i32 = 26;
+
+/// Tiers at the bottom that never play a milestone scene.
+const UNCELEBRATED_TIERS: usize = 2;
+
+/// Relationship tiers, lowest first.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RelationshipTiers {
+    /// (lowest score, label) for each tier, in ascending score order.
+    tiers: Vec<(i32, String)>,
+    /// Affection with any one fish that wins the game.
+    win_threshold: i32,
+}
+
+impl Default for RelationshipTiers {
+    fn default() -> Self {
+        Self {
+            tiers: DEFAULT_TIERS.iter().map(|&(min, label)| (min, label.to_string())).collect(),
+            win_threshold: DEFAULT_WIN_THRESHOLD,
+        }
+    }
+}
+
+impl RelationshipTiers {
+    /// Load `relationship_tiers.json`. A file with no tiers, or with tiers
+    /// out of ascending order, is rejected as a whole.
+    pub fn load() -> Result<Self, String> {
+        let path = [PathBuf::from(TIERS_FILE), save::data_dir().join(TIERS_FILE)]
+            .into_iter()
+            .find(|path| path.exists())
+            .ok_or_else(|| format!("{} not found", TIERS_FILE))?;
+
+        let json = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+        let tiers: Self = serde_json::from_str(&json).map_err(|e| format!("{}: {}", path.display(), e))?;
+        if tiers.tiers.is_empty() {
+            return Err(format!("{}: no tiers", path.display()));
+        }
+        if let Some(pair) = tiers.tiers.windows(2).find(|pair| pair[0].0 >= pair[1].0) {
+            return Err(format!(
+                "{}: tier '{}' must start above '{}'",
+                path.display(),
+                pair[1].1,
+                pair[0].1
+            ));
+        }
+        tracing::info!("Loaded {} relationship tiers from {}", tiers.tiers.len(), path.display());
+        Ok(tiers)
+    }
+
+    /// The tier `score` falls in.
+    fn tier(&self, score: i32) -> &(i32, String) {
+        self.tiers
+            .iter()
+            .rev()
+            .find(|(min, _)| score >= *min)
+            .unwrap_or(&self.tiers[0])
+    }
+
+    /// Label for the tier `score` falls in.
+    pub fn label(&self, score: i32) -> &str {
+        &self.tier(score).1
+    }
+
+    /// Lowest score of the tier `score` falls in.
+    pub fn floor(&self, score: i32) -> i32 {
+        self.tier(score).0
+    }
+
+    /// Affection with any one fish that wins the game.
+    pub fn win_threshold(&self) -> i32 {
+        self.win_threshold
+    }
+
+    /// Each milestone scene that can play, lowest first, as (the scene's
+    /// built-in tier floor from `MILESTONE_TIERS`, the score it plays at).
+    pub fn milestones(&self) -> Vec<(i32, i32)> {
+        let mut milestones: Vec<(i32, i32)> = MILESTONE_TIERS
+            .iter()
+            .rev()
+            .zip(self.tiers.iter().skip(UNCELEBRATED_TIERS).rev())
+            .map(|(&scene, &(score, _))| (scene, score))
+            .collect();
+        milestones.reverse();
+        milestones
+    }
+
+    /// Score the milestone scene named by built-in floor `scene` plays at,
+    /// if there are enough tiers for it.
+    pub fn milestone_score(&self, scene: i32) -> Option<i32> {
+        self.milestones()
+            .into_iter()
+            .find(|&(s, _)| s == scene)
+            .map(|(_, score)| score)
+    }
+
+    /// Affection that unlocks romantic dates. Without a tier for the
+    /// Romantic Interest scene, only a win does.
+    pub fn romantic_threshold(&self) -> i32 {
+        self.milestone_score(ROMANTIC_MILESTONE).unwrap_or(self.win_threshold)
+    }
+}
+
+/// Load the tiers file if there is one. Stays on the built-in tiers
+/// otherwise.
+pub fn init() {
+    let tiers = RelationshipTiers::load().unwrap_or_else(|e| {
+        tracing::info!("Using the built-in relationship tiers ({})", e);
+        RelationshipTiers::default()
+    });
+    if TIERS.set(tiers).is_err() {
+        tracing::warn!("Relationship tiers already initialized");
+    }
+}
+
+/// The active tiers, or the built-in ones before `init`.
+pub fn current() -> &'static RelationshipTiers {
+    TIERS.get_or_init(RelationshipTiers::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_tiers_keep_the_original_boundaries() {
+        let tiers = RelationshipTiers::default();
+        for (below, at, label) in [
+            (0, 1, "Acquaintance"),
+            (5, 6, "Friend"),
+            (15, 16, "Close Friend"),
+            (25, 26, "Romantic Interest"),
+            (40, 41, "Soulmate"),
+        ] {
+            assert_ne!(tiers.label(below), label);
+            assert_eq!(tiers.label(at), label);
+            assert_eq!(tiers.floor(at), at);
+        }
+        assert_eq!(tiers.label(-3), "Stranger");
+        assert_eq!(tiers.win_threshold(), 41);
+    }
+
+    #[test]
+    fn default_milestones_match_their_scenes() {
+        let tiers = RelationshipTiers::default();
+        assert_eq!(tiers.milestones(), vec![(6, 6), (16, 16), (26, 26), (41, 41)]);
+        assert_eq!(tiers.romantic_threshold(), 26);
+    }
+
+    #[test]
+    fn custom_tiers_move_the_milestones() {
+        let tiers = RelationshipTiers {
+            tiers: vec![
+                (0, "Stranger".to_string()),
+                (1, "Acquaintance".to_string()),
+                (10, "Pal".to_string()),
+                (20, "Partner".to_string()),
+            ],
+            win_threshold: 20,
+        };
+        assert_eq!(tiers.milestones(), vec![(26, 10), (41, 20)]);
+        assert_eq!(tiers.romantic_threshold(), 10);
+        assert_eq!(tiers.milestone_score(6), None);
+    }
+}
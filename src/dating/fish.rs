@@ -1,12 +1,18 @@
 //! Fish character rendering helpers.
 
+use std::collections::HashMap;
+
 use crate::ascii_art;
 use crate::data::FishId;
 use crate::plugins::FishRegistry;
 
-/// Get the large ASCII art for a fish by ID and mood.
-pub fn fish_art<'a>(id: &FishId, affection: i32, registry: &'a FishRegistry) -> String {
-    match id {
+/// Get the large ASCII art for a fish by ID and mood, animated over `time`.
+///
+/// Built-in fish do a two-frame idle wiggle; plugin fish cycle their
+/// `art_frames` if they define any.
+pub fn fish_art(id: &FishId, affection: i32, time: f32, registry: &FishRegistry) -> String {
+    let frame = (time.max(0.0) * ascii_art::IDLE_FRAME_RATE) as usize;
+    let art = match id {
         FishId::Bubbles => {
             if affection > 20 {
                 ascii_art::BUBBLES_HAPPY.to_string()
@@ -35,12 +41,58 @@ pub fn fish_art<'a>(id: &FishId, affection: i32, registry: &'a FishRegistry) ->
             }
         }
         FishId::Plugin(plugin_id) => {
-            if let Some(fish) = registry.get(plugin_id) {
-                fish.art_for_affection(affection).to_string()
+            return if let Some(fish) = registry.get(plugin_id) {
+                fish.animated_art(frame, affection).to_string()
             } else {
                 "  ><(((o>".to_string()
-            }
+            };
         }
+    };
+    ascii_art::idle_wiggle(&art, frame)
+}
+
+/// Get the fish art for a dialogue emotion ("happy", "angry", "shy"),
+/// animated like [`fish_art`]. Returns `None` for unknown emotions.
+pub fn fish_art_emotion(
+    id: &FishId,
+    emotion: &str,
+    time: f32,
+    registry: &FishRegistry,
+) -> Option<String> {
+    let frame = (time.max(0.0) * ascii_art::IDLE_FRAME_RATE) as usize;
+    let art = match (id, emotion) {
+        (FishId::Bubbles, "happy") => ascii_art::BUBBLES_HAPPY,
+        (FishId::Bubbles, "angry") => ascii_art::BUBBLES_ART,
+        (FishId::Bubbles, "shy") => ascii_art::BUBBLES_SHY,
+        (FishId::Marina, "happy") => ascii_art::MARINA_HAPPY,
+        (FishId::Marina, "angry") => ascii_art::MARINA_ANGRY,
+        (FishId::Marina, "shy") => ascii_art::MARINA_ART,
+        (FishId::Gill, "happy") => ascii_art::GILL_ART,
+        (FishId::Gill, "angry") => ascii_art::GILL_PUFFED,
+        (FishId::Gill, "shy") => ascii_art::GILL_SHY,
+        (FishId::Plugin(plugin_id), _) => {
+            let fish = registry.get(plugin_id)?;
+            return match emotion {
+                "happy" => Some(fish.art_happy.clone()),
+                "angry" => Some(fish.art_sad.clone()),
+                "shy" => Some(fish.art_neutral.clone()),
+                _ => None,
+            };
+        }
+        _ => return None,
+    };
+    Some(ascii_art::idle_wiggle(art, frame))
+}
+
+/// Minimum affection for gated dialogue choices, keyed by choice text.
+/// Only plugin fish gate choices.
+pub fn choice_requirements(id: &FishId, registry: &FishRegistry) -> HashMap<String, i32> {
+    match id {
+        FishId::Plugin(plugin_id) => registry
+            .get(plugin_id)
+            .map(|f| f.choice_requirements.clone())
+            .unwrap_or_default(),
+        _ => HashMap::new(),
     }
 }
 
@@ -1,6 +1,7 @@
 //! Dating phase: fish selection and dialogue scenes.
 
 pub mod fish;
+pub mod replay;
 pub mod scene;
 
 pub use scene::DatingState;
@@ -0,0 +1,180 @@
+//! Recordings of great dates, saved so they can be watched again.
+//!
+//! Dialogue is deterministic once the tree is built, so a recording only
+//! keeps what picks the tree (fish, date number, affection and memory flags
+//! at the start) and the index of each choice made.
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::data::save::data_dir;
+use crate::data::{FishId, PlayerState};
+use crate::plugins::FishRegistry;
+
+/// Affection a date must earn before it's kept as a replay.
+pub const REPLAY_MIN_AFFECTION: i32 = 8;
+
+/// Most replays kept on disk. The lowest scoring are dropped first.
+const MAX_REPLAYS: usize = 20;
+
+/// A finished date, reduced to what's needed to play it back.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DateReplay {
+    pub fish_id: FishId,
+    /// Dates with this fish before this one, which picks the dialogue.
+    pub date_number: u32,
+    /// Index of each choice picked, in order.
+    pub choices: Vec<usize>,
+    /// Affection with the fish when the date started.
+    #[serde(default)]
+    pub starting_affection: i32,
+    /// Memory flags the player had when the date started.
+    #[serde(default)]
+    pub flags: Vec<String>,
+    #[serde(default)]
+    pub player_name: String,
+    /// Affection the date earned, gift bonus included.
+    #[serde(default)]
+    pub affection_gained: i32,
+}
+
+impl DateReplay {
+    /// Start recording a date with `fish_id`.
+    pub fn start(fish_id: &FishId, player: &PlayerState) -> Self {
+        let mut flags: Vec<String> = player.memory_flags.iter().cloned().collect();
+        flags.sort();
+        Self {
+            fish_id: fish_id.clone(),
+            date_number: player.date_count(fish_id),
+            choices: Vec::new(),
+            starting_affection: player.relationship(fish_id),
+            flags,
+            player_name: player.player_name.clone(),
+            affection_gained: 0,
+        }
+    }
+
+    /// Whether the fish is still around to replay with. Plugin fish go
+    /// missing when their script is removed or turned off.
+    pub fn available(&self, registry: &FishRegistry) -> bool {
+        match &self.fish_id {
+            FishId::Plugin(id) => registry.get(id).is_some(),
+            _ => true,
+        }
+    }
+
+    /// A stand-in player as they were when the date started, so the same
+    /// dialogue tree gets built again.
+    pub fn player_state(&self) -> PlayerState {
+        PlayerState {
+            relationship_scores: HashMap::from([(self.fish_id.clone(), self.starting_affection)]),
+            date_counts: HashMap::from([(self.fish_id.clone(), self.date_number)]),
+            memory_flags: self.flags.iter().cloned().collect::<HashSet<_>>(),
+            player_name: self.player_name.clone(),
+            ..PlayerState::default()
+        }
+    }
+}
+
+fn replays_path(slot: usize) -> PathBuf {
+    data_dir().join(format!("replays_{}.json", slot))
+}
+
+/// Move the shared `replays.json` from older versions into slot 0.
+pub fn migrate_legacy_replays() {
+    let legacy = data_dir().join("replays.json");
+    let slot0 = replays_path(0);
+    if legacy.exists() && !slot0.exists() {
+        match std::fs::rename(&legacy, &slot0) {
+            Ok(()) => tracing::info!("Moved legacy replays to {}", slot0.display()),
+            Err(e) => tracing::warn!("Failed to move legacy replays: {}", e),
+        }
+    }
+}
+
+/// Saved replays for `slot`, best first. A missing or unreadable file gives
+/// none.
+pub fn load_replays(slot: usize) -> Vec<DateReplay> {
+    let path = replays_path(slot);
+    let Ok(json) = std::fs::read_to_string(&path) else {
+        return Vec::new();
+    };
+    match serde_json::from_str(&json) {
+        Ok(replays) => replays,
+        Err(e) => {
+            tracing::warn!("Ignoring unreadable replays in {}: {}", path.display(), e);
+            Vec::new()
+        }
+    }
+}
+
+/// Keep `replay` in `replays`, replacing a lower scoring one of the same
+/// date. Returns whether the list changed.
+fn keep_replay(replays: &mut Vec<DateReplay>, replay: DateReplay) -> bool {
+    let same_date =
+        |r: &DateReplay| r.fish_id == replay.fish_id && r.date_number == replay.date_number;
+    if let Some(existing) = replays.iter().find(|r| same_date(r))
+        && existing.affection_gained >= replay.affection_gained
+    {
+        return false;
+    }
+    replays.retain(|r| !same_date(r));
+    replays.push(replay);
+    replays.sort_by_key(|r| std::cmp::Reverse(r.affection_gained));
+    replays.truncate(MAX_REPLAYS);
+    true
+}
+
+/// Keep `replay` in `slot`'s `replays` and write them to disk.
+pub fn save_replay(
+    slot: usize,
+    replays: &mut Vec<DateReplay>,
+    replay: DateReplay,
+) -> Result<(), String> {
+    if !keep_replay(replays, replay) {
+        return Ok(());
+    }
+    let path = replays_path(slot);
+    let json = serde_json::to_string_pretty(replays).map_err(|e| e.to_string())?;
+    std::fs::write(&path, json).map_err(|e| e.to_string())?;
+    tracing::info!("Saved date replay to {}", path.display());
+    Ok(())
+}
+
+/// Drop every replay saved for `slot`, along with the slot's save.
+pub fn delete_replays(slot: usize) -> Result<(), String> {
+    match std::fs::remove_file(replays_path(slot)) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.to_string()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn replay(fish_id: FishId, date_number: u32, affection_gained: i32) -> DateReplay {
+        DateReplay {
+            fish_id,
+            date_number,
+            choices: Vec::new(),
+            starting_affection: 0,
+            flags: Vec::new(),
+            player_name: String::new(),
+            affection_gained,
+        }
+    }
+
+    #[test]
+    fn better_replay_of_the_same_date_replaces_the_old_one() {
+        let mut replays = Vec::new();
+        assert!(keep_replay(&mut replays, replay(FishId::Plugin("a".into()), 0, 10)));
+        assert!(!keep_replay(&mut replays, replay(FishId::Plugin("a".into()), 0, 9)));
+        assert!(keep_replay(&mut replays, replay(FishId::Plugin("a".into()), 0, 12)));
+        assert!(keep_replay(&mut replays, replay(FishId::Plugin("a".into()), 1, 15)));
+        let scores: Vec<i32> = replays.iter().map(|r| r.affection_gained).collect();
+        assert_eq!(scores, [15, 12]);
+    }
+}
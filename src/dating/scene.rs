@@ -1,51 +1,278 @@
 //! Date scene with dialogue integration.
 
+use std::collections::{HashMap, VecDeque};
+
 use sable_dialogue::prelude::*;
 use winit::keyboard::KeyCode;
 
 use crate::data::dialogues;
-use crate::data::FishId;
+use crate::data::save::DateResume;
+use crate::data::{DEFAULT_PLAYER_NAME, FishId, MilestoneEvent, PlayerState, milestone_label};
 use crate::dating::fish;
+use crate::dating::replay::DateReplay;
 use crate::game::GameScreen;
+use crate::i18n::{t, tf};
+use crate::input::{Action, KeyBindings};
 use crate::plugins::FishRegistry;
-use crate::render::{Colors, GameRenderer};
+use crate::render::Colors;
+use crate::settings::Settings;
+use crate::surface::{centered_start_col, DrawSurface};
 use crate::ui;
+use crate::ui::markup;
 use crate::ui::menu::SelectionMenu;
 
+/// Multiplier applied to a date's affection when the fish got its favorite gift.
+pub const FAVORITE_GIFT_MULTIPLIER: f32 = 1.5;
+
+/// Affection lost when the fish storms off before the date is over.
+pub const EARLY_END_PENALTY: i32 = 3;
+
+/// Token in dialogue text replaced by the player's name.
+const NAME_TOKEN: &str = "{name}";
+
+/// Most dialogue lines kept in the backlog.
+const MAX_HISTORY: usize = 200;
+
+/// Grid column and row where the fish's art starts.
+const FISH_ART_COL: f32 = 3.0;
+const FISH_ART_ROW: f32 = 3.0;
+/// Row the dialogue box starts on.
+const DIALOGUE_BOX_ROW: f32 = 14.0;
+/// Widest the dialogue box gets, and the narrowest it may shrink to.
+const DIALOGUE_BOX_WIDTH: usize = 56;
+const MIN_DIALOGUE_BOX_WIDTH: usize = 32;
+/// Rows at the bottom of the screen kept for the footer hints.
+const FOOTER_ROWS: f32 = 3.0;
+
+/// Seconds auto-play waits on a finished line before moving on.
+const AUTO_PLAY_DELAY: f32 = 1.5;
+
+/// Mood a date starts at, from 0.0 (going badly) to 1.0 (going great).
+const STARTING_MOOD: f32 = 0.5;
+/// Affection from a choice that leaves the mood where it was.
+const NEUTRAL_CHOICE_AFFECTION: f32 = 2.0;
+/// Mood change per point of affection above or below neutral.
+const MOOD_PER_AFFECTION: f32 = 0.08;
+
+/// Each fish's share of a group date's affection: half, rounded away from zero.
+pub fn group_share(affection: i32) -> i32 {
+    (affection as f32 / 2.0).round() as i32
+}
+
+/// The second fish on a group date.
+struct Partner {
+    fish_id: FishId,
+    name: String,
+    color: [f32; 4],
+}
+
+/// Date state captured just before a choice, so it can be undone.
+struct ChoiceSnapshot {
+    runner: DialogueRunner,
+    affection_gained: i32,
+    choices_made: u32,
+    current_mood: f32,
+    history: Vec<(String, String)>,
+    flags_set: Vec<String>,
+}
+
+/// Where the fish art and dialogue box go for a given screen and art size.
+struct DateLayout {
+    /// Lines of fish art to draw; taller art is clipped.
+    fish_rows: usize,
+    /// First column right of the fish art.
+    fish_right: f32,
+    box_col: f32,
+    box_width: usize,
+}
+
+impl DateLayout {
+    fn new(cols: usize, rows: f32, (art_width, art_height): (usize, usize)) -> Self {
+        let fish_right = FISH_ART_COL + art_width as f32;
+        let centered_width = DIALOGUE_BOX_WIDTH.min(cols.saturating_sub(2)).max(MIN_DIALOGUE_BOX_WIDTH);
+        let centered_col = (cols.saturating_sub(centered_width) / 2) as f32;
+        let rows_above_box = (DIALOGUE_BOX_ROW - FISH_ART_ROW) as usize;
+        let rows_to_footer = (rows - FOOTER_ROWS - FISH_ART_ROW).max(0.0) as usize;
+
+        // Art that reaches the box's rows pushes the box right of it, as long
+        // as there's room left for a readable box
+        let beside_col = fish_right + 2.0;
+        let beside_width = cols.saturating_sub(beside_col as usize + 1).min(DIALOGUE_BOX_WIDTH);
+        let overlaps = art_height > rows_above_box && centered_col < fish_right + 1.0;
+        if overlaps && beside_width >= MIN_DIALOGUE_BOX_WIDTH {
+            return Self {
+                fish_rows: art_height.min(rows_to_footer),
+                fish_right,
+                box_col: beside_col,
+                box_width: beside_width,
+            };
+        }
+
+        Self {
+            fish_rows: if overlaps { rows_above_box } else { rows_to_footer }.min(art_height),
+            fish_right,
+            box_col: centered_col,
+            box_width: centered_width,
+        }
+    }
+}
+
 /// State for an active date scene.
 pub struct DatingState {
     pub fish_id: FishId,
+    /// Fish color from the registry, so plugin fish keep their own color.
+    fish_color: [f32; 4],
     runner: DialogueRunner,
     /// Current text being displayed.
     current_text: String,
     /// Current speaker name.
     current_speaker: String,
+    /// Name shown for the player's lines and filled in for `{name}`.
+    player_name: String,
+    /// Emotion set by the current line, which picks the fish's portrait.
+    current_emotion: Option<String>,
     /// Choices menu (if in choice mode).
     choice_menu: Option<SelectionMenu>,
     /// Accumulated affection gained during this date.
     affection_gained: i32,
+    /// Affection carried over from an interrupted run of this date. The date
+    /// keeps whichever is higher, so replaying its choices doesn't count twice.
+    resumed_affection: Option<i32>,
+    /// Choices picked during this date.
+    choices_made: u32,
+    /// Spare fish offered before the date and the affection it's worth.
+    offering: Option<(FishId, i32)>,
+    /// Memory flags set by choices on this date, saved when it ends.
+    flags_set: Vec<String>,
+    /// How the conversation is going, 0.0 to 1.0. Moves with each choice.
+    current_mood: f32,
+    /// Whether the fish was given its favorite gift before the date.
+    favorite_gift: bool,
     /// Whether the date has ended.
     ended: bool,
-    /// Typewriter effect progress.
+    /// Whether a choice made the fish storm off before the date was over.
+    ended_early: bool,
+    /// Typewriter effect progress, in visible characters rather than bytes.
     typewriter_pos: usize,
-    typewriter_timer: f32,
+    /// Fractional characters revealed so far, advanced by the text speed.
+    typewriter_progress: f32,
+    /// Whether text lines advance on their own, stopping at the next choice.
+    auto_play: bool,
+    /// Seconds the current line has been fully shown while auto-playing.
+    auto_play_timer: f32,
+    /// Lines shown so far as (speaker, text), oldest first.
+    history: Vec<(String, String)>,
+    /// Backlog overlay scroll (entries back from the newest), `None` when closed.
+    backlog_scroll: Option<usize>,
+    /// Affection with this fish before the date started.
+    base_affection: i32,
+    /// Minimum affection for gated choices, keyed by choice text.
+    choice_requirements: HashMap<String, i32>,
+    /// State before the last choice, for a single step of undo.
+    undo: Option<Box<ChoiceSnapshot>>,
+    /// Tier reached, when this is a milestone scene rather than a date.
+    milestone: Option<i32>,
+    /// This date so far, kept as a replay if it goes well.
+    recording: DateReplay,
+    /// Choices still to pick when playing a replay back.
+    playback: Option<VecDeque<usize>>,
+    /// The other fish, when this is a group date.
+    partner: Option<Partner>,
 }
 
 impl DatingState {
-    pub fn new(fish_id: FishId, date_number: u32, registry: &FishRegistry) -> Self {
-        let tree = dialogues::build_dialogue(&fish_id, date_number, registry);
+    pub fn new(
+        fish_id: FishId,
+        favorite_gift: bool,
+        player: &PlayerState,
+        registry: &FishRegistry,
+    ) -> Self {
+        let tree = dialogues::build_dialogue(&fish_id, player, registry);
+        Self::with_tree(fish_id, tree, favorite_gift, player, registry)
+    }
+
+    /// Watch a recorded date again. Lines and choices play out on their
+    /// own, and nothing it earns is kept.
+    pub fn replay(replay: &DateReplay, registry: &FishRegistry) -> Self {
+        let player = replay.player_state();
+        let mut state = Self::new(replay.fish_id.clone(), false, &player, registry);
+        state.playback = Some(replay.choices.iter().copied().collect());
+        state
+    }
+
+    /// A group date with two fish. `first` hosts: the date is at their spot
+    /// and their affection gates choices. Both fish share what it earns.
+    pub fn group(first: FishId, second: FishId, player: &PlayerState, registry: &FishRegistry) -> Self {
+        let tree = dialogues::build_group_dialogue(&first, &second, player, registry);
+        let mut state = Self::with_tree(first, tree, false, player, registry);
+        state.partner = Some(Partner {
+            name: second.name_with_registry(registry),
+            color: second.color_with_registry(registry),
+            fish_id: second,
+        });
+        state
+    }
+
+    /// Restart an interrupted date from the top, keeping the affection it
+    /// had earned before the crash.
+    pub fn resume(resume: &DateResume, player: &PlayerState, registry: &FishRegistry) -> Self {
+        let mut state = Self::new(resume.fish_id.clone(), resume.favorite_gift, player, registry);
+        state.resumed_affection = Some(resume.affection);
+        state
+    }
+
+    /// A milestone scene rather than a date. It doesn't count as a date and
+    /// goes straight back to the menu when it ends.
+    pub fn milestone(event: &MilestoneEvent, player: &PlayerState, registry: &FishRegistry) -> Self {
+        let tree = dialogues::build_milestone(event, player, registry);
+        let mut state = Self::with_tree(event.fish_id.clone(), tree, false, player, registry);
+        state.milestone = Some(event.tier);
+        state
+    }
+
+    fn with_tree(
+        fish_id: FishId,
+        tree: DialogueTree,
+        favorite_gift: bool,
+        player: &PlayerState,
+        registry: &FishRegistry,
+    ) -> Self {
+        let affection = player.relationship(&fish_id);
         let runner = DialogueRunner::new(tree);
+        let choice_requirements = fish::choice_requirements(&fish_id, registry);
+        let recording = DateReplay::start(&fish_id, player);
 
         let mut state = Self {
+            fish_color: fish_id.color_with_registry(registry),
             fish_id,
             runner,
             current_text: String::new(),
             current_speaker: String::new(),
+            player_name: player.player_name.clone(),
+            current_emotion: None,
             choice_menu: None,
             affection_gained: 0,
+            resumed_affection: None,
+            choices_made: 0,
+            offering: None,
+            flags_set: Vec::new(),
+            current_mood: STARTING_MOOD,
+            favorite_gift,
             ended: false,
+            ended_early: false,
             typewriter_pos: 0,
-            typewriter_timer: 0.0,
+            typewriter_progress: 0.0,
+            auto_play: false,
+            auto_play_timer: 0.0,
+            history: Vec::new(),
+            backlog_scroll: None,
+            base_affection: affection,
+            choice_requirements,
+            undo: None,
+            milestone: None,
+            recording,
+            playback: None,
+            partner: None,
         };
         state.sync_state();
         state
@@ -53,100 +280,391 @@ impl DatingState {
 
     /// Synchronize rendering state from the dialogue runner.
     fn sync_state(&mut self) {
-        // Drain events for affection tracking
+        // Drain events for affection and memory flag tracking
         while let Some(event) = self.runner.poll_event() {
             if let DialogueEvent::VariableChanged { name, new_value, .. } = event {
                 if name == "affection" {
                     if let Ok(val) = new_value.parse::<i32>() {
                         self.affection_gained += val;
                     }
+                } else if name == dialogues::END_DATE_VARIABLE {
+                    self.ended_early = true;
+                } else if let Some(flag) = name.strip_prefix(dialogues::FLAG_VARIABLE_PREFIX) {
+                    if !self.flags_set.iter().any(|f| f == flag) {
+                        self.flags_set.push(flag.to_string());
+                    }
                 }
             }
         }
 
         match self.runner.current() {
             Some(DialogueState::Text {
-                speaker, text, ..
+                speaker, text, emotion, ..
             }) => {
-                self.current_speaker = speaker
-                    .map(|s| s.display_name().to_string())
-                    .unwrap_or_default();
-                self.current_text = text;
+                self.current_emotion = emotion;
+                self.current_speaker = match speaker {
+                    Some(s) if s.display_name() == DEFAULT_PLAYER_NAME => self.player_name.clone(),
+                    Some(s) => s.display_name().to_string(),
+                    None => String::new(),
+                };
+                self.current_text = self.fill_name(&text);
+                self.push_history(self.current_speaker.clone(), self.current_text.clone());
                 self.choice_menu = None;
                 self.typewriter_pos = 0;
-                self.typewriter_timer = 0.0;
+                self.typewriter_progress = 0.0;
+                self.auto_play_timer = 0.0;
             }
             Some(DialogueState::Choices {
                 prompt, choices, ..
             }) => {
-                self.current_text = prompt.unwrap_or_default();
+                self.current_text = self.fill_name(t(&prompt.unwrap_or_default()));
                 self.current_speaker = String::new();
                 let items: Vec<String> = choices
                     .iter()
                     .map(|c| c.text.clone())
                     .collect();
-                self.choice_menu = Some(SelectionMenu::new(items));
+                // With every option locked there's nothing to pick, so fall
+                // through along the first option as if it were auto-advancing
+                if !items.is_empty() && items.iter().all(|item| self.choice_locked(item)) {
+                    let _ = self.runner.select_choice(0);
+                    self.sync_state();
+                    return;
+                }
+                let mut menu = SelectionMenu::new(items);
+                if let Some(first_open) = menu.items.iter().position(|item| !self.choice_locked(item)) {
+                    menu.select(first_open);
+                }
+                self.choice_menu = Some(menu);
                 self.typewriter_pos = 0;
-                self.typewriter_timer = 0.0;
+                self.typewriter_progress = 0.0;
+                self.auto_play = false;
             }
             Some(DialogueState::End) | None => {
                 self.ended = true;
+                self.undo = None;
+                self.auto_play = false;
             }
             _ => {}
         }
     }
 
-    /// Get the affection gained during this date.
-    #[allow(dead_code)]
+    /// Get the affection gained during this date, including any gift bonus
+    /// and the penalty for the fish storming off. Can be negative.
     pub fn affection_gained(&self) -> i32 {
-        self.affection_gained
+        let choices = self.choice_affection();
+        let gained = if self.favorite_gift {
+            (choices as f32 * FAVORITE_GIFT_MULTIPLIER).round() as i32
+        } else {
+            choices
+        };
+        if self.ended_early { gained - EARLY_END_PENALTY } else { gained }
+    }
+
+    /// Affection from choices so far, before the gift bonus.
+    pub fn choice_affection(&self) -> i32 {
+        self.resumed_affection
+            .map_or(self.affection_gained, |carried| carried.max(self.affection_gained))
+    }
+
+    /// Whether the fish was given its favorite gift before the date.
+    pub fn has_favorite_gift(&self) -> bool {
+        self.favorite_gift
+    }
+
+    /// The other fish, when this is a group date.
+    pub fn partner(&self) -> Option<&FishId> {
+        self.partner.as_ref().map(|p| &p.fish_id)
+    }
+
+    /// Affection each fish gets from this date so far: all of it on a
+    /// regular date, a share of it on a group date.
+    fn affection_each(&self) -> i32 {
+        match self.partner {
+            Some(_) => group_share(self.affection_gained()),
+            None => self.affection_gained(),
+        }
+    }
+
+    /// Whether the partner on a group date is the one speaking.
+    fn partner_speaking(&self) -> bool {
+        self.partner.as_ref().is_some_and(|p| p.name == self.current_speaker)
+    }
+
+    /// Color for a speaker's name: the partner's on a group date, otherwise
+    /// the date's.
+    fn speaker_color(&self, speaker: &str) -> [f32; 4] {
+        match &self.partner {
+            Some(partner) if partner.name == speaker => partner.color,
+            _ => self.fish_color,
+        }
+    }
+
+    /// Whether this date was restarted after a crash.
+    pub fn is_resumed(&self) -> bool {
+        self.resumed_affection.is_some()
+    }
+
+    /// The choice menu when one is showing and ready for input.
+    pub fn active_choice_menu(&mut self, settings: &Settings) -> Option<&mut SelectionMenu> {
+        let waiting = settings.choices_wait_for_text && self.typing();
+        if self.ended || self.is_replay() || self.backlog_scroll.is_some() || waiting {
+            return None;
+        }
+        self.choice_menu.as_mut()
+    }
+
+    /// Whether a click should advance the dialogue like Enter does: on a
+    /// plain text line, or the end-of-date summary.
+    pub fn click_advances(&self) -> bool {
+        self.backlog_scroll.is_none() && self.choice_menu.is_none()
+    }
+
+    /// Affection with this fish right now, counting what this date has earned so far.
+    fn current_affection(&self) -> i32 {
+        self.base_affection + self.affection_gained()
+    }
+
+    /// Affection a choice needs before it can be picked, if it's gated.
+    fn choice_requirement(&self, text: &str) -> Option<i32> {
+        self.choice_requirements.get(text).copied()
+    }
+
+    /// Whether a choice is gated behind more affection than the player has.
+    fn choice_locked(&self, text: &str) -> bool {
+        self.choice_requirement(text)
+            .is_some_and(|required| self.current_affection() < required)
+    }
+
+    /// Move the mood by how much affection a choice gave.
+    fn nudge_mood(&mut self, affection: i32) {
+        let change = (affection as f32 - NEUTRAL_CHOICE_AFFECTION) * MOOD_PER_AFFECTION;
+        self.current_mood = (self.current_mood + change).clamp(0.0, 1.0);
+    }
+
+    /// Rewind to the last choice, taking back the affection it gave.
+    fn undo_choice(&mut self) {
+        let Some(snapshot) = self.undo.take() else {
+            return;
+        };
+        self.runner = snapshot.runner;
+        self.affection_gained = snapshot.affection_gained;
+        self.ended_early = false;
+        self.choices_made = snapshot.choices_made;
+        self.current_mood = snapshot.current_mood;
+        self.history = snapshot.history;
+        self.flags_set = snapshot.flags_set;
+        self.recording.choices.truncate(self.choices_made as usize);
+        self.sync_state();
+    }
+
+    /// Pick choice `idx` from the menu showing now.
+    fn pick_choice(&mut self, idx: usize, chosen: &str) {
+        if self.playback.is_none() {
+            self.undo = Some(Box::new(ChoiceSnapshot {
+                runner: self.runner.clone(),
+                affection_gained: self.affection_gained,
+                choices_made: self.choices_made,
+                current_mood: self.current_mood,
+                history: self.history.clone(),
+                flags_set: self.flags_set.clone(),
+            }));
+        }
+        self.push_history(self.player_name.clone(), self.fill_name(t(chosen)));
+        let before = self.affection_gained;
+        let _ = self.runner.select_choice(idx);
+        self.choices_made += 1;
+        self.recording.choices.push(idx);
+        self.sync_state();
+        self.nudge_mood(self.affection_gained - before);
     }
 
-    pub fn update(&mut self, dt: f32, key: Option<KeyCode>) -> Option<GameScreen> {
+    /// Bring a spare fish along, given to the date when it ends.
+    pub fn set_offering(&mut self, offering: Option<(FishId, i32)>) {
+        self.offering = offering;
+    }
+
+    /// Whether this is a milestone scene rather than a date.
+    pub fn is_milestone(&self) -> bool {
+        self.milestone.is_some()
+    }
+
+    /// Affection with the fish when the scene started.
+    pub fn starting_affection(&self) -> i32 {
+        self.base_affection
+    }
+
+    /// Whether this is a recorded date being played back.
+    pub fn is_replay(&self) -> bool {
+        self.playback.is_some()
+    }
+
+    fn date_result(&self) -> GameScreen {
+        if self.is_milestone() {
+            return GameScreen::MainMenu;
+        }
+        if self.is_replay() {
+            return GameScreen::Replays;
+        }
+        if let Some(partner) = &self.partner {
+            return GameScreen::GroupDateResult {
+                fish: [self.fish_id.clone(), partner.fish_id.clone()],
+                affection: self.affection_each(),
+                flags: self.flags_set.clone(),
+                ended_early: self.ended_early,
+            };
+        }
+        GameScreen::DateResult {
+            fish_id: self.fish_id.clone(),
+            affection: self.affection_gained(),
+            gift_bonus: self.favorite_gift,
+            flags: self.flags_set.clone(),
+            ended_early: self.ended_early,
+            choice_affection: self.choice_affection(),
+            choices: self.choices_made,
+            offering: self.offering.clone(),
+            replay: DateReplay {
+                affection_gained: self.affection_gained(),
+                ..self.recording.clone()
+            },
+        }
+    }
+
+    /// `text` with each `{name}` replaced by the player's name.
+    fn fill_name(&self, text: &str) -> String {
+        text.replace(NAME_TOKEN, &self.player_name)
+    }
+
+    /// Append a line to the backlog, dropping the oldest past the cap.
+    fn push_history(&mut self, speaker: String, text: String) {
+        self.history.push((speaker, text));
+        if self.history.len() > MAX_HISTORY {
+            self.history.remove(0);
+        }
+    }
+
+    /// Whether the current line is still being typed out.
+    fn typing(&self) -> bool {
+        self.typewriter_pos < markup::visible_len(&self.current_text)
+    }
+
+    pub fn update(
+        &mut self,
+        dt: f32,
+        key: Option<KeyCode>,
+        bindings: &KeyBindings,
+        settings: &mut Settings,
+    ) -> Option<GameScreen> {
+        // Live text speed adjustment
+        let speed_steps = match key {
+            Some(KeyCode::Equal | KeyCode::NumpadAdd) => 1.0,
+            Some(KeyCode::Minus | KeyCode::NumpadSubtract) => -1.0,
+            _ => 0.0,
+        };
+        if speed_steps != 0.0 {
+            settings.adjust_text_speed(speed_steps);
+            if let Err(e) = settings.save() {
+                tracing::warn!("Failed to save settings: {}", e);
+            }
+        }
+
         // Typewriter effect
-        self.typewriter_timer += dt;
-        let chars_per_sec = 30.0;
-        self.typewriter_pos = (self.typewriter_timer * chars_per_sec) as usize;
+        if settings.instant_text() {
+            self.typewriter_pos = markup::visible_len(&self.current_text);
+        } else {
+            self.typewriter_progress += dt * settings.text_speed;
+            self.typewriter_pos = self.typewriter_pos.max(self.typewriter_progress as usize);
+        }
+
+        if let Some(scroll) = self.backlog_scroll {
+            if let Some(k) = key {
+                match bindings.menu_action(k) {
+                    Some(Action::MenuUp) => {
+                        let max_scroll = self.history.len().saturating_sub(1);
+                        self.backlog_scroll = Some((scroll + 1).min(max_scroll));
+                    }
+                    Some(Action::MenuDown) => self.backlog_scroll = Some(scroll.saturating_sub(1)),
+                    Some(Action::Back) => self.backlog_scroll = None,
+                    _ if k == KeyCode::Tab => self.backlog_scroll = None,
+                    _ => {}
+                }
+            }
+            return None;
+        }
+        if key == Some(KeyCode::Tab) {
+            self.backlog_scroll = Some(0);
+            return None;
+        }
+
+        if self.is_replay() {
+            return self.update_playback(dt, key, bindings);
+        }
 
         if self.ended {
-            if let Some(KeyCode::Enter | KeyCode::Space) = key {
-                return Some(GameScreen::DateResult {
-                    fish_id: self.fish_id.clone(),
-                    affection: self.affection_gained,
-                });
+            if key.is_some_and(|k| bindings.is(Action::Confirm, k)) {
+                return Some(self.date_result());
             }
             return None;
         }
 
+        if key == Some(KeyCode::Backspace) {
+            self.undo_choice();
+            return None;
+        }
+
+        if self.choice_menu.is_none() {
+            if key == Some(KeyCode::KeyA) {
+                self.auto_play = !self.auto_play;
+                self.auto_play_timer = 0.0;
+                return None;
+            }
+            // Auto-play waits for the typewriter, then gives the line a moment
+            if self.auto_play && !self.typing() {
+                self.auto_play_timer += dt;
+                if self.auto_play_timer >= AUTO_PLAY_DELAY {
+                    let _ = self.runner.advance();
+                    self.sync_state();
+                    return None;
+                }
+            }
+        }
+
         if let Some(k) = key {
+            let prompt_typing = settings.choices_wait_for_text && self.typing();
             if let Some(ref mut menu) = self.choice_menu {
-                match k {
-                    KeyCode::ArrowUp | KeyCode::KeyW => menu.move_up(),
-                    KeyCode::ArrowDown | KeyCode::KeyS => menu.move_down(),
-                    KeyCode::Enter | KeyCode::Space => {
+                if prompt_typing {
+                    // Confirm finishes the prompt; navigation waits for it
+                    if bindings.is(Action::Confirm, k) {
+                        self.typewriter_pos = markup::visible_len(&self.current_text);
+                    }
+                    return None;
+                }
+                match bindings.menu_action(k) {
+                    Some(Action::MenuUp) => menu.move_up(),
+                    Some(Action::MenuDown) => menu.move_down(),
+                    Some(Action::Confirm) => {
                         let idx = menu.selected_index();
-                        let _ = self.runner.select_choice(idx);
-                        self.sync_state();
+                        let chosen = menu.items[idx].clone();
+                        if self.choice_locked(&chosen) {
+                            return None;
+                        }
+                        self.pick_choice(idx, &chosen);
                     }
                     _ => {}
                 }
             } else {
                 // Text node - advance on Enter/Space
-                match k {
-                    KeyCode::Enter | KeyCode::Space => {
+                match bindings.menu_action(k) {
+                    Some(Action::Confirm) => {
                         // If typewriter not done, skip to end
-                        if self.typewriter_pos < self.current_text.len() {
-                            self.typewriter_pos = self.current_text.len();
+                        if self.typing() {
+                            self.typewriter_pos = markup::visible_len(&self.current_text);
                         } else {
                             let _ = self.runner.advance();
                             self.sync_state();
                         }
                     }
-                    KeyCode::Escape => {
-                        return Some(GameScreen::DateResult {
-                            fish_id: self.fish_id.clone(),
-                            affection: self.affection_gained,
-                        });
+                    Some(Action::Back) => {
+                        return Some(self.date_result());
                     }
                     _ => {}
                 }
@@ -156,61 +674,164 @@ impl DatingState {
         None
     }
 
-    pub fn render(&self, renderer: &mut GameRenderer, affection_total: i32, _time: f32, registry: &FishRegistry) {
-        let location = fish::date_location(&self.fish_id, registry);
-        renderer.draw_centered(
-            &format!("=== Date at {} ===", location),
-            1.0,
-            Colors::PINK,
-        );
+    /// Play back a replay: each line and choice waits a moment, then moves
+    /// on. Enter skips ahead and Esc stops watching.
+    fn update_playback(
+        &mut self,
+        dt: f32,
+        key: Option<KeyCode>,
+        bindings: &KeyBindings,
+    ) -> Option<GameScreen> {
+        let action = key.and_then(|k| bindings.menu_action(k));
+        if action == Some(Action::Back) || (self.ended && action == Some(Action::Confirm)) {
+            return Some(self.date_result());
+        }
+        if self.ended {
+            return None;
+        }
+        if self.typing() {
+            if action == Some(Action::Confirm) {
+                self.typewriter_pos = markup::visible_len(&self.current_text);
+            }
+            return None;
+        }
+
+        self.auto_play_timer += dt;
+        if self.auto_play_timer < AUTO_PLAY_DELAY && action != Some(Action::Confirm) {
+            return None;
+        }
+        self.auto_play_timer = 0.0;
+
+        let Some(menu) = &mut self.choice_menu else {
+            let _ = self.runner.advance();
+            self.sync_state();
+            return None;
+        };
+        let next = self.playback.as_mut().and_then(|choices| choices.pop_front());
+        match next.and_then(|idx| Some((idx, menu.items.get(idx)?.clone()))) {
+            Some((idx, chosen)) => {
+                menu.select(idx);
+                self.pick_choice(idx, &chosen);
+            }
+            None => {
+                // Dialogue changed since the recording; stop where it diverges
+                tracing::warn!("Replay doesn't match the dialogue any more, stopping early");
+                self.ended = true;
+            }
+        }
+        None
+    }
+
+    pub fn render(
+        &self,
+        renderer: &mut dyn DrawSurface,
+        affection_total: i32,
+        time: f32,
+        registry: &FishRegistry,
+        settings: &Settings,
+    ) {
+        let title = match self.milestone {
+            Some(tier) => {
+                let name = self.fish_id.name_with_registry(registry);
+                tf("=== {}: {}! ===", &[&name, &t(milestone_label(tier))])
+            }
+            None if self.partner.is_some() => {
+                tf("=== Group date at {} ===", &[&fish::date_location(&self.fish_id, registry)])
+            }
+            None => tf("=== Date at {} ===", &[&fish::date_location(&self.fish_id, registry)]),
+        };
+        renderer.draw_centered(&title, 1.0, Colors::pink());
 
-        // Scene art
+        if let Some(scroll) = self.backlog_scroll {
+            self.render_backlog(renderer, scroll);
+            return;
+        }
+
+        // Fish art on the left side, measured so the rest of the scene can
+        // make room for wide or tall plugin art. On a group date it's
+        // whichever fish is talking.
+        let (shown_fish, shown_color) = match &self.partner {
+            Some(partner) if self.partner_speaking() => (&partner.fish_id, partner.color),
+            _ => (&self.fish_id, self.fish_color),
+        };
+        let fish_art_str = self
+            .current_emotion
+            .as_deref()
+            .and_then(|emotion| fish::fish_art_emotion(shown_fish, emotion, time, registry))
+            .unwrap_or_else(|| fish::fish_art(shown_fish, affection_total, time, registry));
+        let cols = renderer.screen_cols() as usize;
+        let layout = DateLayout::new(cols, renderer.screen_rows(), ui::art_size(&fish_art_str));
+
+        // Scene art, nudged right of the fish if they'd overlap
         let scene_art = fish::date_scene_art(&self.fish_id, registry);
-        renderer.draw_multiline_centered(&scene_art, 3.0, Colors::LIGHT_BLUE);
+        let (scene_width, _) = ui::art_size(&scene_art);
+        let scene_col = centered_start_col(cols as f32, scene_width as f32).max(layout.fish_right + 2.0);
+        renderer.draw_multiline_at_grid(&scene_art, scene_col, 3.0, Colors::light_blue());
 
-        // Fish art on the left side
-        let fish_art_str = fish::fish_art(&self.fish_id, affection_total, registry);
-        renderer.draw_multiline_at_grid(&fish_art_str, 3.0, 3.0, self.fish_id.color());
+        let fish_art_str = ui::clip_art_lines(&fish_art_str, layout.fish_rows);
+        renderer.draw_multiline_at_grid(&fish_art_str, FISH_ART_COL, FISH_ART_ROW, shown_color);
 
         // Hearts
-        let cols = renderer.screen_cols() as usize;
         ui::draw_hearts(
             renderer,
-            (cols / 2 - 8) as f32,
+            centered_start_col(cols as f32, 16.0),
             12.0,
-            affection_total + self.affection_gained,
+            affection_total + self.affection_each(),
             5,
         );
 
+        // Mood meter
+        let mood_color = if self.current_mood >= 0.66 {
+            Colors::green()
+        } else if self.current_mood >= 0.33 {
+            Colors::yellow()
+        } else {
+            Colors::red()
+        };
+        let mood_col = centered_start_col(cols as f32, 20.0);
+        renderer.draw_at_grid(t("Mood"), mood_col, 13.0, Colors::gray());
+        ui::draw_progress_bar(
+            renderer,
+            mood_col + 5.0,
+            13.0,
+            16,
+            self.current_mood,
+            mood_color,
+            Colors::dark_gray(),
+        );
+
         if self.ended {
-            renderer.draw_centered("Date over!", 14.0, Colors::YELLOW);
-            renderer.draw_centered(
-                &format!("Affection gained: +{}", self.affection_gained),
-                15.0,
-                Colors::PINK,
-            );
-            renderer.draw_centered("[Enter] Continue", 17.0, Colors::WHITE);
+            let title = if self.ended_early { "Date over... early." } else { "Date over!" };
+            renderer.draw_centered(t(title), 14.0, Colors::yellow());
+            renderer.draw_centered(&affection_change_line(self.affection_each()), 15.0, Colors::pink());
+            renderer.draw_centered(t("[Enter] Continue"), 17.0, Colors::white());
             return;
         }
 
         // Dialogue box — dynamically sized to fit content
-        let box_row = 14.0;
-        let box_width = 56;
+        let box_row = DIALOGUE_BOX_ROW;
+        let box_width = layout.box_width;
         let inner_width = box_width - 4; // 2 for border chars + 2 for padding
-        let box_col = ((cols.saturating_sub(box_width)) / 2) as f32;
+        let box_col = layout.box_col;
 
         if let Some(ref menu) = self.choice_menu {
-            // Wrap prompt text (if any)
+            // Wrap prompt text (if any), keeping the full text's line count
+            // so the box doesn't grow while the prompt is typed out
             let prompt_lines = if !self.current_text.is_empty() {
-                word_wrap(&self.current_text, inner_width)
+                markup::word_wrap(&self.current_text, inner_width)
             } else {
                 Vec::new()
             };
+            let visible_prompt_lines = if settings.choices_wait_for_text {
+                markup::word_wrap(self.visible_text(), inner_width)
+            } else {
+                prompt_lines.clone()
+            };
 
             // Wrap each choice item with "> " prefix space accounted for
             let choice_lines: Vec<String> = menu.items.iter().map(|item| {
                 // Each choice has "  " or "> " prefix = 2 chars
-                let wrapped = word_wrap(item, inner_width - 2);
+                let wrapped = markup::word_wrap(&self.fill_name(t(item)), inner_width - 2);
                 // For now take the first wrap line; multi-line choices are rare
                 wrapped.into_iter().next().unwrap_or_default()
             }).collect();
@@ -220,7 +841,7 @@ impl DatingState {
             let box_height = 2 + prompt_rows + choice_lines.len() + 1;
             let box_height = box_height.max(5); // minimum height
 
-            ui::draw_box(renderer, box_col, box_row, box_width, box_height, Colors::WHITE);
+            ui::draw_box(renderer, box_col, box_row, box_width, box_height, Colors::white());
 
             // Speaker name on top border
             if !self.current_speaker.is_empty() {
@@ -228,32 +849,46 @@ impl DatingState {
                     &format!(" {} ", self.current_speaker),
                     box_col + 2.0,
                     box_row,
-                    self.fish_id.color(),
+                    self.speaker_color(&self.current_speaker),
                 );
             }
 
             let mut content_row = box_row + 1.0;
 
             // Draw prompt lines
-            for line in &prompt_lines {
-                renderer.draw_at_grid(line, box_col + 2.0, content_row, Colors::GRAY);
-                content_row += 1.0;
-            }
+            markup::draw_lines(renderer, &visible_prompt_lines, box_col + 2.0, content_row, Colors::gray());
+            content_row += prompt_lines.len() as f32;
 
             // Blank separator after prompt
             if !prompt_lines.is_empty() {
                 content_row += 1.0;
             }
 
-            // Draw choices
-            menu.draw(renderer, box_col + 2.0, content_row);
+            // Draw choices once the prompt has finished (if waiting is enabled),
+            // greying out any the player doesn't have enough affection for
+            if !(settings.choices_wait_for_text && self.typing()) {
+                menu.mark_drawn_at(content_row);
+                for (i, line) in choice_lines.iter().enumerate() {
+                    let is_selected = i == menu.selected_index();
+                    let prefix = if is_selected { "> " } else { "  " };
+                    let item = &menu.items[i];
+                    let (text, color) = match self.choice_requirement(item) {
+                        Some(required) if self.choice_locked(item) => {
+                            (format!("{}{} [<3 {}]", prefix, line, required), Colors::dark_gray())
+                        }
+                        _ if is_selected => (format!("{}{}", prefix, line), Colors::yellow()),
+                        _ => (format!("{}{}", prefix, line), Colors::white()),
+                    };
+                    renderer.draw_at_grid(&text, box_col + 2.0, content_row + i as f32, color);
+                }
+            }
         } else {
             // Regular text node — wrap the full text to measure needed height
-            let all_wrapped = word_wrap(&self.current_text, inner_width);
+            let all_wrapped = markup::word_wrap(&self.current_text, inner_width);
             // Box height: borders(2) + text lines + enter prompt row(1) + padding(1)
             let box_height = (2 + all_wrapped.len() + 2).max(5);
 
-            ui::draw_box(renderer, box_col, box_row, box_width, box_height, Colors::WHITE);
+            ui::draw_box(renderer, box_col, box_row, box_width, box_height, Colors::white());
 
             // Speaker name on top border
             if !self.current_speaker.is_empty() {
@@ -261,65 +896,112 @@ impl DatingState {
                     &format!(" {} ", self.current_speaker),
                     box_col + 2.0,
                     box_row,
-                    self.fish_id.color(),
+                    self.speaker_color(&self.current_speaker),
                 );
             }
 
             // Show text with typewriter effect
-            let visible = &self.current_text[..self.current_text.len().min(self.typewriter_pos)];
-            let wrapped = word_wrap(visible, inner_width);
-            for (i, line) in wrapped.iter().enumerate() {
-                renderer.draw_at_grid(line, box_col + 2.0, box_row + 1.0 + i as f32, Colors::WHITE);
+            let wrapped = markup::word_wrap(self.visible_text(), inner_width);
+            markup::draw_lines(renderer, &wrapped, box_col + 2.0, box_row + 1.0, Colors::white());
+
+            if self.auto_play {
+                renderer.draw_at_grid(
+                    t(" AUTO "),
+                    box_col + (box_width as f32) - 9.0,
+                    box_row,
+                    Colors::green(),
+                );
             }
 
             // Show "press enter" prompt at the bottom of the box
-            if self.typewriter_pos >= self.current_text.len() {
+            if !self.typing() && !self.auto_play {
                 let enter_row = box_row + (box_height as f32) - 2.0;
                 renderer.draw_at_grid(
-                    "[Enter]",
+                    t("[Enter]"),
                     box_col + (box_width as f32) - 10.0,
                     enter_row,
-                    Colors::DARK_GRAY,
+                    Colors::dark_gray(),
                 );
             }
         }
-    }
-}
 
-/// Simple word wrapping.
-fn word_wrap(text: &str, max_width: usize) -> Vec<String> {
-    let mut lines = Vec::new();
-    let mut current_line = String::new();
-
-    for word in text.split_whitespace() {
-        if current_line.is_empty() {
-            current_line = word.to_string();
-        } else if current_line.len() + 1 + word.len() > max_width {
-            lines.push(current_line.clone());
-            current_line = word.to_string();
+        let speed_label = if settings.instant_text() {
+            t("Instant").to_string()
         } else {
-            current_line.push(' ');
-            current_line.push_str(word);
+            format!("{}", settings.text_speed)
+        };
+        if self.is_replay() {
+            renderer.draw_centered(
+                t("REPLAY  [Enter] Skip ahead  [Tab] Backlog  [Esc] Stop"),
+                renderer.screen_rows() - 2.0,
+                Colors::dark_gray(),
+            );
+            return;
         }
+        let undo_hint = if self.undo.is_some() { t("  [Bksp] Undo") } else { "" };
+        renderer.draw_centered(
+            &tf("Text speed: {}  [+/-] Adjust  [Tab] Backlog  [H] Help{}", &[&speed_label, &undo_hint]),
+            renderer.screen_rows() - 2.0,
+            Colors::dark_gray(),
+        );
     }
 
-    if !current_line.is_empty() {
-        lines.push(current_line);
-    }
+    /// Draw the scrollable dialogue backlog overlay.
+    fn render_backlog(&self, renderer: &mut dyn DrawSurface, scroll: usize) {
+        let box_width = 64;
+        let inner_width = box_width - 4;
+        let box_row = 3.0;
+        let box_height = (renderer.screen_rows() as usize).saturating_sub(7).max(5);
+        let visible_rows = box_height - 2;
+        let cols = renderer.screen_cols() as usize;
+        let box_col = ((cols.saturating_sub(box_width)) / 2) as f32;
+
+        // Flatten the log up to the scrolled-to entry into wrapped display lines
+        let shown = self.history.len().saturating_sub(scroll);
+        let mut lines: Vec<(String, [f32; 4])> = Vec::new();
+        for (speaker, text) in &self.history[..shown] {
+            if !speaker.is_empty() {
+                let color = if *speaker == self.player_name { Colors::cyan() } else { self.speaker_color(speaker) };
+                lines.push((format!("{}:", speaker), color));
+            }
+            for line in markup::word_wrap(text, inner_width) {
+                lines.push((line, Colors::white()));
+            }
+            lines.push((String::new(), Colors::white()));
+        }
 
-    if lines.is_empty() {
-        lines.push(String::new());
+        let start = lines.len().saturating_sub(visible_rows);
+
+        ui::draw_box(renderer, box_col, box_row, box_width, box_height, Colors::white());
+        renderer.draw_at_grid(t(" Backlog "), box_col + 2.0, box_row, Colors::yellow());
+        for (i, (line, color)) in lines[start..].iter().enumerate() {
+            markup::draw_lines(
+                renderer,
+                std::slice::from_ref(line),
+                box_col + 2.0,
+                box_row + 1.0 + i as f32,
+                *color,
+            );
+        }
+
+        renderer.draw_centered(
+            t("[Arrow Keys] Scroll  [Tab/Esc] Close"),
+            box_row + box_height as f32 + 1.0,
+            Colors::dark_gray(),
+        );
     }
 
-    lines
+    /// The part of the current text revealed by the typewriter so far.
+    fn visible_text(&self) -> &str {
+        markup::visible_prefix(&self.current_text, self.typewriter_pos)
+    }
 }
 
-/// Truncate text to fit within a given width.
-#[allow(dead_code)]
-fn truncate_to_width(text: &str, max_width: usize) -> String {
-    if text.len() <= max_width {
-        text.to_string()
+/// "Affection gained: +N", or "Affection lost: N" for a date that went badly.
+pub fn affection_change_line(affection: i32) -> String {
+    if affection < 0 {
+        tf("Affection lost: {}", &[&-affection])
     } else {
-        format!("{}...", &text[..max_width - 3])
+        tf("Affection gained: +{}", &[&affection])
     }
 }
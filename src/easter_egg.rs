@@ -8,11 +8,18 @@ use winit::keyboard::KeyCode;
 
 use crate::ascii_art;
 use crate::game::GameScreen;
-use crate::render::{Colors, GameRenderer};
+use crate::i18n::{t, tf};
+use crate::input::key_for_char;
+use crate::render::Colors;
+use crate::surface::DrawSurface;
+use crate::ui;
 
 /// Size of cult_papa's face in grid cells (matches the 4-line ASCII head).
 const FACE_SIZE: f32 = 4.0;
 
+/// Clash cycles needed to defeat the moon.
+const CLASH_CYCLES_TO_WIN: u8 = 4;
+
 /// Phases of the moon battle sequence.
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum Phase {
@@ -32,24 +39,31 @@ enum Phase {
     Victory,
 }
 
-/// Secret key sequence detector for "moon".
+/// Word typed on the main menu to start the moon battle.
+pub const MOON_SECRET: &str = "moon";
+
+/// Secret key sequence detector for a word typed on the main menu.
 pub struct SecretSequence {
-    /// The keys we're looking for (M, O, O, N).
-    target: &'static [KeyCode],
+    /// The keys we're looking for, one per letter.
+    target: Vec<KeyCode>,
     /// How far the player has progressed.
     progress: usize,
 }
 
 impl SecretSequence {
-    pub fn new() -> Self {
+    /// Detect `word`. Characters that no key types are skipped.
+    pub fn new(word: &str) -> Self {
         Self {
-            target: &[KeyCode::KeyM, KeyCode::KeyO, KeyCode::KeyO, KeyCode::KeyN],
+            target: word.chars().filter_map(key_for_char).collect(),
             progress: 0,
         }
     }
 
     /// Feed a key press. Returns `true` when the full sequence is matched.
     pub fn feed(&mut self, key: KeyCode) -> bool {
+        if self.target.is_empty() {
+            return false;
+        }
         if key == self.target[self.progress] {
             self.progress += 1;
             if self.progress >= self.target.len() {
@@ -127,13 +141,41 @@ impl MoonBattleState {
                     }
                     self.advance_phase();
                 }
+                KeyCode::KeyS => self.skip_to_victory(),
                 _ => {}
             }
+        } else if self.phase_timer >= self.phase_duration() {
+            // Hands-free: each phase moves on by itself once it has played out
+            self.advance_phase();
         }
 
         None
     }
 
+    /// Seconds a phase plays before auto-advancing.
+    fn phase_duration(&self) -> f32 {
+        match self.phase {
+            Phase::Stargazing => 4.0,
+            Phase::Lasso | Phase::Capture => 3.0,
+            Phase::MoonFalls => 2.5,
+            Phase::DrawSwords => 2.0,
+            Phase::Clash(_) => 0.8,
+            Phase::Victory => f32::INFINITY,
+        }
+    }
+
+    /// Jump straight to the victory screen, still counting the win.
+    fn skip_to_victory(&mut self) {
+        if self.phase == Phase::Victory {
+            return;
+        }
+        self.phase = Phase::Victory;
+        self.phase_timer = 0.0;
+        self.clash_cycles = CLASH_CYCLES_TO_WIN;
+        self.shake = 1.0;
+        self.victory_just_reached = true;
+    }
+
     fn advance_phase(&mut self) {
         self.phase_timer = 0.0;
         self.phase = match self.phase {
@@ -151,7 +193,7 @@ impl MoonBattleState {
             Phase::Clash(n) => {
                 self.shake = 1.0;
                 self.clash_cycles += 1;
-                if self.clash_cycles >= 4 {
+                if self.clash_cycles >= CLASH_CYCLES_TO_WIN {
                     self.victory_just_reached = true;
                     Phase::Victory
                 } else {
@@ -162,18 +204,9 @@ impl MoonBattleState {
         };
     }
 
-    pub fn render(&self, renderer: &mut GameRenderer, time: f32) {
-        // Screen shake offset
-        let shake_x = if self.shake > 0.05 {
-            (time * 50.0).sin() * self.shake * 0.5
-        } else {
-            0.0
-        };
-        let shake_y = if self.shake > 0.05 {
-            (time * 37.0).cos() * self.shake * 0.3
-        } else {
-            0.0
-        };
+    /// Draw the battle. `shake_scale` scales screen shake; 0 turns it off.
+    pub fn render(&self, renderer: &mut dyn DrawSurface, time: f32, shake_scale: f32) {
+        let (shake_x, shake_y) = ui::shake_offset(self.shake * shake_scale, time);
 
         match self.phase {
             Phase::Stargazing => self.render_stargazing(renderer, time, shake_x, shake_y),
@@ -188,26 +221,26 @@ impl MoonBattleState {
         // Phase indicator
         if self.phase != Phase::Victory {
             renderer.draw_centered(
-                "[Enter] Next  [Esc] Skip",
+                t("[Enter] Next  [S] Skip to victory  [Esc] Exit"),
                 28.0,
-                Colors::DARK_GRAY,
+                Colors::dark_gray(),
             );
         }
     }
 
-    fn render_stargazing(&self, renderer: &mut GameRenderer, time: f32, sx: f32, sy: f32) {
+    fn render_stargazing(&self, renderer: &mut dyn DrawSurface, time: f32, sx: f32, sy: f32) {
         // Twinkling night sky (stars only — moon drawn separately with glow)
-        let twinkle = (time * 2.0).sin() * 0.3 + 0.7;
+        let twinkle = ui::wave(renderer, time * 2.0) * 0.3 + 0.7;
         let star_color = [0.8, 0.8, 1.0, twinkle];
         renderer.draw_multiline_centered(ascii_art::STARS_ONLY, 1.0 + sy, star_color);
 
         // Moon with animated glow — single multiline block for alignment
-        let glow = (time * 1.5).sin() * 0.1 + 0.9;
+        let glow = ui::wave(renderer, time * 1.5) * 0.1 + 0.9;
         let moon_color = [1.0, 1.0, 0.8, glow];
         renderer.draw_multiline_centered(ascii_art::MOON_FACE, 2.0 + sy, moon_color);
 
         // cult_papa standing below, looking up
-        renderer.draw_multiline_centered(ascii_art::CULT_PAPA_STANDING, 14.0 + sy, Colors::WHITE);
+        renderer.draw_multiline_centered(ascii_art::CULT_PAPA_STANDING, 14.0 + sy, Colors::white());
 
         // Overlay cult_papa face on head (lines 1-4, centered)
         let cols = renderer.screen_cols();
@@ -215,18 +248,18 @@ impl MoonBattleState {
             cols / 2.0 - FACE_SIZE / 2.0,
             16.5 - FACE_SIZE / 2.0 + sy,
             FACE_SIZE,
-            Colors::WHITE,
+            Colors::white(),
         );
 
         // Dramatic text
         renderer.draw_centered(
-            "cult_papa gazes at the moon...",
+            t("cult_papa gazes at the moon..."),
             24.0,
             [0.7, 0.7, 0.9, 1.0],
         );
     }
 
-    fn render_lasso(&self, renderer: &mut GameRenderer, time: f32, sx: f32, sy: f32) {
+    fn render_lasso(&self, renderer: &mut dyn DrawSurface, time: f32, sx: f32, sy: f32) {
         // Stars dim
         let star_color = [0.5, 0.5, 0.7, 0.5];
         renderer.draw_multiline_centered(ascii_art::STARS_ONLY, 1.0 + sy, star_color);
@@ -235,7 +268,7 @@ impl MoonBattleState {
         renderer.draw_multiline_centered(
             ascii_art::CULT_PAPA_LASSO,
             12.0 + sy,
-            Colors::WHITE,
+            Colors::white(),
         );
 
         // Overlay cult_papa face on head (shifted left of center for lasso pose)
@@ -244,19 +277,19 @@ impl MoonBattleState {
             cols / 2.0 - 7.5 - FACE_SIZE / 2.0,
             14.5 - FACE_SIZE / 2.0 + sy,
             FACE_SIZE,
-            Colors::WHITE,
+            Colors::white(),
         );
 
         // Lasso rope animation
         let lasso_progress = (self.phase_timer * 0.4).min(1.0);
         let rope_col = cols / 2.0 + 6.0 + sx;
         for i in 0..(lasso_progress * 8.0) as usize {
-            let wobble = (time * 5.0 + i as f32).sin() * 0.3;
+            let wobble = ui::wave(renderer, time * 5.0 + i as f32) * 0.3;
             renderer.draw_at_grid(
                 "|",
                 rope_col + wobble,
                 12.0 - i as f32 + sy,
-                Colors::YELLOW,
+                Colors::yellow(),
             );
         }
 
@@ -269,13 +302,13 @@ impl MoonBattleState {
         renderer.draw_multiline_centered(ascii_art::MOON_FACE, 2.0 + sy, moon_color);
 
         renderer.draw_centered(
-            "\"Get over here!\"",
+            t("\"Get over here!\""),
             24.0,
             [1.0, 0.8, 0.2, 1.0],
         );
     }
 
-    fn render_capture(&self, renderer: &mut GameRenderer, time: f32, sx: f32, sy: f32) {
+    fn render_capture(&self, renderer: &mut dyn DrawSurface, time: f32, sx: f32, sy: f32) {
         let star_color = [0.4, 0.4, 0.6, 0.4];
         renderer.draw_multiline_centered(ascii_art::STARS_ONLY, 1.0 + sy, star_color);
 
@@ -283,7 +316,7 @@ impl MoonBattleState {
         renderer.draw_multiline_centered(
             ascii_art::CULT_PAPA_CAPTURE,
             10.0 + sy,
-            Colors::WHITE,
+            Colors::white(),
         );
 
         // Overlay cult_papa face
@@ -292,13 +325,13 @@ impl MoonBattleState {
             cols / 2.0 - 5.0 - FACE_SIZE / 2.0,
             12.5 - FACE_SIZE / 2.0 + sy,
             FACE_SIZE,
-            Colors::WHITE,
+            Colors::white(),
         );
 
         // Moon being pulled down (animated)
         let pull_progress = (self.phase_timer * 0.4).min(1.0);
         let moon_row = 2.0 + pull_progress * 5.0;
-        let struggle = (time * 8.0).sin() * 0.5;
+        let struggle = ui::wave(renderer, time * 8.0) * 0.5;
         let panic_color = [1.0, 0.9, 0.3, 1.0];
         let moon_col = cols / 2.0 + 4.0 + struggle + sx;
         renderer.draw_multiline_at_grid(
@@ -309,13 +342,13 @@ impl MoonBattleState {
         );
 
         renderer.draw_centered(
-            "The moon struggles but cult_papa's grip is iron!",
+            t("The moon struggles but cult_papa's grip is iron!"),
             24.0,
             [0.9, 0.7, 0.2, 1.0],
         );
     }
 
-    fn render_moon_falls(&self, renderer: &mut GameRenderer, time: f32, _sx: f32, sy: f32) {
+    fn render_moon_falls(&self, renderer: &mut dyn DrawSurface, time: f32, _sx: f32, sy: f32) {
         // The sky goes dark without the moon
         let dark_color = [0.3, 0.3, 0.5, 0.3];
         renderer.draw_multiline_centered(ascii_art::STARS_ONLY, 1.0 + sy, dark_color);
@@ -330,27 +363,27 @@ impl MoonBattleState {
         // Impact effect near the end
         let progress = self.phase_timer / 2.0;
         if progress > 0.7 {
-            let flash = ((time * 20.0).sin() * 0.5 + 0.5).min(1.0);
+            let flash = (ui::wave(renderer, time * 20.0) * 0.5 + 0.5).min(1.0);
             renderer.draw_centered(
-                "*** CRASH ***",
+                t("*** CRASH ***"),
                 16.0 + sy,
                 [1.0, 1.0, flash, 1.0],
             );
             renderer.draw_centered(
-                "The ground shakes!",
+                t("The ground shakes!"),
                 17.0 + sy,
                 [0.8, 0.4, 0.2, 1.0],
             );
         }
 
         renderer.draw_centered(
-            "\"You're mine now, moon.\"",
+            t("\"You're mine now, moon.\""),
             24.0,
             [1.0, 0.5, 0.5, 1.0],
         );
     }
 
-    fn render_draw_swords(&self, renderer: &mut GameRenderer, time: f32, sx: f32, sy: f32) {
+    fn render_draw_swords(&self, renderer: &mut dyn DrawSurface, time: f32, sx: f32, sy: f32) {
         let cols = renderer.screen_cols();
         let left_col = cols / 2.0 - 22.0 + sx;
         let right_col = cols / 2.0 + 8.0 + sx;
@@ -360,7 +393,7 @@ impl MoonBattleState {
             ascii_art::CULT_PAPA_SWORD,
             left_col,
             6.0 + sy,
-            Colors::WHITE,
+            Colors::white(),
         );
 
         // Overlay cult_papa face
@@ -368,7 +401,7 @@ impl MoonBattleState {
             left_col + 6.0 - FACE_SIZE / 2.0,
             8.5 - FACE_SIZE / 2.0 + sy,
             FACE_SIZE,
-            Colors::WHITE,
+            Colors::white(),
         );
 
         // Moon with sword
@@ -380,22 +413,19 @@ impl MoonBattleState {
         );
 
         // Dramatic text
-        let flash = (time * 4.0).sin() * 0.4 + 0.6;
-        renderer.draw_centered(
-            "///  PREPARE YOURSELF  \\\\\\",
-            3.0 + sy,
-            [1.0, 0.3, 0.3, flash],
-        );
+        let flash = ui::wave(renderer, time * 4.0) * 0.4 + 0.6;
+        let [r, g, b, _] = Colors::red();
+        renderer.draw_centered(t("///  PREPARE YOURSELF  \\\\\\"), 3.0 + sy, [r, g, b, flash]);
 
-        let gleam = (time * 6.0).sin() * 0.5 + 0.5;
+        let gleam = ui::wave(renderer, time * 6.0) * 0.5 + 0.5;
         renderer.draw_centered(
-            "*  SHING!  *",
+            t("*  SHING!  *"),
             18.0 + sy,
             [0.8, 0.8, 1.0, gleam],
         );
 
         renderer.draw_centered(
-            "\"En garde, celestial body!\"",
+            t("\"En garde, celestial body!\""),
             24.0,
             [0.7, 0.9, 1.0, 1.0],
         );
@@ -403,7 +433,7 @@ impl MoonBattleState {
 
     fn render_clash(
         &self,
-        renderer: &mut GameRenderer,
+        renderer: &mut dyn DrawSurface,
         time: f32,
         frame: u8,
         sx: f32,
@@ -429,7 +459,8 @@ impl MoonBattleState {
         );
 
         // Spark particles
-        for i in 0..5_usize {
+        let sparks = if renderer.reduce_motion() { 0 } else { 5_usize };
+        for i in 0..sparks {
             let angle = time * 3.0 + i as f32 * 1.2;
             let radius = self.phase_timer * 4.0;
             let spark_x = cols / 2.0 + angle.cos() * radius + sx;
@@ -454,9 +485,9 @@ impl MoonBattleState {
         // Impact text
         let impact_texts = ["CLANG!", "SLASH!", "PARRY!"];
         let text = impact_texts[frame as usize % impact_texts.len()];
-        let text_flash = ((time * 10.0).sin() * 0.5 + 0.5).min(1.0);
+        let text_flash = (ui::wave(renderer, time * 10.0) * 0.5 + 0.5).min(1.0);
         renderer.draw_centered(
-            text,
+            t(text),
             20.0 + sy,
             [1.0, text_flash, 0.2, 1.0],
         );
@@ -464,15 +495,15 @@ impl MoonBattleState {
         // Combo counter
         let combo = self.clash_cycles + 1;
         renderer.draw_centered(
-            &format!("COMBO x{}", combo),
+            &tf("COMBO x{}", &[&combo]),
             22.0 + sy,
             [1.0, 0.5, 0.0, 1.0],
         );
     }
 
-    fn render_victory(&self, renderer: &mut GameRenderer, time: f32, sx: f32, sy: f32) {
+    fn render_victory(&self, renderer: &mut dyn DrawSurface, time: f32, sx: f32, sy: f32) {
         // Stars return brighter
-        let twinkle = (time * 2.0).sin() * 0.2 + 0.8;
+        let twinkle = ui::wave(renderer, time * 2.0) * 0.2 + 0.8;
         let star_color = [0.9, 0.9, 1.0, twinkle];
         renderer.draw_multiline_centered(ascii_art::STARS_ONLY, 1.0 + sy, star_color);
 
@@ -486,40 +517,34 @@ impl MoonBattleState {
             cols / 2.0 - 5.5 - FACE_SIZE / 2.0,
             11.5 - FACE_SIZE / 2.0 + sy,
             FACE_SIZE,
-            Colors::WHITE,
+            Colors::white(),
         );
 
         // Celebration particles
-        for i in 0..8_usize {
+        let particles = if renderer.reduce_motion() { 0 } else { 8_usize };
+        for i in 0..particles {
             let x = cols / 2.0 + (time * 1.5 + i as f32 * 0.8).sin() * 15.0 + sx;
             let y = 2.0 + (time * 1.2 + i as f32 * 1.1).cos().abs() * 6.0 + sy;
             let particle = if i % 2 == 0 { "*" } else { "+" };
             let color = match i % 4 {
-                0 => Colors::YELLOW,
-                1 => Colors::CYAN,
-                2 => Colors::PINK,
-                _ => Colors::GREEN,
+                0 => Colors::yellow(),
+                1 => Colors::cyan(),
+                2 => Colors::pink(),
+                _ => Colors::green(),
             };
             renderer.draw_at_grid(particle, x, y, color);
         }
 
         // Victory text with rainbow cycling
-        let hue = time * 2.0;
-        let r = (hue.sin() * 0.5 + 0.5).min(1.0);
-        let g = ((hue + 2.094).sin() * 0.5 + 0.5).min(1.0);
-        let b = ((hue + 4.189).sin() * 0.5 + 0.5).min(1.0);
-        renderer.draw_centered(
-            "cult_papa has conquered the moon!",
-            20.0 + sy,
-            [r, g, b, 1.0],
-        );
+        let rainbow = ui::rainbow_color(renderer, time);
+        renderer.draw_centered(t("cult_papa has conquered the moon!"), 20.0 + sy, rainbow);
 
         renderer.draw_centered(
-            "The tides themselves bow to cult_papa.",
+            t("The tides themselves bow to cult_papa."),
             22.0 + sy,
             [0.7, 0.7, 0.9, 0.8],
         );
 
-        renderer.draw_centered("[Enter] Return", 26.0, Colors::DARK_GRAY);
+        renderer.draw_centered(t("[Enter] Return"), 26.0, Colors::dark_gray());
     }
 }
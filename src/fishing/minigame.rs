@@ -1,19 +1,26 @@
 //! Tug-of-war fishing minigame.
 //!
-//! The player must keep a line indicator centered on a meter by pressing
+//! The player must keep a line indicator centered on a meter by holding
 //! A (left) and D (right) while the fish fights back with random tugs.
 //! Staying centered fills a reel-in progress bar. Drifting too far to
 //! the edges risks the line snapping.
 
 use rand::Rng;
+use rand::seq::SliceRandom;
 use winit::keyboard::KeyCode;
 
 use crate::ascii_art;
-use crate::data::{FishId, FishSize};
+use crate::data::{FishId, FishPersonality, FishSize, GameMode};
 use crate::dating::fish as fish_helpers;
+use crate::fishing::Weather;
 use crate::game::GameScreen;
+use crate::i18n::{t, tf};
+use crate::input::{Action, InputState, KeyBindings};
 use crate::plugins::FishRegistry;
-use crate::render::{Colors, GameRenderer};
+use crate::render::Colors;
+use crate::settings::MeterPreset;
+use crate::surface::DrawSurface;
+use crate::ui;
 
 /// Width of the tug-of-war meter in characters.
 const METER_WIDTH: usize = 50;
@@ -21,8 +28,16 @@ const METER_WIDTH: usize = 50;
 /// How far from center (0.0–1.0) the line can drift before it snaps.
 const SNAP_THRESHOLD: f32 = 1.0;
 
-/// Center zone half-width — staying within this zone reels in the fish.
-const CENTER_ZONE: f32 = 0.2;
+/// How far the line can drift when snapping is off.
+const CASUAL_LINE_LIMIT: f32 = SNAP_THRESHOLD * 0.95;
+
+/// Center zone half-width for the easiest fish — staying within the zone
+/// reels in the fish.
+const WIDEST_CENTER_ZONE: f32 = 0.28;
+/// Center zone half-width for the hardest fish.
+const NARROWEST_CENTER_ZONE: f32 = 0.12;
+/// Range a plugin's own `center_zone` is clamped to.
+const PLUGIN_CENTER_ZONE_RANGE: (f32, f32) = (0.05, 0.5);
 
 /// How much reel progress is needed to land the fish (seconds in zone).
 const REEL_TARGET: f32 = 5.0;
@@ -30,12 +45,131 @@ const REEL_TARGET: f32 = 5.0;
 /// How much reel progress drains per second when outside the center zone.
 const REEL_DRAIN_RATE: f32 = 0.3;
 
-/// Player input force per key press.
+/// Player input force applied per second while a pull key is held.
 const PLAYER_FORCE: f32 = 1.8;
 
+/// Share of `REEL_TARGET` after which the fish fights its hardest.
+const FINAL_PUSH_THRESHOLD: f32 = 0.8;
+/// Extra fish force at the very end of the final push (0.4 = +40%).
+const FINAL_PUSH_FORCE_BOOST: f32 = 0.4;
+/// Extra reel drain outside the zone at the very end of the final push.
+const FINAL_PUSH_DRAIN_BOOST: f32 = 0.5;
+/// The final push never lifts the fish's pull above this, so a player
+/// holding against it can always win back the center.
+const FINAL_PUSH_MAX_FORCE: f32 = PLAYER_FORCE * 0.9;
+
 /// Damping applied to line velocity each frame (friction).
 const VELOCITY_DAMPING: f32 = 3.0;
 
+/// Line distance from center that triggers clutch time.
+const CLUTCH_THRESHOLD: f32 = 0.9;
+/// Seconds clutch time lasts once triggered.
+const CLUTCH_DURATION: f32 = 0.6;
+/// Seconds after clutch time ends before it can trigger again.
+const CLUTCH_COOLDOWN: f32 = 4.0;
+/// Share of the fish's pull that still applies during clutch time.
+const CLUTCH_FORCE_SCALE: f32 = 0.3;
+
+/// Share of the fish's aggression the assist takes off per fight lost in a
+/// row, up to `ASSIST_MAX_FAILURES` losses.
+const ASSIST_STEP: f32 = 0.08;
+const ASSIST_MAX_FAILURES: u32 = 3;
+
+/// Length of one physics step when the fixed timestep is on, in seconds.
+const FIXED_TIMESTEP: f32 = 1.0 / 120.0;
+
+/// Tension (share of the snap distance) where the screen starts to shake.
+const SHAKE_TENSION_START: f32 = 0.6;
+/// How fast a surge's screen shake fades, per second.
+const SHAKE_DECAY: f32 = 3.0;
+
+/// Keys a quick-time prompt can ask for, with their on-screen labels. Keys
+/// bound to an action are skipped, so a prompt never doubles as a pull.
+const QUICK_TIME_KEYS: [(KeyCode, &str); 8] = [
+    (KeyCode::KeyJ, "J"),
+    (KeyCode::KeyK, "K"),
+    (KeyCode::KeyL, "L"),
+    (KeyCode::KeyU, "U"),
+    (KeyCode::KeyI, "I"),
+    (KeyCode::KeyO, "O"),
+    (KeyCode::KeyN, "N"),
+    (KeyCode::KeyM, "M"),
+];
+/// Seconds to answer a quick-time prompt from the hardest fish. Easier fish
+/// give up to `QUICK_TIME_EASY_BONUS` longer.
+const QUICK_TIME_WINDOW: f32 = 1.0;
+const QUICK_TIME_EASY_BONUS: f32 = 0.5;
+/// Shortest gap between quick-time prompts, and the random extra on top.
+/// Easy fish stretch both by up to `QUICK_TIME_EASY_SPACING`.
+const QUICK_TIME_MIN_GAP: f32 = 4.0;
+const QUICK_TIME_GAP_SPREAD: f32 = 4.0;
+const QUICK_TIME_EASY_SPACING: f32 = 1.0;
+/// Reel progress from answering a prompt in time.
+const QUICK_TIME_REEL_BONUS: f32 = 0.6;
+/// Fish pull, as a multiple of its aggression, when a prompt is missed.
+const QUICK_TIME_MISS_SURGE: f32 = 1.8;
+
+/// A key the player must press before time runs out.
+#[derive(Debug, Clone, Copy)]
+struct QuickTimeEvent {
+    key: KeyCode,
+    label: &'static str,
+    /// Seconds left to press `key`.
+    time_left: f32,
+}
+
+/// Characters the tug meter is drawn with. Every glyph is one cell wide, so
+/// the zone and cursor overlays line up in any style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MeterStyle {
+    pub left_cap: char,
+    pub right_cap: char,
+    /// Meter outside the center zone.
+    pub track: char,
+    /// Meter inside the center zone.
+    pub zone: char,
+    /// The exact center.
+    pub center: char,
+    /// Where the line is.
+    pub cursor: char,
+}
+
+impl MeterStyle {
+    pub const CLASSIC: Self = Self {
+        left_cap: '[',
+        right_cap: ']',
+        track: '-',
+        zone: '=',
+        center: '+',
+        cursor: '|',
+    };
+    pub const BLOCKS: Self = Self {
+        left_cap: '▐',
+        right_cap: '▌',
+        track: '░',
+        zone: '▒',
+        center: '▓',
+        cursor: '█',
+    };
+    pub const MINIMAL: Self = Self {
+        left_cap: ' ',
+        right_cap: ' ',
+        track: ' ',
+        zone: '-',
+        center: '+',
+        cursor: 'O',
+    };
+
+    /// The glyphs for a style picked in the settings.
+    pub fn from_preset(preset: MeterPreset) -> Self {
+        match preset {
+            MeterPreset::Classic => Self::CLASSIC,
+            MeterPreset::Blocks => Self::BLOCKS,
+            MeterPreset::Minimal => Self::MINIMAL,
+        }
+    }
+}
+
 /// Phases of the minigame.
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum Phase {
@@ -45,9 +179,66 @@ enum Phase {
     Result,
 }
 
+/// How a hooked fish's fight ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CatchOutcome {
+    Caught,
+    /// The line drifted too far and snapped.
+    Snapped,
+    /// The fight went on too long and the fish slipped away.
+    TimedOut,
+}
+
+/// Size score at or above which a catch is Large.
+const LARGE_SIZE_SCORE: f32 = 0.85;
+/// Size score at or above which a catch is Medium.
+const MEDIUM_SIZE_SCORE: f32 = 0.5;
+/// Size score a fish of difficulty 1.0 adds over one of difficulty 0.0.
+const DIFFICULTY_SIZE_WEIGHT: f32 = 0.15;
+
+/// Size of a landed fish.
+///
+/// The score is `accuracy + difficulty * DIFFICULTY_SIZE_WEIGHT + modifier`:
+/// how well the player kept the line centered (reel progress per second of
+/// fight, about 0.0–1.0), a nudge for harder fish, and a modifier such as
+/// the weather's. A score of `LARGE_SIZE_SCORE` or more is Large, of
+/// `MEDIUM_SIZE_SCORE` or more Medium, anything less Small. So a perfect
+/// fight always lands a Large, and a hard fish needs less precision for it.
+pub fn determine_size(accuracy: f32, difficulty: f32, modifier: f32) -> FishSize {
+    let score = accuracy + difficulty.clamp(0.0, 1.0) * DIFFICULTY_SIZE_WEIGHT + modifier;
+    if score >= LARGE_SIZE_SCORE {
+        FishSize::Large
+    } else if score >= MEDIUM_SIZE_SCORE {
+        FishSize::Medium
+    } else {
+        FishSize::Small
+    }
+}
+
+/// Chance that a caught fish is shiny.
+const SHINY_CHANCE: f64 = 0.02;
+/// Shiny chance for large catches.
+const SHINY_CHANCE_LARGE: f64 = 0.05;
+
+/// Roll whether a catch of the given size is shiny.
+pub fn roll_shiny<R: Rng>(rng: &mut R, size: FishSize) -> bool {
+    let chance = match size {
+        FishSize::Large => SHINY_CHANCE_LARGE,
+        _ => SHINY_CHANCE,
+    };
+    rng.gen_bool(chance)
+}
+
 pub struct MinigameState {
     fish_id: FishId,
     pond_index: usize,
+    /// Whether this cast is in the mystery pond, keeping the fish hidden
+    /// until the catch screen.
+    mystery: bool,
+    /// The fish's difficulty, 0.0–1.0. Harder fish tend to be bigger.
+    difficulty: f32,
+    /// Today's weather (affects fish behavior).
+    weather: Weather,
     phase: Phase,
     /// Timer for phase transitions.
     timer: f32,
@@ -60,16 +251,26 @@ pub struct MinigameState {
     line_vel: f32,
     /// Reel-in progress (0.0 to REEL_TARGET).
     reel_progress: f32,
+    /// Half-width of the zone that reels the fish in. Narrower for harder fish.
+    center_zone: f32,
     /// Whether the catch was successful.
     caught: bool,
     /// Fish size if caught (determined by accuracy).
     fish_size: FishSize,
+    /// Whether the caught fish is a shiny variant.
+    shiny: bool,
+    /// How the fight ended, taken once by `take_outcome`.
+    outcome_just_now: Option<CatchOutcome>,
+    /// Set when a fish bites; cleared by `take_bite_flag`.
+    bite_just_now: bool,
+    /// Set when the line snaps; cleared by `take_snap_flag`.
+    snapped_just_now: bool,
     /// Wait duration before fish bites.
     wait_duration: f32,
 
     // ── Fish AI ──
 
-    /// Base aggressiveness (0.0–1.0). Higher = stronger tugs.
+    /// Base aggressiveness (roughly 0.0–1.3 with weather). Higher = stronger tugs.
     fish_aggression: f32,
     /// Current fish force direction (-1 or 1).
     fish_dir: f32,
@@ -79,8 +280,15 @@ pub struct MinigameState {
     fish_change_timer: f32,
     /// How erratic the fish is (shorter change intervals).
     fish_erratic: f32,
+    /// Which fighting pattern picks the fish's next move.
+    personality: FishPersonality,
+    /// Whether a Lurker is lying still, building up to its next pull.
+    lurking: bool,
     /// Per-frame tension animation offset.
     tension_shake: f32,
+    /// How hard the reeling screen shakes, 0.0 to 1.0. Follows tension and
+    /// jumps on big surges.
+    screen_shake: f32,
 
     // ── Input tracking ──
 
@@ -88,42 +296,169 @@ pub struct MinigameState {
     holding_left: bool,
     /// Whether D is currently held.
     holding_right: bool,
+
+    /// Whether the line snaps at the edge (off in Casual mode).
+    line_snaps: bool,
+    /// Whether the fishing assist eased this fish after earlier losses.
+    assisted: bool,
+
+    // ── Clutch time ──
+
+    /// Whether clutch time can trigger (off in Hardcore mode).
+    clutch_enabled: bool,
+    /// Seconds of clutch time left, while the fish's pull is slowed.
+    clutch_timer: f32,
+    /// Seconds until clutch time can trigger again.
+    clutch_cooldown: f32,
+
+    // ── Quick-time prompts ──
+
+    /// The prompt on screen, if any.
+    quick_time: Option<QuickTimeEvent>,
+    /// Seconds until the next prompt.
+    quick_time_timer: f32,
+
+    // ── Fixed timestep ──
+
+    /// Frame time not yet spent on physics steps.
+    accumulator: f32,
+    /// Line position before the latest physics step, for interpolation.
+    prev_line_pos: f32,
 }
 
 impl MinigameState {
-    pub fn new(fish_id: FishId, pond_index: usize) -> Self {
-        let mut rng = rand::thread_rng();
-        let difficulty = fish_id.difficulty();
+    pub fn new<R: Rng>(
+        fish_id: FishId,
+        registry: &FishRegistry,
+        weather: Weather,
+        mode: GameMode,
+        recent_failures: u32,
+        rng: &mut R,
+    ) -> Self {
+        let difficulty = fish_id.difficulty_with_registry(registry);
+        let center_zone = match fish_id.center_zone_with_registry(registry) {
+            Some(zone) => zone.clamp(PLUGIN_CENTER_ZONE_RANGE.0, PLUGIN_CENTER_ZONE_RANGE.1),
+            None => {
+                let hardness = difficulty.clamp(0.0, 1.0);
+                WIDEST_CENTER_ZONE + (NARROWEST_CENTER_ZONE - WIDEST_CENTER_ZONE) * hardness
+            }
+        };
+        let pond_index = fish_id.pond_index_with_registry(registry).unwrap_or(usize::MAX);
+        let personality = fish_id.personality_with_registry(registry);
 
-        // Fish personality derived from difficulty
-        let fish_aggression = 0.3 + difficulty * 0.7; // 0.3 to 1.0
-        let fish_erratic = 0.3 + difficulty * 0.5;
+        // Fish personality derived from difficulty, scaled by the weather
+        let weather_mult = weather.difficulty_multiplier();
+        let fish_aggression =
+            (0.3 + difficulty * 0.7) * weather_mult * mode.aggression_multiplier(); // 0.24 to 1.3 (1.69 hardcore)
+        let fish_erratic = (0.3 + difficulty * 0.5) * weather_mult;
+        // A fish that keeps winning tires a little each time
+        let assist = ASSIST_STEP * recent_failures.min(ASSIST_MAX_FAILURES) as f32;
+        let fish_aggression = fish_aggression * (1.0 - assist);
 
         Self {
             fish_id,
             pond_index,
+            mystery: false,
+            difficulty,
+            weather,
             phase: Phase::Casting,
             timer: 0.0,
             line_pos: 0.0,
             line_vel: 0.0,
             reel_progress: 0.0,
+            center_zone,
             caught: false,
             fish_size: FishSize::Medium,
+            shiny: false,
+            outcome_just_now: None,
+            bite_just_now: false,
+            snapped_just_now: false,
             wait_duration: rng.r#gen::<f32>() * 2.0 + 1.0,
             fish_aggression,
             fish_dir: if rng.r#gen::<bool>() { 1.0 } else { -1.0 },
             fish_force: fish_aggression * 0.5,
             fish_change_timer: rng.r#gen::<f32>() * 0.5 + 0.3,
             fish_erratic,
+            personality,
+            lurking: false,
             tension_shake: 0.0,
+            screen_shake: 0.0,
             holding_left: false,
             holding_right: false,
+            line_snaps: mode.line_snaps(),
+            assisted: recent_failures > 0,
+            clutch_enabled: mode.clutch_time(),
+            clutch_timer: 0.0,
+            clutch_cooldown: 0.0,
+            quick_time: None,
+            quick_time_timer: 0.0,
+            accumulator: 0.0,
+            prev_line_pos: 0.0,
         }
     }
 
-    pub fn update(&mut self, dt: f32, key: Option<KeyCode>) -> Option<GameScreen> {
+    /// Index of the pond being fished.
+    pub fn pond_index(&self) -> usize {
+        self.pond_index
+    }
+
+    /// This cast moved to the mystery pond, with the fish hidden until the
+    /// catch screen.
+    pub fn into_mystery(mut self) -> Self {
+        self.mystery = true;
+        self
+    }
+
+    /// Whether this cast is in the mystery pond.
+    pub fn is_mystery(&self) -> bool {
+        self.mystery
+    }
+
+    /// Returns how the fight ended, once, when the fish is landed or gets
+    /// away. Subsequent calls return `None`.
+    pub fn take_outcome(&mut self) -> Option<CatchOutcome> {
+        self.outcome_just_now.take()
+    }
+
+    /// Whether the fight is over and the fish got away.
+    pub fn got_away(&self) -> bool {
+        self.phase == Phase::Result && !self.caught
+    }
+
+    /// The fish on the line.
+    pub fn fish_id(&self) -> &FishId {
+        &self.fish_id
+    }
+
+    /// Returns true once when a fish bites.
+    pub fn take_bite_flag(&mut self) -> bool {
+        std::mem::take(&mut self.bite_just_now)
+    }
+
+    /// Returns true once when the line snaps.
+    pub fn take_snap_flag(&mut self) -> bool {
+        std::mem::take(&mut self.snapped_just_now)
+    }
+
+    pub fn update<R: Rng>(
+        &mut self,
+        dt: f32,
+        input: &InputState,
+        bindings: &KeyBindings,
+        fixed_timestep: bool,
+        quick_time_events: bool,
+        rng: &mut R,
+    ) -> Option<GameScreen> {
+        let key = input.just_pressed();
         self.timer += dt;
 
+        // Answered once per frame, however many physics steps run
+        if self.phase == Phase::Reeling
+            && let Some(k) = key
+        {
+            self.answer_quick_time(k);
+        }
+
         match self.phase {
             Phase::Casting => {
                 if self.timer > 1.5 {
@@ -135,26 +470,41 @@ impl MinigameState {
                 if self.timer > self.wait_duration {
                     self.phase = Phase::Reeling;
                     self.timer = 0.0;
+                    self.bite_just_now = true;
+                    self.quick_time_timer = self.quick_time_gap(rng);
+                }
+            }
+            Phase::Reeling if fixed_timestep => {
+                // Step the physics at a steady rate however fast frames come
+                self.accumulator += dt;
+                while self.phase == Phase::Reeling && self.accumulator >= FIXED_TIMESTEP {
+                    self.prev_line_pos = self.line_pos;
+                    self.update_reeling(FIXED_TIMESTEP, input, bindings, quick_time_events, rng);
+                    self.accumulator -= FIXED_TIMESTEP;
                 }
             }
             Phase::Reeling => {
-                self.update_reeling(dt, key);
+                self.update_reeling(dt, input, bindings, quick_time_events, rng);
+                self.prev_line_pos = self.line_pos;
+                self.accumulator = 0.0;
             }
             Phase::Result => {
                 if let Some(k) = key {
-                    match k {
-                        KeyCode::Enter | KeyCode::Space => {
+                    match bindings.menu_action(k) {
+                        Some(Action::Confirm) => {
                             if self.caught {
                                 return Some(GameScreen::CatchResult {
                                     fish_id: self.fish_id.clone(),
                                     pond_index: self.pond_index,
                                     size: self.fish_size,
+                                    shiny: self.shiny,
+                                    mystery: self.mystery,
                                 });
                             } else {
                                 return Some(GameScreen::FishingPondSelect);
                             }
                         }
-                        KeyCode::Escape => {
+                        Some(Action::Back) => {
                             return Some(GameScreen::FishingPondSelect);
                         }
                         _ => {}
@@ -165,7 +515,7 @@ impl MinigameState {
 
         // Escape always exits during active phases
         if self.phase != Phase::Result {
-            if key == Some(KeyCode::Escape) {
+            if key.is_some_and(|k| bindings.is(Action::Back, k)) {
                 return Some(GameScreen::FishingPondSelect);
             }
         }
@@ -173,17 +523,165 @@ impl MinigameState {
         None
     }
 
-    fn update_reeling(&mut self, dt: f32, key: Option<KeyCode>) {
-        let mut rng = rand::thread_rng();
+    /// Line position to draw, blended between the last two physics steps so
+    /// the fixed timestep doesn't look choppy.
+    fn display_line_pos(&self) -> f32 {
+        let alpha = (self.accumulator / FIXED_TIMESTEP).clamp(0.0, 1.0);
+        self.prev_line_pos + (self.line_pos - self.prev_line_pos) * alpha
+    }
 
-        // ── Process input ──
-        if let Some(k) = key {
-            match k {
-                KeyCode::KeyA | KeyCode::ArrowLeft => self.holding_left = true,
-                KeyCode::KeyD | KeyCode::ArrowRight => self.holding_right = true,
-                _ => {}
+    /// How far into the final push the reel is, from 0.0 (not there yet) to
+    /// 1.0 (about to land the fish).
+    fn final_push(&self) -> f32 {
+        let progress = self.reel_progress / REEL_TARGET;
+        ((progress - FINAL_PUSH_THRESHOLD) / (1.0 - FINAL_PUSH_THRESHOLD)).clamp(0.0, 1.0)
+    }
+
+    /// Whether clutch time is slowing the fish right now.
+    fn in_clutch(&self) -> bool {
+        self.clutch_timer > 0.0
+    }
+
+    /// Count down clutch time, and start it when the line nears snapping
+    /// and the cooldown has passed.
+    fn update_clutch(&mut self, dt: f32) {
+        if self.in_clutch() {
+            self.clutch_timer -= dt;
+            if !self.in_clutch() {
+                self.clutch_cooldown = CLUTCH_COOLDOWN;
+            }
+            return;
+        }
+        self.clutch_cooldown = (self.clutch_cooldown - dt).max(0.0);
+        if self.clutch_enabled && self.clutch_cooldown <= 0.0 && self.line_pos.abs() >= CLUTCH_THRESHOLD {
+            self.clutch_timer = CLUTCH_DURATION;
+        }
+    }
+
+    /// Seconds until the next quick-time prompt. Easy fish prompt less often.
+    fn quick_time_gap<R: Rng>(&self, rng: &mut R) -> f32 {
+        let easiness = 1.0 - self.difficulty.clamp(0.0, 1.0);
+        let spacing = 1.0 + QUICK_TIME_EASY_SPACING * easiness;
+        (QUICK_TIME_MIN_GAP + rng.r#gen::<f32>() * QUICK_TIME_GAP_SPREAD) * spacing
+    }
+
+    /// Count down the prompt on screen, surging the fish if it runs out, and
+    /// raise a new one when it's time.
+    fn update_quick_time<R: Rng>(&mut self, dt: f32, bindings: &KeyBindings, rng: &mut R) {
+        if let Some(event) = &mut self.quick_time {
+            event.time_left -= dt;
+            if event.time_left <= 0.0 {
+                self.quick_time = None;
+                self.quick_time_timer = self.quick_time_gap(rng);
+                self.fish_dir = if rng.r#gen::<bool>() { 1.0 } else { -1.0 };
+                self.fish_force = self.fish_aggression * QUICK_TIME_MISS_SURGE;
+                self.fish_change_timer = self.fish_change_timer.max(0.5);
+                self.tension_shake = 0.6;
             }
+            return;
+        }
+        self.quick_time_timer -= dt;
+        if self.quick_time_timer > 0.0 {
+            return;
+        }
+        let free: Vec<(KeyCode, &'static str)> = QUICK_TIME_KEYS
+            .into_iter()
+            .filter(|&(key, _)| !bindings.is_bound(key))
+            .collect();
+        let Some(&(key, label)) = free.choose(rng) else {
+            // Every prompt key is bound to something; don't try again
+            self.quick_time_timer = f32::INFINITY;
+            return;
+        };
+        let easiness = 1.0 - self.difficulty.clamp(0.0, 1.0);
+        self.quick_time = Some(QuickTimeEvent {
+            key,
+            label,
+            time_left: QUICK_TIME_WINDOW + QUICK_TIME_EASY_BONUS * easiness,
+        });
+    }
+
+    /// Reel in a burst if `key` answers the prompt on screen. Other keys
+    /// leave it waiting.
+    fn answer_quick_time(&mut self, key: KeyCode) {
+        if self.quick_time.is_some_and(|event| event.key == key) {
+            self.quick_time = None;
+            self.quick_time_timer = QUICK_TIME_MIN_GAP;
+            self.reel_progress = (self.reel_progress + QUICK_TIME_REEL_BONUS).min(REEL_TARGET);
+        }
+    }
+
+    /// Mixed behavior: occasional surges, direction swaps and gentle drifts.
+    fn steady_move<R: Rng>(&mut self, rng: &mut R) {
+        let base_interval = 0.8 - self.fish_erratic * 0.5; // 0.3 to 0.65s
+        self.fish_change_timer = rng.r#gen::<f32>() * base_interval + 0.15;
+
+        // Randomize direction and strength
+        let surge_chance: f32 = rng.r#gen();
+        if surge_chance < 0.2 {
+            // Big surge — sudden strong pull
+            self.fish_dir = if rng.r#gen::<bool>() { 1.0 } else { -1.0 };
+            self.fish_force = self.fish_aggression * (1.2 + rng.r#gen::<f32>() * 0.8);
+            self.tension_shake = 0.5;
+        } else if surge_chance < 0.5 {
+            // Direction swap with moderate force
+            self.fish_dir = -self.fish_dir;
+            self.fish_force = self.fish_aggression * (0.4 + rng.r#gen::<f32>() * 0.5);
+        } else {
+            // Gentle adjustment
+            self.fish_force = self.fish_aggression * (0.2 + rng.r#gen::<f32>() * 0.4);
+            // Slight random drift
+            self.fish_dir += (rng.r#gen::<f32>() - 0.5) * 0.4;
+            self.fish_dir = self.fish_dir.clamp(-1.0, 1.0);
         }
+    }
+
+    /// Darter: quick, frequent bursts with brief lulls between them.
+    fn darter_move<R: Rng>(&mut self, rng: &mut R) {
+        self.fish_change_timer = rng.r#gen::<f32>() * 0.2 + 0.15;
+        if rng.r#gen::<f32>() < 0.5 {
+            self.fish_dir = if rng.r#gen::<bool>() { 1.0 } else { -1.0 };
+            self.fish_force = self.fish_aggression * (1.0 + rng.r#gen::<f32>() * 0.5);
+            self.tension_shake = 0.3;
+        } else {
+            self.fish_force = self.fish_aggression * (0.1 + rng.r#gen::<f32>() * 0.2);
+        }
+    }
+
+    /// Lurker: lies almost still for a long time, then pulls hard.
+    fn lurker_move<R: Rng>(&mut self, rng: &mut R) {
+        if self.lurking {
+            self.lurking = false;
+            self.fish_change_timer = rng.r#gen::<f32>() * 0.3 + 0.4;
+            self.fish_dir = if rng.r#gen::<bool>() { 1.0 } else { -1.0 };
+            self.fish_force = self.fish_aggression * (1.6 + rng.r#gen::<f32>() * 0.6);
+            self.tension_shake = 0.6;
+        } else {
+            // More erratic lurkers strike sooner
+            self.lurking = true;
+            self.fish_change_timer = rng.r#gen::<f32>() * 1.0 + 1.8 - self.fish_erratic;
+            self.fish_force = self.fish_aggression * 0.05;
+        }
+    }
+
+    /// Thrasher: flips direction constantly with solid force.
+    fn thrasher_move<R: Rng>(&mut self, rng: &mut R) {
+        self.fish_change_timer = rng.r#gen::<f32>() * 0.18 + 0.12;
+        self.fish_dir = -self.fish_dir.signum();
+        self.fish_force = self.fish_aggression * (0.6 + rng.r#gen::<f32>() * 0.3);
+    }
+
+    fn update_reeling<R: Rng>(
+        &mut self,
+        dt: f32,
+        input: &InputState,
+        bindings: &KeyBindings,
+        quick_time_events: bool,
+        rng: &mut R,
+    ) {
+        // ── Process input ──
+        self.holding_left = bindings.held(Action::ReelLeft, input);
+        self.holding_right = bindings.held(Action::ReelRight, input);
 
         // Calculate player force from held keys
         let mut player_impulse: f32 = 0.0;
@@ -193,79 +691,81 @@ impl MinigameState {
         if self.holding_right {
             player_impulse += PLAYER_FORCE;
         }
-        // Reset holds each frame (keys are press-only, not held in this engine)
-        self.holding_left = false;
-        self.holding_right = false;
 
         // ── Update fish AI ──
         self.fish_change_timer -= dt;
         if self.fish_change_timer <= 0.0 {
-            // Fish changes behavior
-            let base_interval = 0.8 - self.fish_erratic * 0.5; // 0.3 to 0.65s
-            self.fish_change_timer = rng.r#gen::<f32>() * base_interval + 0.15;
-
-            // Randomize direction and strength
-            let surge_chance: f32 = rng.r#gen();
-            if surge_chance < 0.2 {
-                // Big surge — sudden strong pull
-                self.fish_dir = if rng.r#gen::<bool>() { 1.0 } else { -1.0 };
-                self.fish_force = self.fish_aggression * (1.2 + rng.r#gen::<f32>() * 0.8);
-                self.tension_shake = 0.5;
-            } else if surge_chance < 0.5 {
-                // Direction swap with moderate force
-                self.fish_dir = -self.fish_dir;
-                self.fish_force = self.fish_aggression * (0.4 + rng.r#gen::<f32>() * 0.5);
-            } else {
-                // Gentle adjustment
-                self.fish_force = self.fish_aggression * (0.2 + rng.r#gen::<f32>() * 0.4);
-                // Slight random drift
-                self.fish_dir += (rng.r#gen::<f32>() - 0.5) * 0.4;
-                self.fish_dir = self.fish_dir.clamp(-1.0, 1.0);
+            match self.personality {
+                FishPersonality::Steady => self.steady_move(rng),
+                FishPersonality::Darter => self.darter_move(rng),
+                FishPersonality::Lurker => self.lurker_move(rng),
+                FishPersonality::Thrasher => self.thrasher_move(rng),
             }
 
             // Fish tends to pull away from center (self-preservation)
-            if self.line_pos.abs() < 0.15 {
+            if self.line_pos.abs() < 0.15 && !self.lurking {
                 self.fish_dir = if rng.r#gen::<bool>() { 1.0 } else { -1.0 };
                 self.fish_force *= 1.3;
             }
         }
 
+        // ── Quick-time prompts ──
+        if quick_time_events {
+            self.update_quick_time(dt, bindings, rng);
+        }
+
         // ── Apply forces ──
-        let fish_accel = self.fish_dir * self.fish_force;
-        self.line_vel += (fish_accel + player_impulse) * dt;
+        // The fish fights harder near the end, but only up to a pull the
+        // player can still overpower
+        let boosted = self.fish_force * (1.0 + FINAL_PUSH_FORCE_BOOST * self.final_push());
+        let fish_force = boosted.min(self.fish_force.max(FINAL_PUSH_MAX_FORCE));
+        let fish_accel = self.fish_dir * fish_force;
+        // Clutch time slows the fish's pull to give the player a moment to react
+        self.update_clutch(dt);
+        let fish_dt = if self.in_clutch() { dt * CLUTCH_FORCE_SCALE } else { dt };
+        self.line_vel += fish_accel * fish_dt + player_impulse * dt;
 
         // Damping
         self.line_vel -= self.line_vel * VELOCITY_DAMPING * dt;
 
         // Integrate position
         self.line_pos += self.line_vel * dt;
+        if !self.line_snaps && self.line_pos.abs() >= SNAP_THRESHOLD {
+            // Casual: the line stops at the edge instead of breaking
+            self.line_pos = self.line_pos.clamp(-CASUAL_LINE_LIMIT, CASUAL_LINE_LIMIT);
+            self.line_vel = 0.0;
+        }
 
         // Tension shake decay
         self.tension_shake *= (1.0 - 4.0 * dt).max(0.0);
 
+        // Screen shake builds past the tension threshold and kicks on surges
+        let tension = self.line_pos.abs() / SNAP_THRESHOLD;
+        let from_tension =
+            ((tension - SHAKE_TENSION_START) / (1.0 - SHAKE_TENSION_START)).clamp(0.0, 1.0);
+        let faded = self.screen_shake * (1.0 - SHAKE_DECAY * dt).max(0.0);
+        self.screen_shake = faded.max(from_tension).max(self.tension_shake);
+
         // ── Reel progress ──
         let dist_from_center = self.line_pos.abs();
-        if dist_from_center < CENTER_ZONE {
+        if dist_from_center < self.center_zone {
             // In the sweet spot — reel in!
-            let efficiency = 1.0 - (dist_from_center / CENTER_ZONE);
+            let efficiency = 1.0 - (dist_from_center / self.center_zone);
             self.reel_progress += efficiency * dt;
         } else {
-            // Outside center — progress drains slowly
-            self.reel_progress = (self.reel_progress - REEL_DRAIN_RATE * dt).max(0.0);
+            // Outside center — progress drains slowly, faster in the final push
+            let drain = REEL_DRAIN_RATE * (1.0 + FINAL_PUSH_DRAIN_BOOST * self.final_push());
+            self.reel_progress = (self.reel_progress - drain * dt).max(0.0);
         }
 
         // ── Win/lose conditions ──
         if self.reel_progress >= REEL_TARGET {
-            // Fish caught! Determine size by how centered the player stayed.
+            // Fish caught! Size mostly comes down to how centered the player stayed
             let avg_accuracy = self.reel_progress / self.timer.max(0.1);
-            self.fish_size = if avg_accuracy > 0.85 {
-                FishSize::Large
-            } else if avg_accuracy > 0.5 {
-                FishSize::Medium
-            } else {
-                FishSize::Small
-            };
+            self.fish_size = determine_size(avg_accuracy, self.difficulty, self.weather.size_modifier());
+            self.shiny = roll_shiny(rng, self.fish_size);
             self.caught = true;
+            self.outcome_just_now = Some(CatchOutcome::Caught);
             self.phase = Phase::Result;
             self.timer = 0.0;
             return;
@@ -274,6 +774,8 @@ impl MinigameState {
         if dist_from_center >= SNAP_THRESHOLD {
             // Line snapped!
             self.caught = false;
+            self.outcome_just_now = Some(CatchOutcome::Snapped);
+            self.snapped_just_now = true;
             self.phase = Phase::Result;
             self.timer = 0.0;
             return;
@@ -282,63 +784,81 @@ impl MinigameState {
         // Timeout safety (30 seconds max)
         if self.timer > 30.0 {
             self.caught = false;
+            self.outcome_just_now = Some(CatchOutcome::TimedOut);
             self.phase = Phase::Result;
             self.timer = 0.0;
         }
     }
 
-    pub fn render(&self, renderer: &mut GameRenderer, time: f32, registry: &FishRegistry) {
-        let fish_name = self.fish_id.name_with_registry(registry);
-        let pond_name = if self.pond_index < ascii_art::POND_NAMES.len() {
-            ascii_art::POND_NAMES[self.pond_index].to_string()
+    /// Draw the minigame. `shake_scale` scales screen shake; 0 turns it off.
+    pub fn render(
+        &self,
+        renderer: &mut dyn DrawSurface,
+        time: f32,
+        registry: &FishRegistry,
+        shake_scale: f32,
+        meter: &MeterStyle,
+    ) {
+        let (fish_name, pond_name) = if self.mystery {
+            (t("???").to_string(), t(ascii_art::MYSTERY_POND_NAME))
         } else {
-            registry.pond_names()
-                .get(self.pond_index - ascii_art::POND_NAMES.len())
-                .map(|s| s.to_string())
-                .unwrap_or_else(|| "Unknown Pond".to_string())
+            (
+                self.fish_id.name_with_registry(registry),
+                registry.pond_name_at(self.pond_index).unwrap_or(t("Unknown Pond")),
+            )
         };
 
         renderer.draw_centered(
-            &format!("=== Fishing at {} ===", pond_name),
+            &tf("=== Fishing at {} ===", &[&pond_name]),
             1.0,
-            Colors::CYAN,
+            Colors::cyan(),
         );
+        renderer.draw_centered(&self.weather.status_line(), 2.0, self.weather.color());
 
         match self.phase {
             Phase::Casting => {
-                renderer.draw_multiline_centered(ascii_art::CASTING_ART, 4.0, Colors::WHITE);
-                renderer.draw_centered("Casting...", 14.0, Colors::YELLOW);
+                renderer.draw_multiline_centered(ascii_art::CASTING_ART, 4.0, Colors::white());
+                renderer.draw_centered(t("Casting..."), 14.0, Colors::yellow());
             }
             Phase::Waiting => {
-                renderer.draw_multiline_centered(ascii_art::CASTING_ART, 4.0, Colors::WHITE);
-                let dots = ".".repeat(((time * 3.0) as usize % 4) + 1);
+                renderer.draw_multiline_centered(ascii_art::CASTING_ART, 4.0, Colors::white());
+                let dots = ".".repeat(ui::anim_frame(renderer, time * 3.0) % 4 + 1);
                 renderer.draw_centered(
-                    &format!("Waiting for a bite{}", dots),
+                    &tf("Waiting for a bite{}", &[&dots]),
                     14.0,
-                    Colors::GRAY,
+                    Colors::gray(),
                 );
             }
             Phase::Reeling => {
-                self.render_reeling(renderer, time, &fish_name, registry);
+                let shake = ui::shake_offset(self.screen_shake * shake_scale, time);
+                self.render_reeling(renderer, time, &fish_name, registry, shake, meter);
             }
             Phase::Result => {
                 if self.caught {
-                    renderer.draw_multiline_centered(ascii_art::CATCH_SUCCESS, 4.0, Colors::GREEN);
+                    renderer.draw_multiline_centered(ascii_art::CATCH_SUCCESS, 4.0, Colors::green());
                     renderer.draw_centered(
-                        &format!("You caught {} ({})!", fish_name, self.fish_size.label()),
+                        &tf("You caught {} ({})!", &[&fish_name, &t(self.fish_size.label())]),
                         12.0,
-                        Colors::YELLOW,
+                        Colors::yellow(),
                     );
-                    renderer.draw_centered("[Enter] Continue", 14.0, Colors::WHITE);
+                    if self.shiny {
+                        let color = ui::rainbow_color(renderer, time);
+                        renderer.draw_centered(t("*** SHINY! ***"), 13.0, color);
+                    }
+                    renderer.draw_centered(t("[Enter] Continue"), 14.0, Colors::white());
                 } else {
-                    renderer.draw_multiline_centered(ascii_art::CATCH_FAIL, 4.0, Colors::RED);
+                    renderer.draw_multiline_centered(ascii_art::CATCH_FAIL, 4.0, Colors::red());
                     let msg = if self.line_pos.abs() >= SNAP_THRESHOLD {
                         "The line snapped!"
                     } else {
                         "The fish got away..."
                     };
-                    renderer.draw_centered(msg, 12.0, Colors::GRAY);
-                    renderer.draw_centered("[Enter] Try Again  [Esc] Back", 14.0, Colors::WHITE);
+                    renderer.draw_centered(t(msg), 12.0, Colors::gray());
+                    renderer.draw_centered(
+                        t("[Enter] Try Again  [R] Fish here again  [Esc] Back"),
+                        14.0,
+                        Colors::white(),
+                    );
                 }
             }
         }
@@ -346,29 +866,37 @@ impl MinigameState {
 
     fn render_reeling(
         &self,
-        renderer: &mut GameRenderer,
+        renderer: &mut dyn DrawSurface,
         time: f32,
         fish_name: &str,
         registry: &FishRegistry,
+        (sx, sy): (f32, f32),
+        meter: &MeterStyle,
     ) {
         let cols = renderer.screen_cols();
 
         // ── Header ──
-        renderer.draw_multiline_centered(ascii_art::FISH_ON_LINE, 3.0, Colors::YELLOW);
+        renderer.draw_multiline_centered(ascii_art::FISH_ON_LINE, 3.0 + sy, Colors::yellow());
 
-        let alert_flash = (time * 6.0).sin() * 0.3 + 0.7;
-        renderer.draw_centered(
-            "! FISH ON THE LINE !",
-            11.0,
-            [1.0, 0.3, 0.3, alert_flash],
-        );
+        let alert_flash = ui::wave(renderer, time * 6.0) * 0.3 + 0.7;
+        if let Some(event) = &self.quick_time {
+            let [r, g, b, _] = Colors::yellow();
+            renderer.draw_centered(
+                &tf("QUICK! Press [{}]! ({}s)", &[&event.label, &format!("{:.1}", event.time_left)]),
+                11.0 + sy,
+                [r, g, b, alert_flash],
+            );
+        } else {
+            let [r, g, b, _] = Colors::red();
+            renderer.draw_centered(t("! FISH ON THE LINE !"), 11.0 + sy, [r, g, b, alert_flash]);
+        }
 
         // ── Tug-of-war meter ──
-        let meter_row = 13.0;
-        self.draw_tug_meter(renderer, meter_row, time);
+        let meter_row = 13.0 + sy;
+        self.draw_tug_meter(renderer, meter_row, sx, time, meter);
 
         // ── Tension indicator ──
-        let tension = self.line_pos.abs() / SNAP_THRESHOLD;
+        let tension = self.display_line_pos().abs() / SNAP_THRESHOLD;
         let tension_label = if tension > 0.8 {
             "!!! EXTREME TENSION !!!"
         } else if tension > 0.6 {
@@ -379,50 +907,72 @@ impl MinigameState {
             "Line is steady"
         };
         let tension_color = if tension > 0.8 {
-            [1.0, 0.1, 0.1, (time * 8.0).sin().abs()]
+            let [r, g, b, _] = Colors::tension_extreme();
+            [r, g, b, ui::wave(renderer, time * 8.0).abs()]
         } else if tension > 0.6 {
-            Colors::ORANGE
+            Colors::tension_high()
         } else if tension > 0.35 {
-            Colors::YELLOW
+            Colors::tension_moderate()
         } else {
-            Colors::GREEN
+            Colors::tension_low()
         };
-        renderer.draw_centered(tension_label, meter_row + 2.0, tension_color);
+        if self.in_clutch() {
+            renderer.draw_centered(t("!CLUTCH!"), meter_row + 2.0, Colors::blue());
+            draw_clutch_vignette(renderer, time);
+        } else {
+            renderer.draw_centered(t(tension_label), meter_row + 2.0, tension_color);
+        }
+        if self.assisted {
+            renderer.draw_centered(t("The fish seems tired..."), meter_row + 3.0, Colors::dark_gray());
+        }
 
         // ── Reel progress bar ──
         let progress_row = meter_row + 4.0;
         let progress = (self.reel_progress / REEL_TARGET).clamp(0.0, 1.0);
-        renderer.draw_centered("REEL PROGRESS", progress_row, Colors::WHITE);
+        let final_push = self.final_push() > 0.0;
+        let push_flash = ui::wave(renderer, time * 8.0) > 0.0;
+        if final_push {
+            let color = if push_flash { Colors::orange() } else { Colors::yellow() };
+            renderer.draw_centered(t("FINAL PUSH!"), progress_row, color);
+        } else {
+            renderer.draw_centered(t("REEL PROGRESS"), progress_row, Colors::white());
+        }
+        let bar_color = if final_push && push_flash { Colors::orange() } else { Colors::cyan() };
         let bar_width = 40_usize;
         let bar_col = (cols as usize).saturating_sub(bar_width) / 2;
         crate::ui::draw_progress_bar(
             renderer,
-            bar_col as f32,
+            bar_col as f32 + sx,
             progress_row + 1.0,
             bar_width,
             progress,
-            Colors::CYAN,
-            Colors::DARK_GRAY,
+            bar_color,
+            Colors::dark_gray(),
         );
         let pct_str = format!("{}%", (progress * 100.0) as u32);
-        renderer.draw_centered(&pct_str, progress_row + 2.0, Colors::CYAN);
+        renderer.draw_centered(&pct_str, progress_row + 2.0, Colors::cyan());
 
         // ── Animated fish ──
         let fish_row = progress_row + 4.0;
-        let small_art = fish_helpers::fish_small_art(&self.fish_id, registry);
+        let small_art = if self.mystery {
+            ascii_art::MYSTERY_FISH_SMALL.to_string()
+        } else {
+            fish_helpers::fish_small_art(&self.fish_id, registry)
+        };
 
         // Fish visual position tracks the line position + wiggle
-        let wiggle = (time * 4.0).sin() * 0.5;
-        let fish_visual_x = cols / 2.0 + self.line_pos * (METER_WIDTH as f32 / 2.0 - 4.0) + wiggle;
+        let wiggle = ui::wave(renderer, time * 4.0) * 0.5;
+        let fish_visual_x =
+            cols / 2.0 + self.display_line_pos() * (METER_WIDTH as f32 / 2.0 - 4.0) + wiggle + sx;
         renderer.draw_at_grid(
             &small_art,
             fish_visual_x - 2.0,
             fish_row,
-            self.fish_id.color(),
+            if self.mystery { Colors::gray() } else { self.fish_id.color_with_registry(registry) },
         );
 
         // Animated water below fish
-        let wave = if ((time * 3.0) as i32) % 2 == 0 {
+        let wave = if ui::anim_frame(renderer, time * 3.0).is_multiple_of(2) {
             "~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~"
         } else {
             " ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~"
@@ -431,82 +981,93 @@ impl MinigameState {
 
         // ── Controls hint ──
         renderer.draw_centered(
-            &format!("Reel in {}!", fish_name),
+            &tf("Reel in {}!", &[&fish_name]),
             fish_row + 3.0,
-            Colors::WHITE,
+            Colors::white(),
         );
         renderer.draw_centered(
-            "[A/Left] Pull left  [D/Right] Pull right  [Esc] Cut line",
+            t("[A/Left] Pull left  [D/Right] Pull right  [H] Help  [Esc] Cut line"),
             fish_row + 4.0,
-            Colors::DARK_GRAY,
+            Colors::dark_gray(),
         );
     }
 
+    /// Whether meter cell `i` of `inner` lies in the reeling zone. Uses the
+    /// same mapping as the line cursor, so the green zone matches scoring.
+    fn cell_in_zone(&self, i: usize, inner: usize) -> bool {
+        let pos = (i as f32 + 0.5) / (inner - 1) as f32 * 2.0 - 1.0;
+        pos.abs() < self.center_zone
+    }
+
     /// Draw the centered tug-of-war meter.
-    fn draw_tug_meter(&self, renderer: &mut GameRenderer, row: f32, time: f32) {
+    fn draw_tug_meter(
+        &self,
+        renderer: &mut dyn DrawSurface,
+        row: f32,
+        sx: f32,
+        time: f32,
+        style: &MeterStyle,
+    ) {
         let cols = renderer.screen_cols() as usize;
         let bar_start = cols.saturating_sub(METER_WIDTH) / 2;
+        let bar_col = bar_start as f32 + sx;
         let inner = METER_WIDTH - 2;
         let half = inner / 2;
 
-        // Center zone boundaries (in bar-character indices)
-        let zone_chars = (CENTER_ZONE * half as f32) as usize;
-        let zone_left = half - zone_chars;
-        let zone_right = half + zone_chars;
-
         // Line indicator position (mapped from -1..1 to 0..inner-1)
-        let shake = if self.tension_shake > 0.05 {
+        let shake = if self.tension_shake > 0.05 && !renderer.reduce_motion() {
             (time * 40.0).sin() * self.tension_shake * 2.0
         } else {
             0.0
         };
-        let mapped = ((self.line_pos + shake * 0.02) * 0.5 + 0.5).clamp(0.0, 1.0);
+        let mapped = ((self.display_line_pos() + shake * 0.02) * 0.5 + 0.5).clamp(0.0, 1.0);
         let cursor_idx = (mapped * (inner - 1) as f32) as usize;
 
         // ── Draw danger zone markers ──
-        let danger_left = "<<< SNAP";
-        let danger_right = "SNAP >>>";
-        let tension = self.line_pos.abs() / SNAP_THRESHOLD;
+        let danger_left = t("<<< SNAP");
+        let danger_right = t("SNAP >>>");
+        let tension = self.display_line_pos().abs() / SNAP_THRESHOLD;
         let danger_alpha = if tension > 0.6 {
-            (time * 6.0).sin().abs()
+            ui::wave(renderer, time * 6.0).abs()
         } else {
             0.3
         };
+        let [r, g, b, _] = Colors::tension_extreme();
         renderer.draw_at_grid(
             danger_left,
-            (bar_start as f32) - danger_left.len() as f32 - 1.0,
+            bar_col - danger_left.chars().count() as f32 - 1.0,
             row,
-            [1.0, 0.2, 0.2, danger_alpha],
+            [r, g, b, danger_alpha],
         );
         renderer.draw_at_grid(
             danger_right,
-            (bar_start + METER_WIDTH) as f32 + 1.0,
+            bar_col + METER_WIDTH as f32 + 1.0,
             row,
-            [1.0, 0.2, 0.2, danger_alpha],
+            [r, g, b, danger_alpha],
         );
 
         // ── Build base bar ──
         let mut bar = String::with_capacity(METER_WIDTH);
-        bar.push('[');
+        bar.push(style.left_cap);
         for i in 0..inner {
             if i == cursor_idx {
-                bar.push('|');
+                bar.push(style.cursor);
             } else if i == half {
-                bar.push('+');
-            } else if i >= zone_left && i <= zone_right {
-                bar.push('=');
+                bar.push(style.center);
+            } else if self.cell_in_zone(i, inner) {
+                bar.push(style.zone);
             } else {
-                bar.push('-');
+                bar.push(style.track);
             }
         }
-        bar.push(']');
-        renderer.draw_at_grid(&bar, bar_start as f32, row, Colors::DARK_GRAY);
+        bar.push(style.right_cap);
+        renderer.draw_at_grid(&bar, bar_col, row, Colors::dark_gray());
 
         // ── Overdraw center zone in green ──
         let zone_overlay: String = (0..inner)
             .map(|i| {
-                if i >= zone_left && i <= zone_right {
-                    if i == half { '+' } else { '=' }
+                if self.cell_in_zone(i, inner) {
+                    if i == half { style.center } else { style.zone }
                 } else {
                     ' '
                 }
@@ -514,31 +1075,191 @@ impl MinigameState {
             .collect();
         renderer.draw_at_grid(
             &format!(" {}", zone_overlay),
-            bar_start as f32,
+            bar_col,
             row,
-            Colors::GREEN,
+            Colors::green(),
         );
 
         // ── Overdraw cursor ──
-        let in_zone = cursor_idx >= zone_left && cursor_idx <= zone_right;
-        let cursor_color = if in_zone {
-            Colors::CYAN
+        let cursor_color = if self.display_line_pos().abs() < self.center_zone {
+            Colors::cyan()
         } else if tension > 0.7 {
             [1.0, 0.2, 0.2, 1.0]
         } else {
-            Colors::YELLOW
+            Colors::yellow()
         };
         let cursor_overlay: String = (0..inner)
-            .map(|i| if i == cursor_idx { '|' } else { ' ' })
+            .map(|i| if i == cursor_idx { style.cursor } else { ' ' })
             .collect();
         renderer.draw_at_grid(
             &format!(" {}", cursor_overlay),
-            bar_start as f32,
+            bar_col,
             row,
             cursor_color,
         );
 
         // ── Center marker label ──
-        renderer.draw_centered("v CENTER v", row - 1.0, [0.5, 0.8, 0.5, 0.6]);
+        renderer.draw_centered(t("v CENTER v"), row - 1.0, [0.5, 0.8, 0.5, 0.6]);
+    }
+}
+
+/// Pulse blue bars down both screen edges during clutch time.
+fn draw_clutch_vignette(renderer: &mut dyn DrawSurface, time: f32) {
+    let alpha = 0.35 + 0.25 * ui::wave(renderer, time * 6.0).abs();
+    let [r, g, b, _] = Colors::blue();
+    let color = [r, g, b, alpha];
+    let right = renderer.screen_cols() - 2.0;
+    let rows = renderer.screen_rows() as usize;
+    for row in 0..rows {
+        renderer.draw_at_grid("||", 0.0, row as f32, color);
+        renderer.draw_at_grid("||", right, row as f32, color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use super::*;
+    use crate::data::PlayerState;
+    use crate::game::game_rng;
+
+    /// A fight with Bubbles in calm weather, `failures` losses in.
+    fn fight(failures: u32, seed: u64) -> MinigameState {
+        let registry = FishRegistry::new();
+        let mut rng = StdRng::seed_from_u64(seed);
+        MinigameState::new(FishId::Bubbles, &registry, Weather::Calm, GameMode::Normal, failures, &mut rng)
+    }
+
+    #[test]
+    fn losing_streak_eases_the_next_fight() {
+        let mut player = PlayerState::default();
+        for _ in 0..3 {
+            player.record_catch_attempt(&FishId::Bubbles, false);
+        }
+        let failures = player.recent_failures.get(&FishId::Bubbles).copied().unwrap_or(0);
+        assert_eq!(failures, 3);
+
+        let fresh = fight(0, 1);
+        let assisted = fight(failures, 1);
+        assert!(assisted.fish_aggression < fresh.fish_aggression);
+        assert!(assisted.assisted);
+
+        player.record_catch_attempt(&FishId::Bubbles, true);
+        assert!(!player.recent_failures.contains_key(&FishId::Bubbles));
+    }
+
+    #[test]
+    fn size_boundaries() {
+        assert_eq!(determine_size(LARGE_SIZE_SCORE, 0.0, 0.0), FishSize::Large);
+        assert_eq!(determine_size(LARGE_SIZE_SCORE - 0.01, 0.0, 0.0), FishSize::Medium);
+        assert_eq!(determine_size(MEDIUM_SIZE_SCORE, 0.0, 0.0), FishSize::Medium);
+        assert_eq!(determine_size(MEDIUM_SIZE_SCORE - 0.01, 0.0, 0.0), FishSize::Small);
+        assert_eq!(determine_size(1.0, 0.0, 0.0), FishSize::Large);
+        assert_eq!(determine_size(0.0, 1.0, 0.0), FishSize::Small);
+    }
+
+    #[test]
+    fn harder_fish_and_modifiers_shift_the_size() {
+        // Hard fish need less precision for a Large
+        assert_eq!(determine_size(0.75, 0.0, 0.0), FishSize::Medium);
+        assert_eq!(determine_size(0.75, 1.0, 0.0), FishSize::Large);
+        // Difficulty past 1.0 counts as 1.0
+        assert_eq!(determine_size(0.6, 5.0, 0.0), FishSize::Medium);
+        // Weather can push a catch down a size
+        assert_eq!(determine_size(0.9, 0.0, -0.1), FishSize::Medium);
+    }
+
+    /// Run `frames` through a fight already on the line, with nothing held.
+    fn reel(frames: &[f32]) -> MinigameState {
+        let mut state = fight(0, 2);
+        state.phase = Phase::Reeling;
+        let input = InputState::new();
+        let bindings = KeyBindings::default();
+        let mut rng = StdRng::seed_from_u64(3);
+        for &dt in frames {
+            state.update(dt, &input, &bindings, true, false, &mut rng);
+        }
+        state
+    }
+
+    #[test]
+    fn fixed_timestep_ignores_frame_rate() {
+        // The short last frame soaks up float rounding in the 0.01 s sums,
+        // so both runs take the same number of physics steps
+        let slow = reel(&[0.1, 0.001]);
+        let mut frames = vec![0.01; 10];
+        frames.push(0.001);
+        let fast = reel(&frames);
+        assert_eq!(slow.line_pos, fast.line_pos);
+        assert_eq!(slow.reel_progress, fast.reel_progress);
+    }
+
+    /// Play a fight from the cast with `seed`, pulling left, then right, then
+    /// letting go in half-second turns. Returns the line position, fish
+    /// force and reel progress after every frame.
+    fn scripted_fight(seed: u64) -> Vec<(f32, f32, f32)> {
+        let registry = FishRegistry::new();
+        let bindings = KeyBindings::default();
+        let mut rng = game_rng(Some(seed));
+        let mut state =
+            MinigameState::new(FishId::Bubbles, &registry, Weather::Calm, GameMode::Normal, 0, &mut rng);
+        let mut input = InputState::new();
+        (0..1200)
+            .map(|frame| {
+                match frame / 30 % 3 {
+                    0 => input.press(KeyCode::KeyA),
+                    1 => {
+                        input.release(KeyCode::KeyA);
+                        input.press(KeyCode::KeyD);
+                    }
+                    _ => input.clear_held(),
+                }
+                state.update(1.0 / 60.0, &input, &bindings, true, true, &mut rng);
+                input.end_frame();
+                (state.line_pos, state.fish_force, state.reel_progress)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn same_seed_plays_out_the_same() {
+        let first = scripted_fight(797);
+        let second = scripted_fight(797);
+        for (frame, (a, b)) in first.iter().zip(&second).enumerate() {
+            assert_eq!(a, b, "fights split on frame {}", frame);
+        }
+        // The fish actually fought back at some point
+        assert!(first.iter().any(|&(line_pos, _, _)| line_pos != 0.0));
+    }
+
+    #[test]
+    fn clutch_time_waits_out_its_cooldown() {
+        let mut state = fight(0, 4);
+        state.line_pos = CLUTCH_THRESHOLD + 0.05;
+        state.update_clutch(0.01);
+        assert!(state.in_clutch());
+
+        // Clutch time runs out with the line still at the edge
+        state.update_clutch(CLUTCH_DURATION);
+        assert!(!state.in_clutch());
+        state.update_clutch(0.01);
+        assert!(!state.in_clutch());
+
+        // Once the cooldown passes it can trigger again
+        state.update_clutch(CLUTCH_COOLDOWN);
+        assert!(state.in_clutch());
+    }
+
+    #[test]
+    fn clutch_time_is_off_in_hardcore() {
+        let registry = FishRegistry::new();
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut state =
+            MinigameState::new(FishId::Bubbles, &registry, Weather::Calm, GameMode::Hardcore, 0, &mut rng);
+        state.line_pos = CLUTCH_THRESHOLD + 0.05;
+        state.update_clutch(0.01);
+        assert!(!state.in_clutch());
     }
 }
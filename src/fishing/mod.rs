@@ -2,6 +2,8 @@
 
 pub mod minigame;
 pub mod pond;
+pub mod weather;
 
-pub use minigame::MinigameState;
+pub use minigame::{CatchOutcome, MinigameState};
 pub use pond::PondSelectState;
+pub use weather::Weather;
@@ -1,80 +1,196 @@
 //! Pond selection screen.
 
+use std::collections::HashMap;
+
+use rand::Rng;
 use winit::keyboard::KeyCode;
 
 use crate::ascii_art;
-use crate::data::FishId;
+use crate::data::{FishId, GameMode, PlayerState};
+use crate::fishing::Weather;
+use crate::fishing::weather::day_hash;
 use crate::game::GameScreen;
+use crate::i18n::{t, tf};
+use crate::input::{Action, KeyBindings};
 use crate::plugins::FishRegistry;
-use crate::render::{Colors, GameRenderer};
+use crate::render::Colors;
+use crate::surface::{centered_start_col, DrawSurface};
+use crate::ui;
 use crate::ui::menu::SelectionMenu;
 
+/// Pick which resident of a pond bites. Easier fish bite more often.
+pub fn pick_resident<R: Rng>(
+    residents: &[FishId],
+    registry: &FishRegistry,
+    rng: &mut R,
+) -> Option<FishId> {
+    let weights: Vec<f32> = residents
+        .iter()
+        .map(|f| 1.1 - f.difficulty_with_registry(registry).clamp(0.0, 1.0))
+        .collect();
+    let total: f32 = weights.iter().sum();
+    let mut roll = rng.r#gen::<f32>() * total;
+    for (fish_id, weight) in residents.iter().zip(&weights) {
+        if roll < *weight {
+            return Some(fish_id.clone());
+        }
+        roll -= weight;
+    }
+    residents.last().cloned()
+}
+
+/// Pick the fish that bites in the mystery pond: any known fish, built-in
+/// or plugin, with easier fish biting more often.
+pub fn pick_mystery<R: Rng>(registry: &FishRegistry, rng: &mut R) -> Option<FishId> {
+    pick_resident(&FishId::all_with_plugins(registry), registry, rng)
+}
+
+/// Width of the pond overview panel, border included.
+const OVERVIEW_WIDTH: usize = 26;
+/// Rows the overview panel spans, alongside the pond scene.
+const OVERVIEW_ROWS: std::ops::Range<usize> = 3..17;
+/// Narrowest screen that fits the overview beside the pond scene.
+const OVERVIEW_MIN_COLS: f32 = 90.0;
+
+/// Affection bonus for catching a fish in today's special pond.
+pub const SPECIAL_POND_BONUS: i32 = 3;
+
+/// Stars in the difficulty rating under the pond rumor.
+const DIFFICULTY_STARS: u32 = 5;
+
+/// Which of `pond_count` ponds is today's special, if there are any ponds.
+pub fn special_pond_index(day: u32, pond_count: usize) -> Option<usize> {
+    if pond_count == 0 {
+        return None;
+    }
+    Some((day_hash(day, 1) % pond_count as u64) as usize)
+}
+
 pub struct PondSelectState {
     menu: SelectionMenu,
-    /// Mapping from menu index to FishId.
-    fish_map: Vec<FishId>,
+    /// Mapping from menu index to the fish living in that pond.
+    fish_map: Vec<Vec<FishId>>,
+    /// Today's weather.
+    weather: Weather,
+    /// Menu index of today's special pond.
+    special: Option<usize>,
+    /// Menu index of the mystery pond, listed after every real pond.
+    mystery: usize,
+    /// The save's game mode, passed on to the minigame.
+    mode: GameMode,
+    /// Fights lost in a row per fish, for the fishing assist. Empty when
+    /// the assist is off.
+    recent_failures: HashMap<FishId, u32>,
 }
 
 impl PondSelectState {
-    pub fn new(registry: &FishRegistry) -> Self {
+    pub fn new(
+        registry: &FishRegistry,
+        weather: Weather,
+        day: u32,
+        mode: GameMode,
+        recent_failures: HashMap<FishId, u32>,
+    ) -> Self {
         let mut pond_names: Vec<String> = ascii_art::POND_NAMES
             .iter()
             .map(|s| s.to_string())
             .collect();
-        let mut fish_map: Vec<FishId> = Vec::new();
+        let mut fish_map: Vec<Vec<FishId>> = Vec::new();
 
         // Map built-in ponds to fish
         for fish_id in &FishId::BUILTIN {
-            fish_map.push(fish_id.clone());
+            fish_map.push(vec![fish_id.clone()]);
         }
 
-        // Add plugin fish ponds
-        for plugin_id in registry.plugin_ids() {
-            if let Some(fish) = registry.get(plugin_id) {
-                pond_names.push(fish.pond_name.clone());
-                fish_map.push(FishId::Plugin(plugin_id.clone()));
-            }
+        // Add plugin ponds, which may be shared by several fish
+        for pond in registry.ponds() {
+            pond_names.push(pond.name.clone());
+            fish_map.push(pond.fish_ids.iter().cloned().map(FishId::Plugin).collect());
+        }
+
+        let special = special_pond_index(day, fish_map.len());
+        if let Some(name) = special.and_then(|i| pond_names.get_mut(i)) {
+            name.push_str(" *");
         }
+        let mystery = pond_names.len();
+        pond_names.push(ascii_art::MYSTERY_POND_NAME.to_string());
 
         Self {
             menu: SelectionMenu::new(pond_names),
             fish_map,
+            weather,
+            special,
+            mystery,
+            mode,
+            recent_failures,
         }
     }
 
-    pub fn update(&mut self, key: KeyCode) -> Option<GameScreen> {
-        match key {
-            KeyCode::ArrowUp | KeyCode::KeyW => {
+    /// The pond menu, for mouse selection.
+    pub fn menu_mut(&mut self) -> &mut SelectionMenu {
+        &mut self.menu
+    }
+
+    pub fn update<R: Rng>(
+        &mut self,
+        key: KeyCode,
+        bindings: &KeyBindings,
+        registry: &FishRegistry,
+        rng: &mut R,
+    ) -> Option<GameScreen> {
+        match bindings.menu_action(key) {
+            Some(Action::MenuUp) => {
                 self.menu.move_up();
                 None
             }
-            KeyCode::ArrowDown | KeyCode::KeyS => {
+            Some(Action::MenuDown) => {
                 self.menu.move_down();
                 None
             }
-            KeyCode::Enter | KeyCode::Space => {
-                let pond_idx = self.menu.selected_index();
-                if let Some(fish_id) = self.fish_map.get(pond_idx) {
-                    Some(GameScreen::FishingMinigame(
-                        crate::fishing::MinigameState::new(fish_id.clone(), pond_idx),
-                    ))
+            Some(Action::Confirm) => {
+                let selected = self.menu.selected_index();
+                let mystery = selected == self.mystery;
+                let fish_id = if mystery {
+                    pick_mystery(registry, rng)?
                 } else {
-                    None
-                }
+                    pick_resident(self.fish_map.get(selected)?, registry, rng)?
+                };
+                let failures = self.recent_failures.get(&fish_id).copied().unwrap_or(0);
+                let state = crate::fishing::MinigameState::new(
+                    fish_id,
+                    registry,
+                    self.weather,
+                    self.mode,
+                    failures,
+                    rng,
+                );
+                Some(GameScreen::FishingMinigame(if mystery { state.into_mystery() } else { state }))
             }
-            KeyCode::Escape => Some(GameScreen::MainMenu),
+            Some(Action::Back) => Some(GameScreen::MainMenu),
             _ => None,
         }
     }
 
-    pub fn render(&self, renderer: &mut GameRenderer, time: f32, registry: &FishRegistry) {
-        renderer.draw_centered("=== CHOOSE A FISHING SPOT ===", 1.0, Colors::CYAN);
+    pub fn render(
+        &self,
+        renderer: &mut dyn DrawSurface,
+        time: f32,
+        registry: &FishRegistry,
+        player: &PlayerState,
+    ) {
+        renderer.draw_centered(t("=== CHOOSE A FISHING SPOT ==="), 1.0, Colors::cyan());
+        renderer.draw_centered(&self.weather.status_line(), 2.0, self.weather.color());
 
         // Animated pond scene
-        renderer.draw_multiline_centered(ascii_art::POND_SCENE, 3.0, Colors::LIGHT_BLUE);
+        let pond_idx = self.menu.selected_index();
+        if pond_idx == self.mystery {
+            renderer.draw_multiline_centered(ascii_art::MYSTERY_POND_SCENE, 3.0, Colors::purple());
+        } else {
+            renderer.draw_multiline_centered(ascii_art::POND_SCENE, 3.0, Colors::light_blue());
+        }
 
         // Animate water
-        let wave_offset = ((time * 2.0).sin() * 2.0) as i32;
+        let wave_offset = (ui::wave(renderer, time * 2.0) * 2.0) as i32;
         let wave = if wave_offset > 0 { "~~ " } else { " ~~" };
         renderer.draw_centered(
             &wave.repeat(15),
@@ -82,19 +198,133 @@ impl PondSelectState {
             [0.2, 0.4, 0.8, 0.5],
         );
 
+        if let Some(name) = self.special.and_then(|i| registry.pond_name_at(i)) {
+            let banner = tf(
+                "* TODAY'S SPECIAL: {} (+{} affection per catch) *",
+                &[&name, &SPECIAL_POND_BONUS],
+            );
+            renderer.draw_centered(&banner, 17.0, Colors::yellow());
+        }
+
         // Pond selection
-        renderer.draw_centered("Select a pond:", 18.0, Colors::WHITE);
+        renderer.draw_centered(t("Select a pond:"), 18.0, Colors::white());
         self.menu.draw_centered(renderer, 20.0);
 
         // Fish hint for selected pond
-        let pond_idx = self.menu.selected_index();
-        if let Some(fish_id) = self.fish_map.get(pond_idx) {
-            let name = fish_id.name_with_registry(registry);
-            let species = fish_id.species_with_registry(registry);
-            let hint = format!("Rumor has it {} ({}) swims here...", name, species);
-            renderer.draw_centered(&hint, 24.0, Colors::GRAY);
+        if pond_idx == self.mystery {
+            renderer.draw_centered(t("Anything could be biting here..."), 24.0, Colors::gray());
         }
+        match self.fish_map.get(pond_idx).map(Vec::as_slice) {
+            Some([fish_id]) => {
+                let name = fish_id.name_with_registry(registry);
+                let species = fish_id.species_with_registry(registry);
+                let hint = tf("Rumor has it {} ({}) swims here...", &[&name, &species]);
+                renderer.draw_centered(&hint, 24.0, Colors::gray());
+            }
+            Some(residents) if !residents.is_empty() => {
+                let names: Vec<String> = residents
+                    .iter()
+                    .map(|f| f.name_with_registry(registry))
+                    .collect();
+                let hint = tf("Rumor has it {} swim here...", &[&(names.join(", "))]);
+                renderer.draw_centered(&hint, 24.0, Colors::gray());
+            }
+            _ => {}
+        }
+
+        // Rate the pond by its hardest resident
+        let hardest = self.fish_map.get(pond_idx).and_then(|residents| {
+            residents
+                .iter()
+                .map(|f| f.difficulty_with_registry(registry))
+                .max_by(f32::total_cmp)
+        });
+        if let Some(difficulty) = hardest {
+            let label = t("Difficulty:");
+            let width = label.chars().count() + 1 + DIFFICULTY_STARS as usize;
+            let col = centered_start_col(renderer.screen_cols(), width as f32);
+            renderer.draw_at_grid(label, col, 25.0, Colors::gray());
+            let stars_col = col + label.chars().count() as f32 + 1.0;
+            ui::draw_difficulty_stars(renderer, stars_col, 25.0, difficulty, DIFFICULTY_STARS);
+        }
+
+        renderer.draw_centered(t("[Enter] Cast  [Esc] Back"), 26.0, Colors::dark_gray());
+
+        if renderer.screen_cols() >= OVERVIEW_MIN_COLS {
+            self.render_overview(renderer, registry, player);
+        }
+    }
+
+    /// Panel down the left side listing every pond, checked once all its
+    /// fish are caught, with the best size landed there.
+    fn render_overview(&self, renderer: &mut dyn DrawSurface, registry: &FishRegistry, player: &PlayerState) {
+        let col = 1.0;
+        let top = OVERVIEW_ROWS.start;
+        let height = OVERVIEW_ROWS.len();
+        ui::draw_box(renderer, col, top as f32, OVERVIEW_WIDTH, height, Colors::dark_gray());
+        renderer.draw_at_grid(t(" PONDS "), col + 2.0, top as f32, Colors::cyan());
+
+        // Scroll so the selected pond stays in view
+        let visible = height - 2;
+        let selected = self.menu.selected_index();
+        let first = selected.saturating_sub(visible - 1);
+
+        let size_width = 6;
+        let name_width = OVERVIEW_WIDTH - 4 - 4 - size_width;
+        for (row, (i, residents)) in self.fish_map.iter().enumerate().skip(first).take(visible).enumerate() {
+            let caught = !residents.is_empty() && residents.iter().all(|f| player.has_caught(f));
+            let best = residents.iter().filter_map(|f| player.best_size(f)).max();
+
+            let mark = if caught { "[x]" } else { "[ ]" };
+            let name = registry.pond_name_at(i).map(t).unwrap_or_default();
+            let name = ui::truncate_to_width(name, name_width);
+            let size = best.map(|s| t(s.label())).unwrap_or_default();
+            let line = format!("{} {:<name_width$} {:>size_width$}", mark, name, size);
+
+            let color = if i == selected {
+                Colors::yellow()
+            } else if caught {
+                Colors::white()
+            } else {
+                Colors::gray()
+            };
+            renderer.draw_at_grid(&line, col + 2.0, (top + 1 + row) as f32, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use super::*;
+
+    #[test]
+    fn mystery_pick_is_seedable() {
+        let registry = FishRegistry::new();
+        let picks = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..20).map(|_| pick_mystery(&registry, &mut rng)).collect::<Vec<_>>()
+        };
+        assert_eq!(picks(7), picks(7));
+    }
+
+    #[test]
+    fn mystery_pick_is_a_known_fish() {
+        let registry = FishRegistry::new();
+        let known = FishId::all_with_plugins(&registry);
+        let mut rng = StdRng::seed_from_u64(853);
+        for _ in 0..100 {
+            let fish_id = pick_mystery(&registry, &mut rng).expect("some fish bites");
+            assert!(known.contains(&fish_id));
+        }
+    }
 
-        renderer.draw_centered("[Enter] Cast  [Esc] Back", 26.0, Colors::DARK_GRAY);
+    #[test]
+    fn special_pond_is_fixed_per_day() {
+        let week: Vec<Option<usize>> = (1..=7).map(|day| special_pond_index(day, 5)).collect();
+        assert_eq!(week, [Some(2), Some(4), Some(3), Some(4), Some(3), Some(1), Some(3)]);
+        assert_eq!(special_pond_index(3, 0), None);
     }
 }
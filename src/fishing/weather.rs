@@ -0,0 +1,116 @@
+//! Daily weather that nudges how hard fish fight.
+
+use crate::i18n::{t, tf};
+use crate::render::Colors;
+
+/// Stable hash of a day number, so daily rolls don't depend on build or
+/// platform. `stream` keeps unrelated daily rolls from lining up.
+pub fn day_hash(day: u32, stream: u32) -> u64 {
+    // SplitMix64 finalizer
+    let mut x = ((stream as u64) << 32 | day as u64).wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+/// Weather conditions for a single in-game day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Weather {
+    Calm,
+    Choppy,
+    Stormy,
+}
+
+impl Weather {
+    /// Weather for a given day.
+    ///
+    /// Derived from `day_hash` so the same save day always shows the same
+    /// weather.
+    pub fn for_day(day: u32) -> Self {
+        match day_hash(day, 0) % 10 {
+            0..=4 => Weather::Calm,
+            5..=7 => Weather::Choppy,
+            _ => Weather::Stormy,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Weather::Calm => "Calm",
+            Weather::Choppy => "Choppy",
+            Weather::Stormy => "Stormy",
+        }
+    }
+
+    /// Short flavor text describing the effect on fishing.
+    pub fn hint(&self) -> &'static str {
+        match self {
+            Weather::Calm => "the fish are sluggish",
+            Weather::Choppy => "the fish are restless",
+            Weather::Stormy => "the fish fight hard",
+        }
+    }
+
+    /// Multiplier applied to fish aggression and erratic movement.
+    pub fn difficulty_multiplier(&self) -> f32 {
+        match self {
+            Weather::Calm => 0.8,
+            Weather::Choppy => 1.0,
+            Weather::Stormy => 1.3,
+        }
+    }
+
+    /// Added to a catch's size score: rough water stirs up bigger fish.
+    pub fn size_modifier(&self) -> f32 {
+        match self {
+            Weather::Calm => 0.0,
+            Weather::Choppy => 0.03,
+            Weather::Stormy => 0.08,
+        }
+    }
+
+    pub fn color(&self) -> [f32; 4] {
+        match self {
+            Weather::Calm => Colors::light_blue(),
+            Weather::Choppy => Colors::cyan(),
+            Weather::Stormy => Colors::purple(),
+        }
+    }
+
+    /// One-line status shown on fishing screens.
+    pub fn status_line(&self) -> String {
+        tf("Weather: {} - {}", &[&t(self.label()), &t(self.hint())])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_day_same_weather() {
+        for day in 0..365 {
+            assert_eq!(Weather::for_day(day), Weather::for_day(day));
+        }
+    }
+
+    #[test]
+    fn day_hash_is_stable_across_builds() {
+        // SplitMix64 reference output for a zero seed
+        assert_eq!(day_hash(0, 0), 0xe220_a839_7b1d_cdaf);
+        assert_eq!(day_hash(1, 0), 0x910a_2dec_8902_5cc1);
+        let week: Vec<Weather> = (1..=7).map(Weather::for_day).collect();
+        assert_eq!(
+            week,
+            [
+                Weather::Choppy,
+                Weather::Calm,
+                Weather::Calm,
+                Weather::Stormy,
+                Weather::Stormy,
+                Weather::Calm,
+                Weather::Choppy,
+            ]
+        );
+    }
+}
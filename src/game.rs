@@ -1,117 +1,685 @@
 //! Top-level game state machine and screen management.
 
+use std::cell::Cell;
+use std::collections::HashMap;
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
 use winit::keyboard::KeyCode;
 
 use crate::achievements::AchievementTracker;
 use crate::ascii_art;
-use crate::data::{FishId, FishSize, PlayerState, relationship_label};
+use crate::audio::{AudioManager, Sfx, Track};
+use crate::cutscene::CutsceneState;
+use crate::data::{
+    ACTIONS_PER_DAY, Chemistry, DAILY_LOGIN_BONUS, DEFAULT_PLAYER_NAME, DailyWelcome, FishId, FishSize, GameMode, MAX_PLAYER_NAME_LEN, MAX_STREAK_BONUS, PlayerState,
+    relationship_label,
+};
 use crate::data::save;
 use crate::dating::DatingState;
+use crate::dating::replay::{self, DateReplay, REPLAY_MIN_AFFECTION};
+use crate::dating::scene::{FAVORITE_GIFT_MULTIPLIER, affection_change_line};
 use crate::dating::fish as fish_helpers;
-use crate::easter_egg::{MoonBattleState, SecretSequence};
-use crate::fishing::{MinigameState, PondSelectState};
-use crate::plugins::FishRegistry;
-use crate::render::{Colors, GameRenderer};
+use crate::easter_egg::{MOON_SECRET, MoonBattleState, SecretSequence};
+use crate::fishing::pond::{pick_mystery, special_pond_index, SPECIAL_POND_BONUS};
+use crate::fishing::minigame::MeterStyle;
+use crate::fishing::{CatchOutcome, MinigameState, PondSelectState, Weather};
+use crate::i18n::{t, tf};
+use crate::input::{Action, InputState, KeyBindings};
+use crate::plugins::loader::find_scripts;
+use crate::plugins::manifest::PluginManifest;
+use crate::plugins::{FishRegistry, PluginLoadReport};
+use crate::render::Colors;
+use crate::settings::Settings;
+use crate::surface::{centered_start_col, DrawSurface};
 use crate::ui;
+use crate::ui::markup;
 use crate::ui::menu::SelectionMenu;
+use crate::ui::text_input::TextInput;
+
+/// Longest frame counted towards playtime, so a minimized or stalled
+/// window doesn't add a huge jump in one step.
+const MAX_PLAYTIME_STEP: f32 = 0.1;
+
+/// Affection a fish gives you for releasing it.
+const RELEASE_AFFECTION_BONUS: i32 = 2;
+
+/// Column width the encyclopedia text wraps to on the fish detail page.
+const LORE_WIDTH: usize = 56;
+
+/// Seconds a notice stays at the bottom of the screen.
+const NOTICE_DURATION: f32 = 3.0;
+
+/// Affection for offering a spare Small fish on a date.
+const OFFERING_AFFECTION_SMALL: i32 = 1;
+/// Affection for offering a spare Medium fish on a date.
+const OFFERING_AFFECTION_MEDIUM: i32 = 2;
+/// Extra affection when the offered fish lives in the date's pond.
+const OFFERING_PONDMATE_BONUS: i32 = 1;
+
+/// Picks on a quick date, in menu order.
+const QUICK_DATE_CHOICES: [&str; 3] = ["Compliment", "Tease", "Ignore"];
+/// Affection from a quick date compliment. Well short of a good full date.
+const QUICK_DATE_COMPLIMENT: i32 = 2;
+
+/// Affection a fish needs before it'll come on a group date. Group dates
+/// unlock once two fish are there.
+const GROUP_DATE_MIN_AFFECTION: i32 = 6;
+
+/// Seconds the "Saved" indicator shows after an autosave.
+const AUTOSAVE_INDICATOR_DURATION: f32 = 1.5;
+
+/// Stars in a fish's difficulty rating.
+const DIFFICULTY_STARS: u32 = 5;
+
+/// Stand-in name and species for fish the player hasn't caught yet.
+const UNDISCOVERED_NAME: &str = "???";
+
+/// Key that reloads plugin scripts from disk.
+const RELOAD_PLUGINS_KEY: KeyCode = KeyCode::F5;
+
+/// Keys that mute and step the master volume.
+const MUTE_KEY: KeyCode = KeyCode::KeyM;
+const VOLUME_DOWN_KEY: KeyCode = KeyCode::BracketLeft;
+const VOLUME_UP_KEY: KeyCode = KeyCode::BracketRight;
+/// Master volume change per volume key press.
+const VOLUME_STEP: f32 = 0.1;
+/// Seconds the volume bar shows after a volume or mute key.
+const VOLUME_OVERLAY_DURATION: f32 = 1.5;
+
+/// Help overlay text for the fishing minigame.
+const MINIGAME_HELP: &[&str] = &[
+    "Wait for a bite, then fight the fish.",
+    "",
+    "Hold [A]/[D] to pull the line left or right.",
+    "Keep the line inside the CENTER zone to reel in.",
+    "The closer to center, the faster the bar fills.",
+    "",
+    "The fish tugs the line away from center.",
+    "Drift to the edge and the line SNAPS!",
+    "Near the edge, CLUTCH time slows the fish for a moment",
+    "(not in Hardcore).",
+    "",
+    "Press the key a QUICK! prompt shows for a burst of",
+    "progress. Miss it and the fish surges.",
+    "",
+    "Stay centered the whole time for a Large catch.",
+];
+
+/// Help overlay text for dates.
+const DATE_HELP: &[&str] = &[
+    "Talk your way into a fish's heart.",
+    "",
+    "[Enter] advances the conversation.",
+    "When choices appear, pick one with the arrows.",
+    "Each answer changes how much the fish likes you.",
+    "",
+    "Affection adds up across dates. Bring a gift",
+    "the fish loves for a bonus.",
+    "",
+    "[Tab] shows the backlog of what was said.",
+    "[A] turns auto-play on or off. Lines move on by",
+    "themselves until the next choice.",
+];
 
 /// All possible game screens.
 pub enum GameScreen {
+    /// Pick which save slot to play, shown before the main menu.
+    SlotSelect,
     MainMenu,
     FishingPondSelect,
     FishingMinigame(MinigameState),
     CatchResult {
         fish_id: FishId,
+        /// The fish's home pond.
         pond_index: usize,
         size: FishSize,
+        shiny: bool,
+        /// Whether it was caught in the mystery pond rather than at home.
+        mystery: bool,
     },
     FishCollection,
+    /// Encyclopedia page for one collected fish, opened from the collection.
+    FishDetail {
+        fish_id: FishId,
+    },
+    /// List of all achievements and their unlock status.
+    Achievements,
+    /// Lifetime play statistics.
+    Stats,
+    /// Every catch in order, newest first.
+    Journal,
     DateSelect,
-    Dating(DatingState),
+    /// Shown instead of the date list when no caught fish are left to date.
+    NoDates,
+    /// Pick a gift to bring along before the date starts.
+    GiftSelect {
+        fish_id: FishId,
+    },
+    /// Offer a spare fish from the collection, right after the gift.
+    OfferSelect {
+        fish_id: FishId,
+        /// Whether the gift picked was the fish's favorite.
+        favorite: bool,
+    },
+    Dating(Box<DatingState>),
+    /// A date condensed to one choice, for fish already dated once.
+    QuickDate {
+        fish_id: FishId,
+    },
     DateResult {
         fish_id: FishId,
         affection: i32,
+        /// Whether the favorite gift bonus was applied.
+        gift_bonus: bool,
+        /// Memory flags the date's choices set.
+        flags: Vec<String>,
+        /// Whether the fish stormed off before the date was over.
+        ended_early: bool,
+        /// Affection from choices alone, before the gift bonus.
+        choice_affection: i32,
+        /// Choices picked on the date.
+        choices: u32,
+        /// Spare fish offered before the date and the affection it's worth.
+        offering: Option<(FishId, i32)>,
+        /// The date as recorded, kept if it earned enough affection.
+        replay: DateReplay,
+    },
+    /// Pick two fish for a group date, one at a time. `first` is set once
+    /// the first fish is picked.
+    GroupDateSelect {
+        first: Option<FishId>,
+    },
+    /// A date with two fish at once.
+    GroupDate(Box<DatingState>),
+    /// Summary after a group date ends. Each fish gets `affection`.
+    GroupDateResult {
+        fish: [FishId; 2],
+        affection: i32,
+        flags: Vec<String>,
+        ended_early: bool,
     },
+    /// Saved replays of great dates.
+    Replays,
+    /// Every installed plugin fish, caught or not.
+    PluginGallery,
     GameOver,
+    /// Confirmation prompt shown before quitting the game.
+    ConfirmQuit,
+    /// Confirmation prompt shown before New Game erases the current save.
+    ConfirmNewGame,
+    /// Confirmation prompt before releasing duplicate Small fish.
+    ConfirmReleaseSmalls,
     /// Secret: cult_papa captures and fights the moon.
     MoonBattle(MoonBattleState),
+    /// A plugin cutscene started by its secret word.
+    Cutscene(CutsceneState),
+    /// List of problems found while loading plugin scripts.
+    PluginErrors,
+    /// Turn plugin scripts on and off in the plugin manifest.
+    PluginManager,
+    /// Pick Casual / Normal / Hardcore before starting a new game.
+    ModeSelect,
+    /// Type the player's name, right after picking a mode.
+    NameEntry,
+    /// Greeting on the first launch of a new real-world day.
+    WelcomeBack(DailyWelcome),
+    /// Offer to pick up a date the game crashed in the middle of.
+    ConfirmResumeDate(save::DateResume),
 }
 
 /// The complete game state.
 pub struct Game {
     pub screen: GameScreen,
     pub player: PlayerState,
+    /// Save slot the current player state is loaded from and saved to.
+    pub slot: usize,
     pub time: f32,
     pub registry: FishRegistry,
+    /// Where the registry's plugins came from, shown on the main menu.
+    plugin_report: PluginLoadReport,
     // Screen-specific sub-states
     menu: SelectionMenu,
+    slot_menu: Option<SelectionMenu>,
     pond_state: Option<PondSelectState>,
     date_select_menu: Option<SelectionMenu>,
-    collection_scroll: usize,
+    gift_menu: Option<SelectionMenu>,
+    offer_menu: Option<SelectionMenu>,
+    /// Fish behind each offer menu entry after "Nothing".
+    offer_choices: Vec<FishId>,
+    mode_menu: Option<SelectionMenu>,
+    name_input: Option<TextInput>,
+    collection_menu: Option<SelectionMenu>,
+    /// First collection entry on screen. Follows the selection when drawn.
+    collection_scroll: Cell<usize>,
+    /// Lines or entries the scrolling screen on show fit on its last draw,
+    /// so scrolling stops with the last page full.
+    scroll_page: Cell<usize>,
+    /// Lore lines scrolled past on the fish detail page.
+    detail_scroll: usize,
+    achievements_scroll: usize,
+    stats_scroll: usize,
+    journal_scroll: usize,
+    plugin_errors_scroll: usize,
+    /// Manifest being edited on the plugin manager screen.
+    plugin_manifest: Option<PluginManifest>,
+    plugin_menu: Option<SelectionMenu>,
+    /// Whether the player state changed since the last save.
+    dirty: Cell<bool>,
+    /// Seconds since the last autosave check.
+    autosave_timer: f32,
+    /// Seconds left to show the autosave indicator.
+    autosave_indicator: f32,
+    /// Seconds left to show the volume bar.
+    volume_overlay: f32,
+    /// Whether plugins need reloading when the plugin manager closes.
+    plugin_manifest_changed: bool,
+    replay_menu: Option<SelectionMenu>,
+    /// One entry per plugin fish, in registration order.
+    gallery_menu: Option<SelectionMenu>,
+    quick_date_menu: Option<SelectionMenu>,
+    group_menu: Option<SelectionMenu>,
+    /// Fish behind each group date menu entry.
+    group_choices: Vec<FishId>,
+    /// The fish's reaction and the affection given, once the quick date's
+    /// choice is made.
+    quick_date_outcome: Option<(String, i32)>,
+    /// Marker for the date in progress, kept on disk in case of a crash.
+    date_resume: Option<save::DateResume>,
+    /// Replays saved for the current slot, best first.
+    replays: Vec<DateReplay>,
     /// Tracks the secret "moon" key sequence on the main menu.
     moon_secret: SecretSequence,
+    /// Secret words for plugin cutscenes, in registry order.
+    cutscene_secrets: Vec<SecretSequence>,
     /// Achievement tracker (Steam + local).
     pub achievements: AchievementTracker,
+    /// Music and sound effects.
+    audio: AudioManager,
+    /// Source of all gameplay randomness, seeded by `--seed` for repeatable runs.
+    rng: StdRng,
+    /// Player-configurable key bindings.
+    pub bindings: KeyBindings,
+    /// Persisted player settings.
+    pub settings: Settings,
+    /// Rich presence last sent to Steam, to skip redundant updates.
+    presence: String,
+    /// Whether the help overlay is showing on the minigame or date screen.
+    help_open: bool,
+    /// Short message at the bottom of the screen and seconds left to show it.
+    notice: Option<(String, f32)>,
+    /// Set once the player quits; the frontend exits its loop.
+    quit: bool,
+}
+
+/// The game's source of randomness: from `seed` if given, otherwise from
+/// entropy.
+pub(crate) fn game_rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(seed) => {
+            tracing::info!("Using RNG seed {}", seed);
+            StdRng::seed_from_u64(seed)
+        }
+        None => StdRng::from_entropy(),
+    }
 }
 
 impl Game {
-    pub fn new(registry: FishRegistry) -> Self {
-        let player = save::load_game().unwrap_or_default();
-        let has_save = save::save_exists();
+    /// Create a game with `slot` preselected. Starts on the slot picker.
+    ///
+    /// With a `seed`, fish behavior, shiny rolls and pond residents play out
+    /// the same way every run. Without one the game seeds from entropy.
+    pub fn new(
+        registry: FishRegistry,
+        plugin_report: PluginLoadReport,
+        slot: usize,
+        seed: Option<u64>,
+    ) -> Self {
+        save::migrate_legacy_save();
+        replay::migrate_legacy_replays();
+        let player = save::load_game(slot).unwrap_or_default();
+        let menu = Self::initial_menu(save::save_exists(slot));
+        let settings = Settings::load();
+        let mut audio = AudioManager::new(settings.master_volume, settings.muted);
+        audio.play_music(Track::Menu);
+        let rng = game_rng(seed);
+
+        let mut game = Self {
+            screen: GameScreen::SlotSelect,
+            player,
+            slot,
+            time: 0.0,
+            registry,
+            plugin_report,
+            menu,
+            slot_menu: None,
+            pond_state: None,
+            date_select_menu: None,
+            gift_menu: None,
+            offer_menu: None,
+            offer_choices: Vec::new(),
+            mode_menu: None,
+            name_input: None,
+            collection_menu: None,
+            collection_scroll: Cell::new(0),
+            scroll_page: Cell::new(0),
+            detail_scroll: 0,
+            achievements_scroll: 0,
+            stats_scroll: 0,
+            journal_scroll: 0,
+            plugin_errors_scroll: 0,
+            plugin_manifest: None,
+            plugin_menu: None,
+            plugin_manifest_changed: false,
+            replay_menu: None,
+            gallery_menu: None,
+            quick_date_menu: None,
+            quick_date_outcome: None,
+            group_menu: None,
+            group_choices: Vec::new(),
+            date_resume: None,
+            replays: replay::load_replays(slot),
+            dirty: Cell::new(false),
+            autosave_timer: 0.0,
+            autosave_indicator: 0.0,
+            volume_overlay: 0.0,
+            moon_secret: SecretSequence::new(MOON_SECRET),
+            cutscene_secrets: Vec::new(),
+            achievements: AchievementTracker::new(),
+            audio,
+            rng,
+            bindings: KeyBindings::load(),
+            settings,
+            presence: String::new(),
+            help_open: false,
+            notice: None,
+            quit: false,
+        };
+        game.rebuild_cutscene_secrets();
+        game.rebuild_slot_menu();
+        game
+    }
 
+    /// The main menu shown right after loading a slot.
+    fn initial_menu(has_save: bool) -> SelectionMenu {
         let menu_items = if has_save {
             vec![
                 "Go Fishing".to_string(),
                 "Go on a Date".to_string(),
                 "Fish Collection".to_string(),
+                "Achievements".to_string(),
+                "Stats".to_string(),
                 "New Game".to_string(),
                 "Quit".to_string(),
             ]
         } else {
             vec![
                 "Go Fishing".to_string(),
+                "Achievements".to_string(),
+                "Stats".to_string(),
                 "Quit".to_string(),
             ]
         };
 
-        Self {
-            screen: GameScreen::MainMenu,
-            player,
-            time: 0.0,
-            registry,
-            menu: SelectionMenu::new(menu_items),
-            pond_state: None,
-            date_select_menu: None,
-            collection_scroll: 0,
-            moon_secret: SecretSequence::new(),
-            achievements: AchievementTracker::new(),
+        SelectionMenu::new(menu_items)
+    }
+
+    /// Show a short message at the bottom of the screen.
+    fn show_notice(&mut self, text: &str) {
+        self.notice = Some((text.to_string(), NOTICE_DURATION));
+    }
+
+    /// Load plugin scripts again and swap in the new registry, for plugin
+    /// authors iterating on a script. The player's save is untouched.
+    fn reload_plugins(&mut self) {
+        let busy = matches!(
+            self.screen,
+            GameScreen::FishingMinigame(_)
+                | GameScreen::CatchResult { .. }
+                | GameScreen::GiftSelect { .. }
+                | GameScreen::OfferSelect { .. }
+                | GameScreen::Dating(_)
+                | GameScreen::QuickDate { .. }
+                | GameScreen::DateResult { .. }
+                | GameScreen::GroupDate(_)
+                | GameScreen::GroupDateResult { .. }
+                | GameScreen::FishDetail { .. }
+        );
+        if busy {
+            self.show_notice("Finish the current activity first");
+            return;
+        }
+
+        let (registry, report) = crate::plugins::load_all_plugins();
+        self.registry = registry;
+        self.plugin_report = report;
+        self.rebuild_cutscene_secrets();
+        let errors = self.registry.failed_plugin_count();
+        self.show_notice(&tf(
+            "Reloaded plugins: {} fish, {} with errors",
+            &[&self.plugin_report.fish_count, &errors],
+        ));
+
+        // Screens holding names or ponds from the old registry build them again
+        let rebuild = match self.screen {
+            GameScreen::SlotSelect => {
+                self.rebuild_slot_menu();
+                None
+            }
+            GameScreen::FishingPondSelect => Some(GameScreen::FishingPondSelect),
+            GameScreen::FishCollection => Some(GameScreen::FishCollection),
+            GameScreen::DateSelect => Some(GameScreen::DateSelect),
+            GameScreen::GroupDateSelect { .. } => Some(GameScreen::GroupDateSelect { first: None }),
+            GameScreen::PluginErrors => Some(GameScreen::PluginErrors),
+            GameScreen::Replays => Some(GameScreen::Replays),
+            GameScreen::PluginGallery => Some(GameScreen::PluginGallery),
+            _ => None,
+        };
+        if let Some(screen) = rebuild {
+            self.transition_to(screen);
+        }
+    }
+
+    /// Whether the player has asked to quit.
+    pub fn quit_requested(&self) -> bool {
+        self.quit
+    }
+
+    /// Save the player state to the active slot.
+    pub fn save(&self) -> Result<(), String> {
+        save::save_game(self.slot, &self.player)?;
+        self.dirty.set(false);
+        Ok(())
+    }
+
+    /// Save on the way out, unless no slot is set up yet. Saving from the
+    /// slot picker would bring back a slot the player just deleted.
+    pub fn save_on_exit(&self) {
+        if self.slot_loaded() {
+            let _ = self.save();
+        }
+    }
+
+    /// Whether a slot has been picked and set up, so the player state is
+    /// worth saving.
+    fn slot_loaded(&self) -> bool {
+        !matches!(
+            self.screen,
+            GameScreen::SlotSelect | GameScreen::ModeSelect | GameScreen::NameEntry
+        )
+    }
+
+    /// Whether the current screen is a calm moment to autosave. Fights,
+    /// dates and scenes are left alone, as are screens before a slot is
+    /// set up.
+    fn autosave_allowed(&self) -> bool {
+        self.slot_loaded()
+            && !matches!(
+                self.screen,
+                GameScreen::FishingMinigame(_)
+                    | GameScreen::Dating(_)
+                    | GameScreen::GroupDate(_)
+                    | GameScreen::MoonBattle(_)
+                    | GameScreen::Cutscene(_)
+            )
+    }
+
+    /// Save every `autosave_interval` seconds if anything changed.
+    fn update_autosave(&mut self, dt: f32) {
+        self.autosave_indicator = (self.autosave_indicator - dt).max(0.0);
+        let interval = self.settings.autosave_interval;
+        if interval <= 0.0 {
+            return;
+        }
+        self.autosave_timer += dt;
+        // Past due saves wait for the next calm screen
+        if self.autosave_timer < interval || !self.autosave_allowed() {
+            return;
+        }
+        self.autosave_timer = 0.0;
+        if !self.dirty.get() {
+            return;
+        }
+        match self.save() {
+            Ok(()) => {
+                tracing::info!("Autosaved slot {}", self.slot + 1);
+                self.autosave_indicator = AUTOSAVE_INDICATOR_DURATION;
+            }
+            Err(e) => tracing::warn!("Autosave failed: {}", e),
         }
     }
 
+    /// Switch to a save slot, loading its player state.
+    fn load_slot(&mut self, slot: usize) {
+        self.slot = slot;
+        self.player = save::load_game(slot).unwrap_or_default();
+        self.replays = replay::load_replays(slot);
+        self.menu = Self::initial_menu(save::save_exists(slot));
+        // Saves from before an achievement existed unlock it on load
+        self.achievements.check_state(&mut self.player, &self.registry);
+        self.sync_stats();
+    }
+
+    /// Rebuild the slot picker from what's on disk.
+    fn rebuild_slot_menu(&mut self) {
+        let selected = self.slot_menu.as_ref().map_or(self.slot, |m| m.selected_index());
+        let items: Vec<String> = save::list_slots()
+            .iter()
+            .enumerate()
+            .map(|(i, info)| {
+                if !info.occupied {
+                    return tf("Slot {}: Empty", &[&(i + 1)]);
+                }
+                let closest = info
+                    .closest_fish
+                    .as_ref()
+                    .map(|f| f.name_with_registry(&self.registry))
+                    .unwrap_or_else(|| t("Nobody yet").to_string());
+                tf(
+                    "Slot {}: Day {} | Fish: {} | Closest: {}",
+                    &[&(i + 1), &info.day, &info.fish_count, &closest],
+                )
+            })
+            .collect();
+        let mut menu = SelectionMenu::new(items);
+        menu.select(selected);
+        self.slot_menu = Some(menu);
+    }
+
     /// Rebuild the main menu based on current state.
     fn rebuild_menu(&mut self) {
         let has_fish = !self.player.fish_collection.is_empty();
-        let mut items = vec!["Go Fishing".to_string()];
+        let mut items = Vec::new();
+        if self.player.actions_remaining == 0 {
+            items.push("Rest until tomorrow".to_string());
+        } else {
+            items.push("Go Fishing".to_string());
+            if has_fish {
+                items.push("Go on a Date".to_string());
+            }
+            if self.group_date_fish().len() >= 2 {
+                items.push("Group Date".to_string());
+            }
+        }
         if has_fish {
-            items.push("Go on a Date".to_string());
             items.push("Fish Collection".to_string());
+            items.push("Journal".to_string());
+        }
+        items.push("Achievements".to_string());
+        items.push("Stats".to_string());
+        if !self.replays.is_empty() {
+            items.push("Replays".to_string());
+        }
+        if self.plugin_report.dir.is_some() {
+            items.push("Plugins".to_string());
+        }
+        if self.registry.count() > 0 {
+            items.push("Fish Gallery".to_string());
+        }
+        if self.player.has_won() {
+            items.push("Settle Down".to_string());
         }
         items.push("Save Game".to_string());
+        if save::save_exists(self.slot) {
+            items.push("New Game".to_string());
+        }
         items.push("Quit".to_string());
         self.menu = SelectionMenu::new(items);
     }
 
-    pub fn update(&mut self, dt: f32, key: Option<KeyCode>) {
+    pub fn update(&mut self, dt: f32, input: &InputState) {
+        let key = input
+            .just_pressed()
+            .map(|k| self.quick_select_key(k))
+            .or_else(|| self.pointer_key(input));
         self.time += dt;
+        self.player.playtime_seconds += dt.min(MAX_PLAYTIME_STEP) as f64;
         self.achievements.run_callbacks();
         self.achievements.update(dt);
+        if let Some((_, timer)) = &mut self.notice {
+            *timer -= dt;
+            if *timer <= 0.0 {
+                self.notice = None;
+            }
+        }
+
+        self.volume_overlay = (self.volume_overlay - dt).max(0.0);
+
+        if key == Some(RELOAD_PLUGINS_KEY) {
+            self.reload_plugins();
+            return;
+        }
+        if key.is_some_and(|k| self.volume_key(k)) {
+            return;
+        }
+
+        if matches!(
+            self.screen,
+            GameScreen::FishingMinigame(_) | GameScreen::Dating(_) | GameScreen::GroupDate(_)
+        ) {
+            if key == Some(KeyCode::KeyH) {
+                self.help_open = !self.help_open;
+                return;
+            }
+            if self.help_open {
+                // The scene stays paused behind the overlay
+                let close = key.is_some_and(|k| {
+                    self.bindings.is(Action::Confirm, k) || self.bindings.is(Action::Back, k)
+                });
+                if close {
+                    self.help_open = false;
+                }
+                return;
+            }
+        }
 
         let transition = match &mut self.screen {
+            GameScreen::SlotSelect => self.update_slot_select(key),
             GameScreen::MainMenu => self.update_main_menu(key),
             GameScreen::FishingPondSelect => {
                 if let Some(ref mut state) = self.pond_state {
                     if let Some(k) = key {
-                        state.update(k)
+                        state.update(k, &self.bindings, &self.registry, &mut self.rng)
                     } else {
                         None
                     }
@@ -119,36 +687,483 @@ impl Game {
                     None
                 }
             }
-            GameScreen::FishingMinigame(state) => state.update(dt, key),
-            GameScreen::CatchResult { .. } => self.update_catch_result(key),
+            GameScreen::FishingMinigame(state) if key == Some(KeyCode::KeyR) && state.got_away() => {
+                if state.is_mystery() {
+                    self.fish_mystery_again()
+                } else {
+                    let fish_id = state.fish_id().clone();
+                    Some(self.fish_again(fish_id))
+                }
+            }
+            GameScreen::FishingMinigame(state) => {
+                let result = state.update(
+                    dt,
+                    input,
+                    &self.bindings,
+                    self.settings.fixed_timestep,
+                    !self.settings.classic_minigame,
+                    &mut self.rng,
+                );
+                if state.take_bite_flag() {
+                    self.audio.play_sfx(Sfx::Bite);
+                }
+                if state.take_snap_flag() {
+                    self.audio.play_sfx(Sfx::Snap);
+                }
+                if let Some(outcome) = state.take_outcome() {
+                    let landed = outcome == CatchOutcome::Caught;
+                    self.player.record_catch_attempt(state.fish_id(), landed);
+                    if !landed {
+                        self.player.perfect_streak = 0;
+                    }
+                    let _ = self.save();
+                }
+                result
+            }
+            GameScreen::CatchResult {
+                fish_id,
+                pond_index,
+                size,
+                shiny,
+                mystery,
+            } => {
+                let (fish_id, pond, size, shiny) =
+                    (fish_id.clone(), (!*mystery).then_some(*pond_index), *size, *shiny);
+                self.update_catch_result(key, fish_id, pond, size, shiny)
+            }
             GameScreen::FishCollection => self.update_collection(key),
+            GameScreen::FishDetail { fish_id } => {
+                let fish_id = fish_id.clone();
+                self.update_fish_detail(key, &fish_id)
+            }
+            GameScreen::Achievements => self.update_achievements(key),
+            GameScreen::Stats => self.update_stats(key),
+            GameScreen::Journal => self.update_journal(key),
             GameScreen::DateSelect => self.update_date_select(key),
-            GameScreen::Dating(state) => state.update(dt, key),
-            GameScreen::DateResult { .. } => self.update_date_result(key),
+            GameScreen::GiftSelect { fish_id } => {
+                let fish_id = fish_id.clone();
+                self.update_gift_select(key, fish_id)
+            }
+            GameScreen::OfferSelect { fish_id, favorite } => {
+                let (fish_id, favorite) = (fish_id.clone(), *favorite);
+                self.update_offer_select(key, fish_id, favorite)
+            }
+            GameScreen::QuickDate { fish_id } => {
+                let fish_id = fish_id.clone();
+                self.update_quick_date(key, fish_id)
+            }
+            GameScreen::Dating(state) => {
+                let next = state.update(dt, key, &self.bindings, &mut self.settings);
+                if let Some(resume) = &mut self.date_resume
+                    && resume.affection != state.choice_affection()
+                {
+                    resume.affection = state.choice_affection();
+                    if let Err(e) = save::save_resume(resume) {
+                        tracing::warn!("Failed to update resume.json: {}", e);
+                    }
+                }
+                next
+            }
+            GameScreen::DateResult { .. } | GameScreen::GroupDateResult { .. } => self.update_date_result(key),
+            GameScreen::GroupDateSelect { first } => {
+                let first = first.clone();
+                self.update_group_date_select(key, first)
+            }
+            GameScreen::GroupDate(state) => state.update(dt, key, &self.bindings, &mut self.settings),
             GameScreen::GameOver => self.update_game_over(key),
+            GameScreen::ConfirmQuit => self.update_confirm_quit(key),
+            GameScreen::ConfirmNewGame => self.update_confirm_new_game(key),
+            GameScreen::ConfirmResumeDate(resume) => {
+                let resume = resume.clone();
+                self.update_confirm_resume_date(key, resume)
+            }
+            GameScreen::ConfirmReleaseSmalls => self.update_confirm_release_smalls(key),
+            GameScreen::PluginErrors => self.update_plugin_errors(key),
+            GameScreen::PluginManager => self.update_plugin_manager(key),
+            GameScreen::Replays => self.update_replays(key),
+            GameScreen::PluginGallery => self.update_plugin_gallery(key),
+            GameScreen::ModeSelect => self.update_mode_select(key),
+            GameScreen::NameEntry => self.update_name_entry(key, input.typed()),
+            GameScreen::WelcomeBack(_) => self.update_welcome_back(key),
+            GameScreen::NoDates => self.update_no_dates(key),
             GameScreen::MoonBattle(state) => {
                 let result = state.update(dt, key);
                 if state.take_victory_flag() {
                     self.achievements.on_moon_victory(&mut self.player.achievements);
-                    let _ = save::save_game(&self.player);
+                    let _ = self.save();
                 }
                 result
             }
+            GameScreen::Cutscene(state) => state.update(dt, key),
         };
 
+        // Player state only changes in response to input or a screen change
+        if key.is_some() || transition.is_some() {
+            self.dirty.set(true);
+        }
         if let Some(new_screen) = transition {
             self.transition_to(new_screen);
         }
+        self.update_autosave(dt);
+
+        let presence = self.presence_status();
+        if presence != self.presence {
+            self.achievements.set_presence(&presence);
+            self.presence = presence;
+        }
+    }
+
+    /// The menu the mouse can point at on the current screen, if any.
+    fn active_menu(&mut self) -> Option<&mut SelectionMenu> {
+        match &mut self.screen {
+            GameScreen::SlotSelect => self.slot_menu.as_mut(),
+            GameScreen::MainMenu => Some(&mut self.menu),
+            GameScreen::FishingPondSelect => self.pond_state.as_mut().map(|p| p.menu_mut()),
+            GameScreen::FishCollection => self.collection_menu.as_mut(),
+            GameScreen::DateSelect => self.date_select_menu.as_mut(),
+            GameScreen::GiftSelect { .. } => self.gift_menu.as_mut(),
+            GameScreen::QuickDate { .. } if self.quick_date_outcome.is_none() => {
+                self.quick_date_menu.as_mut()
+            }
+            GameScreen::OfferSelect { .. } => self.offer_menu.as_mut(),
+            GameScreen::ModeSelect => self.mode_menu.as_mut(),
+            GameScreen::PluginManager => self.plugin_menu.as_mut(),
+            GameScreen::Replays => self.replay_menu.as_mut(),
+            GameScreen::GroupDateSelect { .. } => self.group_menu.as_mut(),
+            GameScreen::Dating(state) | GameScreen::GroupDate(state) => state.active_choice_menu(&self.settings),
+            _ => None,
+        }
+    }
+
+    /// Turn mouse input into the key press it stands for.
+    ///
+    /// Hovering a menu item selects it, and clicking it confirms like Enter.
+    /// Clicking through a dialogue line advances it.
+    fn pointer_key(&mut self, input: &InputState) -> Option<KeyCode> {
+        let confirm = self.bindings.keys(Action::Confirm).first().copied();
+        let (_, row) = input.cursor()?;
+
+        let in_dialogue = matches!(
+            &self.screen,
+            GameScreen::Dating(state) | GameScreen::GroupDate(state) if state.click_advances()
+        );
+        if input.clicked() && in_dialogue {
+            return confirm;
+        }
+
+        let menu = self.active_menu()?;
+        let hit = menu.hit_test(row)?;
+        if input.cursor_moved() || input.clicked() {
+            menu.select(hit);
+        }
+        if input.clicked() { confirm } else { None }
+    }
+
+    /// Number keys pick the matching item of the active menu and confirm
+    /// it, like moving there and pressing Enter. Other keys pass through.
+    fn quick_select_key(&mut self, key: KeyCode) -> KeyCode {
+        let Some(confirm) = self.bindings.keys(Action::Confirm).first().copied() else {
+            return key;
+        };
+        let picked = self.active_menu().is_some_and(|menu| menu.quick_select(key));
+        if picked { confirm } else { key }
+    }
+
+    /// Handle the mute and volume keys, returning whether `key` was one.
+    ///
+    /// Keys bound to an action are left alone, and so is M wherever it's
+    /// typed: the name entry, the main menu's moon secret and the minigame's
+    /// quick-time prompts.
+    fn volume_key(&mut self, key: KeyCode) -> bool {
+        if self.bindings.is_bound(key) || matches!(self.screen, GameScreen::NameEntry) {
+            return false;
+        }
+        let mute_allowed = !matches!(self.screen, GameScreen::MainMenu | GameScreen::FishingMinigame(_));
+        match key {
+            MUTE_KEY if mute_allowed => {
+                self.settings.muted = !self.settings.muted;
+                self.audio.set_muted(self.settings.muted);
+            }
+            VOLUME_DOWN_KEY | VOLUME_UP_KEY => {
+                let step = if key == VOLUME_UP_KEY { VOLUME_STEP } else { -VOLUME_STEP };
+                // Round so repeated steps land back on tenths
+                let volume = ((self.settings.master_volume + step) * 10.0).round() / 10.0;
+                self.settings.master_volume = volume.clamp(0.0, 1.0);
+                self.audio.set_volume(self.settings.master_volume);
+            }
+            _ => return false,
+        }
+        self.volume_overlay = VOLUME_OVERLAY_DURATION;
+        if let Err(e) = self.settings.save() {
+            tracing::warn!("Failed to save settings: {}", e);
+        }
+        true
+    }
+
+    /// Name of the current screen, for the debug console.
+    pub fn screen_name(&self) -> &'static str {
+        match &self.screen {
+            GameScreen::SlotSelect => "SlotSelect",
+            GameScreen::MainMenu => "MainMenu",
+            GameScreen::FishingPondSelect => "FishingPondSelect",
+            GameScreen::FishingMinigame(_) => "FishingMinigame",
+            GameScreen::CatchResult { .. } => "CatchResult",
+            GameScreen::FishCollection => "FishCollection",
+            GameScreen::FishDetail { .. } => "FishDetail",
+            GameScreen::Achievements => "Achievements",
+            GameScreen::Stats => "Stats",
+            GameScreen::Journal => "Journal",
+            GameScreen::DateSelect => "DateSelect",
+            GameScreen::GiftSelect { .. } => "GiftSelect",
+            GameScreen::OfferSelect { .. } => "OfferSelect",
+            GameScreen::Dating(_) => "Dating",
+            GameScreen::QuickDate { .. } => "QuickDate",
+            GameScreen::DateResult { .. } => "DateResult",
+            GameScreen::GroupDateSelect { .. } => "GroupDateSelect",
+            GameScreen::GroupDate(_) => "GroupDate",
+            GameScreen::GroupDateResult { .. } => "GroupDateResult",
+            GameScreen::Replays => "Replays",
+            GameScreen::PluginGallery => "PluginGallery",
+            GameScreen::GameOver => "GameOver",
+            GameScreen::ConfirmQuit => "ConfirmQuit",
+            GameScreen::ConfirmNewGame => "ConfirmNewGame",
+            GameScreen::ConfirmResumeDate(_) => "ConfirmResumeDate",
+            GameScreen::ConfirmReleaseSmalls => "ConfirmReleaseSmalls",
+            GameScreen::MoonBattle(_) => "MoonBattle",
+            GameScreen::Cutscene(_) => "Cutscene",
+            GameScreen::PluginErrors => "PluginErrors",
+            GameScreen::PluginManager => "PluginManager",
+            GameScreen::ModeSelect => "ModeSelect",
+            GameScreen::NameEntry => "NameEntry",
+            GameScreen::WelcomeBack(_) => "WelcomeBack",
+            GameScreen::NoDates => "NoDates",
+        }
+    }
+
+    /// Rich presence text describing what the player is doing right now.
+    fn presence_status(&self) -> String {
+        let pond_name = |index: usize| self.registry.pond_name_at(index).unwrap_or(t("the pond")).to_string();
+        match &self.screen {
+            GameScreen::FishingPondSelect => t("Picking a fishing spot").to_string(),
+            GameScreen::FishingMinigame(state) if state.is_mystery() => {
+                tf("Fishing at {}", &[&t(ascii_art::MYSTERY_POND_NAME)])
+            }
+            GameScreen::FishingMinigame(state) => tf("Fishing at {}", &[&pond_name(state.pond_index())]),
+            GameScreen::CatchResult { mystery: true, .. } => {
+                tf("Fishing at {}", &[&t(ascii_art::MYSTERY_POND_NAME)])
+            }
+            GameScreen::CatchResult { pond_index, .. } => tf("Fishing at {}", &[&pond_name(*pond_index)]),
+            GameScreen::DateSelect
+            | GameScreen::GiftSelect { .. }
+            | GameScreen::OfferSelect { .. }
+            | GameScreen::GroupDateSelect { .. } => t("Planning a date").to_string(),
+            GameScreen::GroupDate(state) => {
+                let first = state.fish_id.name_with_registry(&self.registry);
+                let second = state.partner().map(|f| f.name_with_registry(&self.registry)).unwrap_or_default();
+                tf("On a group date with {} and {}", &[&first, &second])
+            }
+            GameScreen::GroupDateResult { fish: [first, second], .. } => tf(
+                "On a group date with {} and {}",
+                &[&first.name_with_registry(&self.registry), &second.name_with_registry(&self.registry)],
+            ),
+            GameScreen::Dating(state) => {
+                tf("Dating {}", &[&state.fish_id.name_with_registry(&self.registry)])
+            }
+            GameScreen::DateResult { fish_id, .. } | GameScreen::QuickDate { fish_id } => {
+                tf("Dating {}", &[&fish_id.name_with_registry(&self.registry)])
+            }
+            GameScreen::FishCollection
+            | GameScreen::FishDetail { .. }
+            | GameScreen::Achievements
+            | GameScreen::Stats
+            | GameScreen::Journal
+            | GameScreen::ConfirmReleaseSmalls => {
+                t("Admiring the collection").to_string()
+            }
+            GameScreen::GameOver => t("Settling down").to_string(),
+            GameScreen::MoonBattle(_) => t("Fighting the Moon").to_string(),
+            _ => t("In the main menu").to_string(),
+        }
+    }
+
+    /// Sync lifetime totals to Steam stats.
+    fn sync_stats(&self) {
+        self.achievements.update_stats(
+            self.player.fish_collection.len() as i32,
+            self.player.dates_completed as i32,
+        );
     }
 
-    fn transition_to(&mut self, screen: GameScreen) {
+    fn transition_to(&mut self, mut screen: GameScreen) {
+        self.help_open = false;
+        // Milestone scenes play on the way back to the menu
+        if matches!(screen, GameScreen::MainMenu)
+            && let Some(event) = self.player.take_pending_event()
+        {
+            let _ = self.save();
+            screen = GameScreen::Dating(Box::new(DatingState::milestone(&event, &self.player, &self.registry)));
+        }
+        let out_of_actions = self.player.actions_remaining == 0;
+        let needs_action = matches!(
+            screen,
+            GameScreen::FishingPondSelect
+                | GameScreen::DateSelect
+                | GameScreen::GroupDateSelect { .. }
+                | GameScreen::FishingMinigame(_)
+        );
+        if out_of_actions && needs_action {
+            self.show_notice("You're worn out. Rest until tomorrow.");
+            self.transition_to(GameScreen::MainMenu);
+            return;
+        }
+        let spends_action = match &screen {
+            GameScreen::FishingMinigame(_) => true,
+            GameScreen::Dating(state) => !state.is_milestone() && !state.is_replay() && !state.is_resumed(),
+            GameScreen::QuickDate { .. } => true,
+            GameScreen::GroupDate(_) => true,
+            _ => false,
+        };
+        if spends_action {
+            self.player.spend_action();
+        }
+        // The date ended, one way or another, so there's nothing to resume
+        if self.date_resume.is_some() && !matches!(screen, GameScreen::Dating(_)) {
+            self.date_resume = None;
+            save::clear_resume();
+        }
         match &screen {
             GameScreen::MainMenu => {
                 self.rebuild_menu();
-                self.moon_secret.reset();
+                self.reset_secrets();
+                self.audio.play_music(Track::Menu);
+            }
+            GameScreen::Dating(state) => {
+                self.audio.play_sfx(Sfx::DateStart);
+                if self.settings.resume_dates && !state.is_milestone() && !state.is_replay() {
+                    let resume = save::DateResume {
+                        slot: self.slot,
+                        fish_id: state.fish_id.clone(),
+                        date_number: self.player.date_count(&state.fish_id),
+                        affection: state.choice_affection(),
+                        favorite_gift: state.has_favorite_gift(),
+                    };
+                    if let Err(e) = save::save_resume(&resume) {
+                        tracing::warn!("Failed to write resume.json: {}", e);
+                    }
+                    self.date_resume = Some(resume);
+                }
             }
             GameScreen::FishingPondSelect => {
-                self.pond_state = Some(PondSelectState::new(&self.registry));
+                let weather = Weather::for_day(self.player.current_day);
+                self.pond_state = Some(PondSelectState::new(
+                    &self.registry,
+                    weather,
+                    self.player.current_day,
+                    self.player.game_mode,
+                    self.assist_failures(),
+                ));
+            }
+            GameScreen::FishCollection => {
+                // Coming back from a detail page keeps that fish selected
+                let selected = match self.screen {
+                    GameScreen::FishDetail { .. } => {
+                        self.collection_menu.as_ref().map_or(0, |m| m.selected_index())
+                    }
+                    _ => 0,
+                };
+                let items = self
+                    .collection_entries()
+                    .iter()
+                    .map(|f| {
+                        if self.player.is_discovered(f) {
+                            f.name_with_registry(&self.registry)
+                        } else {
+                            UNDISCOVERED_NAME.to_string()
+                        }
+                    })
+                    .collect();
+                let mut menu = SelectionMenu::new(items);
+                menu.select(selected);
+                self.collection_menu = Some(menu);
+                if selected == 0 {
+                    self.collection_scroll.set(0);
+                }
+            }
+            GameScreen::FishDetail { .. } => {
+                self.detail_scroll = 0;
+            }
+            GameScreen::Achievements => {
+                self.achievements_scroll = 0;
+            }
+            GameScreen::FishingMinigame(_) if !self.player.seen_tutorial => {
+                self.help_open = true;
+                self.player.seen_tutorial = true;
+                let _ = self.save();
+            }
+            GameScreen::Stats => {
+                self.stats_scroll = 0;
+            }
+            GameScreen::Journal => {
+                self.journal_scroll = 0;
+            }
+            GameScreen::ModeSelect => {
+                let items = GameMode::ALL.iter().map(|m| m.label().to_string()).collect();
+                let mut menu = SelectionMenu::new(items);
+                menu.select(GameMode::ALL.iter().position(|m| *m == GameMode::Normal).unwrap_or(0));
+                self.mode_menu = Some(menu);
+            }
+            GameScreen::NameEntry => {
+                self.name_input = Some(TextInput::new("", MAX_PLAYER_NAME_LEN));
+            }
+            GameScreen::PluginErrors => {
+                self.plugin_errors_scroll = 0;
+            }
+            GameScreen::Replays => {
+                let items = self
+                    .replays
+                    .iter()
+                    .map(|r| {
+                        if !r.available(&self.registry) {
+                            return tf("{} - Replay unavailable", &[&r.fish_id.name()]);
+                        }
+                        let name = r.fish_id.name_with_registry(&self.registry);
+                        let number = r.date_number + 1;
+                        tf("{} - Date {} (+{})", &[&name, &number, &r.affection_gained])
+                    })
+                    .collect();
+                self.replay_menu = Some(SelectionMenu::new(items));
+            }
+            GameScreen::PluginGallery => {
+                let items = self.registry.all_fish().iter().map(|f| f.name.clone()).collect();
+                self.gallery_menu = Some(SelectionMenu::new(items));
+            }
+            GameScreen::PluginManager => {
+                let dir = self.plugin_report.dir.clone().unwrap_or_default();
+                let mut manifest = match PluginManifest::load(&dir) {
+                    Ok(manifest) => manifest.unwrap_or_default(),
+                    Err(e) => {
+                        tracing::warn!("Starting a fresh plugin manifest: {}", e);
+                        PluginManifest::default()
+                    }
+                };
+                manifest.add_missing(&find_scripts(&dir));
+                self.plugin_menu = Some(SelectionMenu::new(Self::plugin_menu_items(&manifest, &dir)));
+                self.plugin_manifest = Some(manifest);
+                self.plugin_manifest_changed = false;
+            }
+            GameScreen::CatchResult { fish_id, pond_index, size, mystery, .. } => {
+                self.audio.play_sfx(Sfx::Catch);
+                self.player.discover(fish_id.clone());
+                let mut bonus = self.player.record_catch_streak(*size);
+                // The mystery pond is never today's special
+                if !*mystery && self.is_special_pond(*pond_index) {
+                    bonus += SPECIAL_POND_BONUS;
+                }
+                if bonus > 0 {
+                    self.player.add_affection(fish_id.clone(), bonus);
+                }
+                self.achievements.check_state(&mut self.player, &self.registry);
+                let _ = self.save();
             }
             GameScreen::DateSelect => {
                 let all_fish = FishId::all_with_plugins(&self.registry);
@@ -157,162 +1172,739 @@ impl Game {
                     .filter(|f| self.player.has_caught(f))
                     .map(|f| {
                         let score = self.player.relationship(f);
-                        let label = relationship_label(score);
+                        let label = t(relationship_label(score));
                         let name = f.name_with_registry(&self.registry);
                         let species = f.species_with_registry(&self.registry);
                         format!("{} ({}) - {} [{}]", name, species, label, score)
                     })
                     .collect();
                 if dateable.is_empty() {
-                    // No fish to date, go back
-                    self.rebuild_menu();
-                    self.screen = GameScreen::MainMenu;
+                    // Possible after releasing every fish; say so rather
+                    // than bouncing back to the menu
+                    self.screen = GameScreen::NoDates;
                     return;
                 }
                 self.date_select_menu = Some(SelectionMenu::new(dateable));
             }
-            GameScreen::CatchResult {
-                fish_id,
-                pond_index,
-                size,
-            } => {
-                let pond_name = if *pond_index < ascii_art::POND_NAMES.len() {
-                    ascii_art::POND_NAMES[*pond_index].to_string()
-                } else {
-                    self.registry.pond_names()
-                        .get(*pond_index - ascii_art::POND_NAMES.len())
-                        .map(|s| s.to_string())
-                        .unwrap_or_else(|| "Unknown Pond".to_string())
-                };
-                self.player.add_catch(fish_id.clone(), &pond_name, *size);
-                // Give a small affection bonus for catching
-                self.player.add_affection(fish_id.clone(), 1);
-                // Check catch-related achievements
-                self.achievements.on_catch_size(*size, &mut self.player.achievements);
-                self.achievements.check_state(&mut self.player, &self.registry);
-                let _ = save::save_game(&self.player);
+            GameScreen::GroupDateSelect { first } => {
+                self.group_choices = self
+                    .group_date_fish()
+                    .into_iter()
+                    .filter(|f| Some(f) != first.as_ref())
+                    .collect();
+                let items = self
+                    .group_choices
+                    .iter()
+                    .map(|f| {
+                        let score = self.player.relationship(f);
+                        let name = f.name_with_registry(&self.registry);
+                        format!("{} - {} [{}]", name, t(relationship_label(score)), score)
+                    })
+                    .collect();
+                self.group_menu = Some(SelectionMenu::new(items));
+            }
+            GameScreen::GroupDate(_) => {
+                self.audio.play_sfx(Sfx::DateStart);
+            }
+            GameScreen::GroupDateResult { fish, affection, flags, .. } => {
+                for fish_id in fish {
+                    self.player.add_affection(fish_id.clone(), *affection);
+                }
+                for flag in flags {
+                    self.player.set_flag(flag);
+                }
+                self.finish_date_with(fish);
+            }
+            GameScreen::QuickDate { .. } => {
+                let items = QUICK_DATE_CHOICES.iter().map(|c| c.to_string()).collect();
+                self.quick_date_menu = Some(SelectionMenu::new(items));
+                self.quick_date_outcome = None;
+            }
+            GameScreen::GiftSelect { .. } => {
+                let mut items = vec!["No gift".to_string()];
+                items.extend(self.player.gift_items().iter().map(|s| s.to_string()));
+                self.gift_menu = Some(SelectionMenu::new(items));
+            }
+            GameScreen::OfferSelect { fish_id, .. } => {
+                self.offer_choices = self.spare_fish(fish_id);
+                let mut items = vec!["Nothing".to_string()];
+                items.extend(self.offer_choices.iter().map(|f| {
+                    let size = self.player.spare_size(f).unwrap_or(FishSize::Small);
+                    format!("{} ({})", f.name_with_registry(&self.registry), t(size.label()))
+                }));
+                self.offer_menu = Some(SelectionMenu::new(items));
             }
             GameScreen::DateResult {
                 fish_id,
                 affection,
+                flags,
+                choice_affection,
+                choices,
+                offering,
+                replay,
+                ..
             } => {
+                if *affection >= REPLAY_MIN_AFFECTION
+                    && let Err(e) =
+                        replay::save_replay(self.slot, &mut self.replays, replay.clone())
+                {
+                    tracing::warn!("Failed to save date replay: {}", e);
+                }
                 self.player.add_affection(fish_id.clone(), *affection);
-                self.player.increment_date_count(fish_id.clone());
-                self.player.dates_completed += 1;
-                self.player.current_day += 1;
-                // Check date/relationship achievements
-                self.achievements.check_state(&mut self.player, &self.registry);
-                let _ = save::save_game(&self.player);
+                if let Some((offered, bonus)) = offering
+                    && self.player.consume_duplicate(offered)
+                {
+                    tracing::info!("Offered a spare {} to {}", offered.name(), fish_id.name());
+                    self.player.add_affection(fish_id.clone(), *bonus);
+                }
+                self.player.record_choices(fish_id.clone(), *choice_affection, *choices);
+                for flag in flags {
+                    self.player.set_flag(flag);
+                }
+                self.finish_date(fish_id);
             }
             _ => {}
         }
         self.screen = screen;
     }
 
-    fn update_main_menu(&mut self, key: Option<KeyCode>) -> Option<GameScreen> {
-        let k = key?;
+    /// Count a date with `fish_id` as done, once its affection is given.
+    fn finish_date(&mut self, fish_id: &FishId) {
+        self.finish_date_with(std::slice::from_ref(fish_id));
+    }
 
-        // Feed every key press to the secret "moon" detector
-        if self.moon_secret.feed(k) {
-            self.achievements.on_moon_battle_started(&mut self.player.achievements);
-            let _ = save::save_game(&self.player);
-            return Some(GameScreen::MoonBattle(MoonBattleState::new()));
+    /// Count one date shared by every fish in `fish_ids`: each fish's own
+    /// date count goes up, but it adds a single completed date overall.
+    fn finish_date_with(&mut self, fish_ids: &[FishId]) {
+        for fish_id in fish_ids {
+            self.player.increment_date_count(fish_id.clone());
+            self.player.record_date_day(fish_id.clone());
         }
+        self.player.dates_completed += 1;
+        // Check date/relationship achievements
+        self.achievements.check_state(&mut self.player, &self.registry);
+        self.sync_stats();
+        let _ = self.save();
+    }
 
-        match k {
-            KeyCode::ArrowUp | KeyCode::KeyW => {
-                self.menu.move_up();
+    fn update_slot_select(&mut self, key: Option<KeyCode>) -> Option<GameScreen> {
+        let k = key?;
+        let menu = self.slot_menu.as_mut()?;
+        if k == KeyCode::KeyD {
+            let slot = menu.selected_index();
+            if let Err(e) = save::delete_slot(slot).and_then(|()| replay::delete_replays(slot)) {
+                tracing::warn!("Failed to delete slot {}: {}", slot, e);
+            }
+            self.rebuild_slot_menu();
+            return None;
+        }
+        match self.bindings.menu_action(k) {
+            Some(Action::MenuUp) => {
+                menu.move_up();
                 None
             }
-            KeyCode::ArrowDown | KeyCode::KeyS => {
-                self.menu.move_down();
+            Some(Action::MenuDown) => {
+                menu.move_down();
                 None
             }
-            KeyCode::Enter | KeyCode::Space => {
+            Some(Action::Confirm) => {
+                let slot = menu.selected_index();
+                self.load_slot(slot);
+                if !save::save_exists(slot) {
+                    return Some(GameScreen::ModeSelect);
+                }
+                if let Some(welcome) = self.record_login() {
+                    return Some(GameScreen::WelcomeBack(welcome));
+                }
+                self.enter_loaded_game()
+            }
+            // Nothing is loaded yet, so there's nothing to save
+            Some(Action::Back) => {
+                self.quit = true;
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Build a secret word detector for each plugin cutscene.
+    fn rebuild_cutscene_secrets(&mut self) {
+        self.cutscene_secrets = self
+            .registry
+            .cutscenes()
+            .iter()
+            .map(|cutscene| SecretSequence::new(&cutscene.trigger))
+            .collect();
+    }
+
+    /// Forget any half-typed secret words.
+    fn reset_secrets(&mut self) {
+        self.moon_secret.reset();
+        for secret in &mut self.cutscene_secrets {
+            secret.reset();
+        }
+    }
+
+    fn update_main_menu(&mut self, key: Option<KeyCode>) -> Option<GameScreen> {
+        let k = key?;
+
+        // Feed every key press to the secret "moon" detector
+        if self.moon_secret.feed(k) {
+            self.achievements.on_moon_battle_started(&mut self.player.achievements);
+            let _ = self.save();
+            return Some(GameScreen::MoonBattle(MoonBattleState::new()));
+        }
+
+        // Plugin cutscene words are watched the same way
+        let mut triggered = None;
+        for (i, secret) in self.cutscene_secrets.iter_mut().enumerate() {
+            if secret.feed(k) && triggered.is_none() {
+                triggered = Some(i);
+            }
+        }
+        if let Some(def) = triggered.and_then(|i| self.registry.cutscenes().get(i)) {
+            tracing::info!("Playing plugin cutscene '{}'", def.trigger);
+            return Some(GameScreen::Cutscene(CutsceneState::new(def.clone())));
+        }
+
+        if k == KeyCode::KeyP && !self.registry.errors().is_empty() {
+            return Some(GameScreen::PluginErrors);
+        }
+
+        match self.bindings.menu_action(k) {
+            Some(Action::MenuUp) => {
+                self.menu.move_up();
+                None
+            }
+            Some(Action::MenuDown) => {
+                self.menu.move_down();
+                None
+            }
+            Some(Action::Confirm) => {
                 let selected = &self.menu.items[self.menu.selected_index()];
                 match selected.as_str() {
                     "Go Fishing" => Some(GameScreen::FishingPondSelect),
                     "Go on a Date" => Some(GameScreen::DateSelect),
-                    "Fish Collection" => Some(GameScreen::FishCollection),
-                    "Save Game" => {
-                        let _ = save::save_game(&self.player);
-                        None
-                    }
-                    "New Game" => {
-                        self.player = PlayerState::default();
-                        let _ = save::save_game(&self.player);
+                    "Group Date" => Some(GameScreen::GroupDateSelect { first: None }),
+                    "Rest until tomorrow" => {
+                        self.player.rest();
+                        tracing::info!("Rested until day {}", self.player.current_day);
+                        let _ = self.save();
+                        self.show_notice(&tf("Day {} begins", &[&self.player.current_day]));
                         self.rebuild_menu();
                         None
                     }
-                    "Quit" => {
-                        std::process::exit(0);
+                    "Fish Collection" => Some(GameScreen::FishCollection),
+                    "Achievements" => Some(GameScreen::Achievements),
+                    "Stats" => Some(GameScreen::Stats),
+                    "Journal" => Some(GameScreen::Journal),
+                    "Plugins" => Some(GameScreen::PluginManager),
+                    "Replays" => Some(GameScreen::Replays),
+                    "Fish Gallery" => Some(GameScreen::PluginGallery),
+                    "Settle Down" => Some(GameScreen::GameOver),
+                    "Save Game" => {
+                        let _ = self.save();
+                        None
                     }
+                    "New Game" => Some(GameScreen::ConfirmNewGame),
+                    "Quit" => Some(GameScreen::ConfirmQuit),
                     _ => None,
                 }
             }
-            KeyCode::Escape => {
-                std::process::exit(0);
-            }
+            Some(Action::Back) => Some(GameScreen::ConfirmQuit),
             _ => None,
         }
     }
 
-    fn update_catch_result(&mut self, key: Option<KeyCode>) -> Option<GameScreen> {
-        if let Some(KeyCode::Enter | KeyCode::Space) = key {
-            if self.player.has_won() {
-                return Some(GameScreen::GameOver);
+    fn update_mode_select(&mut self, key: Option<KeyCode>) -> Option<GameScreen> {
+        let k = key?;
+        let menu = self.mode_menu.as_mut()?;
+        match self.bindings.menu_action(k) {
+            Some(Action::MenuUp) => menu.move_up(),
+            Some(Action::MenuDown) => menu.move_down(),
+            Some(Action::Confirm) => {
+                let mode = GameMode::ALL[menu.selected_index()];
+                self.player = PlayerState {
+                    game_mode: mode,
+                    ..PlayerState::default()
+                };
+                tracing::info!("Started a new {} game in slot {}", mode.label(), self.slot + 1);
+                let _ = self.save();
+                return Some(GameScreen::NameEntry);
             }
+            // An empty slot has no game to go back to
+            Some(Action::Back) if !save::save_exists(self.slot) => return Some(GameScreen::SlotSelect),
+            Some(Action::Back) => return Some(GameScreen::MainMenu),
+            _ => {}
+        }
+        None
+    }
+
+    fn update_name_entry(&mut self, key: Option<KeyCode>, typed: Option<char>) -> Option<GameScreen> {
+        let input = self.name_input.as_mut()?;
+        if !input.update(key, typed) {
+            return None;
+        }
+        // A blank name keeps the default
+        let name = input.value().trim();
+        if !name.is_empty() {
+            self.player.player_name = name.to_string();
+        }
+        tracing::info!("Player named {}", self.player.player_name);
+        // Starts the login streak; there's nothing to welcome back yet
+        self.record_login();
+        let _ = self.save();
+        self.show_notice("New game started");
+        Some(GameScreen::MainMenu)
+    }
+
+    /// Record today's launch, saving if it was the first of the day.
+    fn record_login(&mut self) -> Option<DailyWelcome> {
+        let (today, yesterday) = crate::data::today_and_yesterday();
+        let welcome = self.player.record_login(&today, &yesterday)?;
+        tracing::info!("Daily login streak: {}", welcome.streak);
+        let _ = self.save();
+        Some(welcome)
+    }
+
+    fn update_welcome_back(&mut self, key: Option<KeyCode>) -> Option<GameScreen> {
+        if key.is_some_and(|k| self.bindings.is(Action::Confirm, k)) {
+            return self.enter_loaded_game();
+        }
+        None
+    }
+
+    /// Leave the slot picker for the game just loaded: the offer to resume
+    /// an interrupted date if there is one, otherwise the menu.
+    fn enter_loaded_game(&mut self) -> Option<GameScreen> {
+        if let Some(resume) = self.interrupted_date() {
+            return Some(GameScreen::ConfirmResumeDate(resume));
+        }
+        // Go straight to the freshly built menu rather than through
+        // `transition_to`, which would replace it with the in-game menu
+        self.reset_secrets();
+        self.screen = GameScreen::MainMenu;
+        None
+    }
+
+    /// A date in this slot that was cut short by a crash. Markers left by
+    /// another slot, a date already counted, or a missing plugin fish are
+    /// ignored.
+    fn interrupted_date(&self) -> Option<save::DateResume> {
+        if !self.settings.resume_dates {
+            return None;
+        }
+        let resume = save::load_resume()?;
+        let available = match &resume.fish_id {
+            FishId::Plugin(id) => self.registry.get(id).is_some(),
+            _ => true,
+        };
+        let current = resume.slot == self.slot
+            && resume.date_number == self.player.date_count(&resume.fish_id)
+            && self.player.has_caught(&resume.fish_id);
+        (available && current).then_some(resume)
+    }
+
+    fn update_confirm_resume_date(&mut self, key: Option<KeyCode>, resume: save::DateResume) -> Option<GameScreen> {
+        let k = key?;
+        if k == KeyCode::KeyY || self.bindings.is(Action::Confirm, k) {
+            tracing::info!("Resuming interrupted date with {}", resume.fish_id.name());
+            let state = DatingState::resume(&resume, &self.player, &self.registry);
+            return Some(GameScreen::Dating(Box::new(state)));
+        }
+        if k == KeyCode::KeyN || self.bindings.is(Action::Back, k) {
+            save::clear_resume();
+            self.reset_secrets();
+            self.screen = GameScreen::MainMenu;
+        }
+        None
+    }
+
+    fn update_confirm_quit(&mut self, key: Option<KeyCode>) -> Option<GameScreen> {
+        let k = key?;
+        if k == KeyCode::KeyY || self.bindings.is(Action::Confirm, k) {
+            let _ = self.save();
+            self.quit = true;
+        }
+        if k == KeyCode::KeyN {
+            self.quit = true;
+        }
+        if k == KeyCode::KeyC || self.bindings.is(Action::Back, k) {
             return Some(GameScreen::MainMenu);
         }
         None
     }
 
+    fn update_confirm_new_game(&mut self, key: Option<KeyCode>) -> Option<GameScreen> {
+        let k = key?;
+        if k == KeyCode::KeyY || self.bindings.is(Action::Confirm, k) {
+            // The save is only wiped once a mode is picked
+            return Some(GameScreen::ModeSelect);
+        }
+        if k == KeyCode::KeyN || self.bindings.is(Action::Back, k) {
+            return Some(GameScreen::MainMenu);
+        }
+        None
+    }
+
+    fn update_confirm_release_smalls(&mut self, key: Option<KeyCode>) -> Option<GameScreen> {
+        let k = key?;
+        if k == KeyCode::KeyY || self.bindings.is(Action::Confirm, k) {
+            let released = self.player.release_smalls();
+            let _ = self.save();
+            self.show_notice(&tf("Released {} Small fish", &[&released]));
+            return Some(GameScreen::FishCollection);
+        }
+        if k == KeyCode::KeyN || self.bindings.is(Action::Back, k) {
+            return Some(GameScreen::FishCollection);
+        }
+        None
+    }
+
+    fn update_catch_result(
+        &mut self,
+        key: Option<KeyCode>,
+        fish_id: FishId,
+        pond_index: Option<usize>,
+        size: FishSize,
+        shiny: bool,
+    ) -> Option<GameScreen> {
+        let k = key?;
+        let had_won = self.player.has_won();
+        let fish_again = k == KeyCode::KeyF;
+        if fish_again || k == KeyCode::KeyK || self.bindings.is(Action::Confirm, k) {
+            // Catches from the mystery pond are logged there, not at home
+            let pond_name = match pond_index {
+                Some(index) => self.registry.pond_name_at(index).unwrap_or(t("Unknown Pond")),
+                None => ascii_art::MYSTERY_POND_NAME,
+            }
+            .to_string();
+            self.player.add_catch(fish_id.clone(), &pond_name, size, shiny);
+            // Give a small affection bonus for catching
+            self.player.add_affection(fish_id.clone(), 1);
+        } else if k == KeyCode::KeyR {
+            // Released fish stay out of the collection but remember the kindness
+            self.player.add_affection(fish_id.clone(), RELEASE_AFFECTION_BONUS);
+            self.player.released_count += 1;
+        } else {
+            return None;
+        }
+
+        // Check catch-related achievements
+        self.achievements.on_catch_size(size, &mut self.player.achievements);
+        if shiny {
+            self.achievements.on_shiny_catch(&mut self.player.achievements);
+        }
+        self.achievements.check_state(&mut self.player, &self.registry);
+        self.sync_stats();
+        let _ = self.save();
+
+        // A catch that just won the game goes to the menu, where Settle Down waits
+        if fish_again && (had_won || !self.player.has_won()) {
+            if pond_index.is_none() {
+                return self.fish_mystery_again();
+            }
+            return Some(self.fish_again(fish_id));
+        }
+        Some(GameScreen::MainMenu)
+    }
+
+    /// A fresh fight with `fish_id`, skipping the menus.
+    fn fish_again(&mut self, fish_id: FishId) -> GameScreen {
+        let weather = Weather::for_day(self.player.current_day);
+        let failures = self.assist_failures().get(&fish_id).copied().unwrap_or(0);
+        GameScreen::FishingMinigame(MinigameState::new(
+            fish_id,
+            &self.registry,
+            weather,
+            self.player.game_mode,
+            failures,
+            &mut self.rng,
+        ))
+    }
+
+    /// Cast into the mystery pond again, for whichever fish bites next.
+    fn fish_mystery_again(&mut self) -> Option<GameScreen> {
+        let fish_id = pick_mystery(&self.registry, &mut self.rng)?;
+        let weather = Weather::for_day(self.player.current_day);
+        let failures = self.assist_failures().get(&fish_id).copied().unwrap_or(0);
+        let state = MinigameState::new(
+            fish_id,
+            &self.registry,
+            weather,
+            self.player.game_mode,
+            failures,
+            &mut self.rng,
+        );
+        Some(GameScreen::FishingMinigame(state.into_mystery()))
+    }
+
+    /// Fights lost in a row per fish, or nothing if the fishing assist is off.
+    fn assist_failures(&self) -> HashMap<FishId, u32> {
+        if self.settings.fishing_assist(self.player.game_mode) {
+            self.player.recent_failures.clone()
+        } else {
+            HashMap::new()
+        }
+    }
+
+    /// Every fish in collection order. Undiscovered ones show as silhouettes.
+    fn collection_entries(&self) -> Vec<FishId> {
+        FishId::all_with_plugins(&self.registry)
+    }
+
     fn update_collection(&mut self, key: Option<KeyCode>) -> Option<GameScreen> {
-        match key? {
-            KeyCode::Escape | KeyCode::Enter => Some(GameScreen::MainMenu),
-            KeyCode::ArrowUp | KeyCode::KeyW => {
-                self.collection_scroll = self.collection_scroll.saturating_sub(1);
+        let k = key?;
+        if k == KeyCode::KeyX {
+            if self.player.releasable_small_count() == 0 {
+                self.show_notice("No spare Small fish to release");
+                return None;
+            }
+            return Some(GameScreen::ConfirmReleaseSmalls);
+        }
+
+        let action = self.bindings.menu_action(k);
+        let menu = self.collection_menu.as_mut()?;
+        match action {
+            Some(Action::MenuUp) => {
+                menu.move_up();
                 None
             }
-            KeyCode::ArrowDown | KeyCode::KeyS => {
-                self.collection_scroll += 1;
+            Some(Action::MenuDown) => {
+                menu.move_down();
                 None
             }
+            Some(Action::Confirm) => {
+                let idx = menu.selected_index();
+                match self.collection_entries().get(idx) {
+                    Some(fish_id) if !self.player.is_discovered(fish_id) => {
+                        self.show_notice("Catch this fish to learn more about it");
+                        None
+                    }
+                    Some(fish_id) => Some(GameScreen::FishDetail {
+                        fish_id: fish_id.clone(),
+                    }),
+                    None => Some(GameScreen::MainMenu),
+                }
+            }
+            Some(Action::Back) => Some(GameScreen::MainMenu),
             _ => None,
         }
     }
 
+    fn update_fish_detail(&mut self, key: Option<KeyCode>, fish_id: &FishId) -> Option<GameScreen> {
+        match self.bindings.menu_action(key?) {
+            Some(Action::Confirm | Action::Back) => Some(GameScreen::FishCollection),
+            Some(Action::MenuUp) => {
+                self.detail_scroll = self.detail_scroll.saturating_sub(1);
+                None
+            }
+            Some(Action::MenuDown) => {
+                let max_scroll = self.max_scroll(self.lore_lines(fish_id).len());
+                self.detail_scroll = (self.detail_scroll + 1).min(max_scroll);
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// A fish's encyclopedia entry wrapped to `LORE_WIDTH`, with a blank
+    /// line between paragraphs.
+    fn lore_lines(&self, fish_id: &FishId) -> Vec<String> {
+        let lore = fish_id.encyclopedia_with_registry(&self.registry);
+        let mut lines = Vec::new();
+        for (i, paragraph) in lore.split("\n\n").enumerate() {
+            if i > 0 {
+                lines.push(String::new());
+            }
+            lines.extend(markup::word_wrap(paragraph, LORE_WIDTH));
+        }
+        lines
+    }
+
+    fn update_achievements(&mut self, key: Option<KeyCode>) -> Option<GameScreen> {
+        match self.bindings.menu_action(key?) {
+            Some(Action::Confirm | Action::Back) => Some(GameScreen::MainMenu),
+            Some(Action::MenuUp) => {
+                self.achievements_scroll = self.achievements_scroll.saturating_sub(1);
+                None
+            }
+            Some(Action::MenuDown) => {
+                let max_scroll = self.max_scroll(AchievementTracker::total_count());
+                self.achievements_scroll = (self.achievements_scroll + 1).min(max_scroll);
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Furthest a list of `len` lines or entries can scroll on the screen
+    /// on show.
+    fn max_scroll(&self, len: usize) -> usize {
+        len.saturating_sub(self.scroll_page.get().max(1))
+    }
+
+    fn update_stats(&mut self, key: Option<KeyCode>) -> Option<GameScreen> {
+        match self.bindings.menu_action(key?) {
+            Some(Action::Confirm | Action::Back) => Some(GameScreen::MainMenu),
+            Some(Action::MenuUp) => {
+                self.stats_scroll = self.stats_scroll.saturating_sub(1);
+                None
+            }
+            Some(Action::MenuDown) => {
+                let (left, right) = self.stats_columns();
+                let max_scroll = self.max_scroll(left.len().max(right.len()));
+                self.stats_scroll = (self.stats_scroll + 1).min(max_scroll);
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn update_journal(&mut self, key: Option<KeyCode>) -> Option<GameScreen> {
+        match self.bindings.menu_action(key?) {
+            Some(Action::Confirm | Action::Back) => Some(GameScreen::MainMenu),
+            Some(Action::MenuUp) => {
+                self.journal_scroll = self.journal_scroll.saturating_sub(1);
+                None
+            }
+            Some(Action::MenuDown) => {
+                let max_scroll = self.max_scroll(self.player.fish_collection.len());
+                self.journal_scroll = (self.journal_scroll + 1).min(max_scroll);
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn update_plugin_errors(&mut self, key: Option<KeyCode>) -> Option<GameScreen> {
+        match self.bindings.menu_action(key?) {
+            Some(Action::Confirm | Action::Back) => Some(GameScreen::MainMenu),
+            Some(Action::MenuUp) => {
+                self.plugin_errors_scroll = self.plugin_errors_scroll.saturating_sub(1);
+                None
+            }
+            Some(Action::MenuDown) => {
+                let max_scroll = self.max_scroll(self.registry.errors().len());
+                self.plugin_errors_scroll = (self.plugin_errors_scroll + 1).min(max_scroll);
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Menu lines for the plugin manager, one per manifest entry.
+    fn plugin_menu_items(manifest: &PluginManifest, dir: &std::path::Path) -> Vec<String> {
+        manifest
+            .plugins
+            .iter()
+            .map(|entry| {
+                let mark = if entry.enabled { "[x]" } else { "[ ]" };
+                if dir.join(&entry.file).exists() {
+                    format!("{} {}", mark, entry.file)
+                } else {
+                    format!("{} {} {}", mark, entry.file, t("(missing)"))
+                }
+            })
+            .collect()
+    }
+
+    fn update_plugin_manager(&mut self, key: Option<KeyCode>) -> Option<GameScreen> {
+        let k = key?;
+        let menu = self.plugin_menu.as_mut()?;
+        match self.bindings.menu_action(k) {
+            Some(Action::MenuUp) => menu.move_up(),
+            Some(Action::MenuDown) => menu.move_down(),
+            Some(Action::Confirm) => {
+                let index = menu.selected_index();
+                let manifest = self.plugin_manifest.as_mut()?;
+                let entry = manifest.plugins.get_mut(index)?;
+                entry.enabled = !entry.enabled;
+                let dir = self.plugin_report.dir.clone().unwrap_or_default();
+                let saved = manifest.save(&dir);
+                let mut menu = SelectionMenu::new(Self::plugin_menu_items(manifest, &dir));
+                menu.select(index);
+                self.plugin_menu = Some(menu);
+                match saved {
+                    Ok(()) => self.plugin_manifest_changed = true,
+                    Err(e) => {
+                        tracing::error!("Failed to save plugin manifest: {}", e);
+                        self.show_notice("Couldn't save the plugin manifest");
+                    }
+                }
+            }
+            Some(Action::Back) => {
+                // Apply the new selection once, on the way out
+                if std::mem::take(&mut self.plugin_manifest_changed) {
+                    self.reload_plugins();
+                }
+                return Some(GameScreen::MainMenu);
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn update_replays(&mut self, key: Option<KeyCode>) -> Option<GameScreen> {
+        let k = key?;
+        let menu = self.replay_menu.as_mut()?;
+        match self.bindings.menu_action(k) {
+            Some(Action::MenuUp) => menu.move_up(),
+            Some(Action::MenuDown) => menu.move_down(),
+            Some(Action::Confirm) => {
+                let replay = self.replays.get(menu.selected_index())?;
+                if !replay.available(&self.registry) {
+                    self.show_notice("Replay unavailable: that fish's plugin isn't loaded");
+                    return None;
+                }
+                let state = DatingState::replay(replay, &self.registry);
+                return Some(GameScreen::Dating(Box::new(state)));
+            }
+            Some(Action::Back) => return Some(GameScreen::MainMenu),
+            _ => {}
+        }
+        None
+    }
+
+    fn update_plugin_gallery(&mut self, key: Option<KeyCode>) -> Option<GameScreen> {
+        let k = key?;
+        let menu = self.gallery_menu.as_mut()?;
+        match self.bindings.menu_action(k) {
+            Some(Action::MenuUp) => menu.move_up(),
+            Some(Action::MenuDown) => menu.move_down(),
+            Some(Action::Back) => return Some(GameScreen::MainMenu),
+            _ => {}
+        }
+        None
+    }
+
     fn update_date_select(&mut self, key: Option<KeyCode>) -> Option<GameScreen> {
         let k = key?;
+        if k == KeyCode::KeyQ {
+            return self.start_quick_date();
+        }
         if let Some(ref mut menu) = self.date_select_menu {
-            match k {
-                KeyCode::ArrowUp | KeyCode::KeyW => {
+            match self.bindings.menu_action(k) {
+                Some(Action::MenuUp) => {
                     menu.move_up();
                     None
                 }
-                KeyCode::ArrowDown | KeyCode::KeyS => {
+                Some(Action::MenuDown) => {
                     menu.move_down();
                     None
                 }
-                KeyCode::Enter | KeyCode::Space => {
+                Some(Action::Confirm) => {
                     let idx = menu.selected_index();
                     let all_fish = FishId::all_with_plugins(&self.registry);
                     let dateable: Vec<FishId> = all_fish
                         .into_iter()
                         .filter(|f| self.player.has_caught(f))
                         .collect();
-                    if let Some(fish_id) = dateable.get(idx) {
-                        let date_num = self.player.date_count(fish_id);
-                        Some(GameScreen::Dating(DatingState::new(
-                            fish_id.clone(),
-                            date_num,
-                            &self.registry,
-                        )))
-                    } else {
-                        None
-                    }
+                    dateable.get(idx).map(|fish_id| GameScreen::GiftSelect {
+                        fish_id: fish_id.clone(),
+                    })
                 }
-                KeyCode::Escape => Some(GameScreen::MainMenu),
+                Some(Action::Back) => Some(GameScreen::MainMenu),
                 _ => None,
             }
         } else {
@@ -320,228 +1912,1086 @@ impl Game {
         }
     }
 
-    fn update_date_result(&mut self, key: Option<KeyCode>) -> Option<GameScreen> {
-        if let Some(KeyCode::Enter | KeyCode::Space) = key {
-            if self.player.has_won() {
-                return Some(GameScreen::GameOver);
+    fn update_gift_select(&mut self, key: Option<KeyCode>, fish_id: FishId) -> Option<GameScreen> {
+        let k = key?;
+        let menu = self.gift_menu.as_mut()?;
+        match self.bindings.menu_action(k) {
+            Some(Action::MenuUp) => {
+                menu.move_up();
+                None
+            }
+            Some(Action::MenuDown) => {
+                menu.move_down();
+                None
+            }
+            Some(Action::Confirm) => {
+                // Index 0 is "No gift", which never matches a favorite
+                let gift = &menu.items[menu.selected_index()];
+                let favorite = menu.selected_index() > 0
+                    && fish_id
+                        .favorite_gift_with_registry(&self.registry)
+                        .is_some_and(|fav| fav.eq_ignore_ascii_case(gift));
+                if self.spare_fish(&fish_id).is_empty() {
+                    return Some(self.start_date(fish_id, favorite, None));
+                }
+                Some(GameScreen::OfferSelect { fish_id, favorite })
+            }
+            Some(Action::Back) => Some(GameScreen::DateSelect),
+            _ => None,
+        }
+    }
+
+    /// Fish with a spare specimen that could be offered on a date with `date`.
+    fn spare_fish(&self, date: &FishId) -> Vec<FishId> {
+        FishId::all_with_plugins(&self.registry)
+            .into_iter()
+            .filter(|f| f != date && self.player.spare_size(f).is_some())
+            .collect()
+    }
+
+    /// Affection a date gets for being offered a spare `offered`. Fish from
+    /// the same pond are old friends and count for more.
+    fn offering_affection(&self, date: &FishId, offered: &FishId) -> i32 {
+        let base = match self.player.spare_size(offered) {
+            Some(FishSize::Medium) => OFFERING_AFFECTION_MEDIUM,
+            _ => OFFERING_AFFECTION_SMALL,
+        };
+        let pond = date.pond_index_with_registry(&self.registry);
+        if pond.is_some() && pond == offered.pond_index_with_registry(&self.registry) {
+            base + OFFERING_PONDMATE_BONUS
+        } else {
+            base
+        }
+    }
+
+    fn start_date(&self, fish_id: FishId, favorite: bool, offering: Option<(FishId, i32)>) -> GameScreen {
+        let mut state = DatingState::new(fish_id, favorite, &self.player, &self.registry);
+        state.set_offering(offering);
+        GameScreen::Dating(Box::new(state))
+    }
+
+    fn update_offer_select(&mut self, key: Option<KeyCode>, fish_id: FishId, favorite: bool) -> Option<GameScreen> {
+        let k = key?;
+        let menu = self.offer_menu.as_mut()?;
+        match self.bindings.menu_action(k) {
+            Some(Action::MenuUp) => {
+                menu.move_up();
+                None
+            }
+            Some(Action::MenuDown) => {
+                menu.move_down();
+                None
+            }
+            Some(Action::Confirm) => {
+                // Index 0 is "Nothing"
+                let offering = menu
+                    .selected_index()
+                    .checked_sub(1)
+                    .and_then(|i| self.offer_choices.get(i))
+                    .map(|offered| (offered.clone(), self.offering_affection(&fish_id, offered)));
+                Some(self.start_date(fish_id, favorite, offering))
             }
+            Some(Action::Back) => Some(GameScreen::GiftSelect { fish_id }),
+            _ => None,
+        }
+    }
+
+    /// Caught fish close enough to the player to come on a group date.
+    fn group_date_fish(&self) -> Vec<FishId> {
+        FishId::all_with_plugins(&self.registry)
+            .into_iter()
+            .filter(|f| self.player.has_caught(f) && self.player.relationship(f) >= GROUP_DATE_MIN_AFFECTION)
+            .collect()
+    }
+
+    fn update_group_date_select(&mut self, key: Option<KeyCode>, first: Option<FishId>) -> Option<GameScreen> {
+        let k = key?;
+        let menu = self.group_menu.as_mut()?;
+        match self.bindings.menu_action(k) {
+            Some(Action::MenuUp) => {
+                menu.move_up();
+                None
+            }
+            Some(Action::MenuDown) => {
+                menu.move_down();
+                None
+            }
+            Some(Action::Confirm) => {
+                let picked = self.group_choices.get(menu.selected_index())?.clone();
+                match first {
+                    None => Some(GameScreen::GroupDateSelect { first: Some(picked) }),
+                    Some(first) => {
+                        let state = DatingState::group(first, picked, &self.player, &self.registry);
+                        Some(GameScreen::GroupDate(Box::new(state)))
+                    }
+                }
+            }
+            // Back out of the second pick to change the first
+            Some(Action::Back) if first.is_some() => Some(GameScreen::GroupDateSelect { first: None }),
+            Some(Action::Back) => Some(GameScreen::MainMenu),
+            _ => None,
+        }
+    }
+
+    fn update_no_dates(&mut self, key: Option<KeyCode>) -> Option<GameScreen> {
+        match self.bindings.menu_action(key?) {
+            Some(Action::Confirm | Action::Back) => Some(GameScreen::MainMenu),
+            _ => None,
+        }
+    }
+
+    /// Quick date with the fish picked on the date list, once the player
+    /// has been on a full date with it.
+    fn start_quick_date(&mut self) -> Option<GameScreen> {
+        let idx = self.date_select_menu.as_ref()?.selected_index();
+        let fish_id = FishId::all_with_plugins(&self.registry)
+            .into_iter()
+            .filter(|f| self.player.has_caught(f))
+            .nth(idx)?;
+        if self.player.date_count(&fish_id) == 0 {
+            let name = fish_id.name_with_registry(&self.registry);
+            self.show_notice(&tf("Go on a full date with {} first", &[&name]));
+            return None;
+        }
+        Some(GameScreen::QuickDate { fish_id })
+    }
+
+    /// Affection a quick date choice gives. Teasing only lands with fish
+    /// the player has good chemistry with.
+    fn quick_date_affection(&self, fish_id: &FishId, choice: &str) -> i32 {
+        match choice {
+            "Compliment" => QUICK_DATE_COMPLIMENT,
+            "Tease" => match self.player.chemistry(fish_id) {
+                Chemistry::High => QUICK_DATE_COMPLIMENT + 1,
+                Chemistry::Medium | Chemistry::Unknown => QUICK_DATE_COMPLIMENT - 1,
+                Chemistry::Low => 0,
+            },
+            _ => 0,
+        }
+    }
+
+    fn update_quick_date(&mut self, key: Option<KeyCode>, fish_id: FishId) -> Option<GameScreen> {
+        let k = key?;
+        if self.quick_date_outcome.is_some() {
+            return self.bindings.is(Action::Confirm, k).then_some(GameScreen::MainMenu);
+        }
+        let menu = self.quick_date_menu.as_mut()?;
+        match self.bindings.menu_action(k) {
+            Some(Action::MenuUp) => menu.move_up(),
+            Some(Action::MenuDown) => menu.move_down(),
+            Some(Action::Confirm) => {
+                let choice = menu.items[menu.selected_index()].clone();
+                let affection = self.quick_date_affection(&fish_id, &choice);
+                let reaction = match (choice.as_str(), affection) {
+                    ("Compliment", _) => "{} blushes and swims a happy loop.",
+                    ("Tease", a) if a > QUICK_DATE_COMPLIMENT => "{} laughs and teases you right back!",
+                    ("Tease", 0) => "{} doesn't find that funny at all.",
+                    ("Tease", _) => "{} isn't sure whether to laugh.",
+                    _ => "{} drifts off, unimpressed.",
+                };
+                self.player.add_affection(fish_id.clone(), affection);
+                self.finish_date(&fish_id);
+                self.quick_date_outcome = Some((reaction.to_string(), affection));
+            }
+            // Backing out before choosing doesn't give the action back;
+            // the fish still waited for you
+            Some(Action::Back) => return Some(GameScreen::MainMenu),
+            _ => {}
+        }
+        None
+    }
+
+    fn update_date_result(&mut self, key: Option<KeyCode>) -> Option<GameScreen> {
+        if key.is_some_and(|k| self.bindings.is(Action::Confirm, k)) {
             return Some(GameScreen::MainMenu);
         }
         None
     }
 
     fn update_game_over(&mut self, key: Option<KeyCode>) -> Option<GameScreen> {
-        if let Some(KeyCode::Enter | KeyCode::Space) = key {
+        if key.is_some_and(|k| self.bindings.is(Action::Confirm, k)) {
             self.player = PlayerState::default();
-            let _ = save::save_game(&self.player);
+            let _ = self.save();
             return Some(GameScreen::MainMenu);
         }
         None
     }
 
-    pub fn render(&self, renderer: &mut GameRenderer) {
+    pub fn render(&self, renderer: &mut dyn DrawSurface) {
+        renderer.set_reduce_motion(self.settings.reduce_motion);
         match &self.screen {
+            GameScreen::SlotSelect => self.render_slot_select(renderer),
             GameScreen::MainMenu => self.render_main_menu(renderer),
             GameScreen::FishingPondSelect => {
                 if let Some(ref state) = self.pond_state {
-                    state.render(renderer, self.time, &self.registry);
+                    state.render(renderer, self.time, &self.registry, &self.player);
                 }
             }
-            GameScreen::FishingMinigame(state) => state.render(renderer, self.time, &self.registry),
+            GameScreen::FishingMinigame(state) => {
+                state.render(
+                    renderer,
+                    self.time,
+                    &self.registry,
+                    self.settings.shake_scale(),
+                    &MeterStyle::from_preset(self.settings.meter_style),
+                )
+            }
             GameScreen::CatchResult {
                 fish_id,
+                pond_index,
                 size,
-                ..
-            } => self.render_catch_result(renderer, fish_id, *size),
+                shiny,
+                mystery,
+            } => {
+                let special = !*mystery && self.is_special_pond(*pond_index);
+                self.render_catch_result(renderer, fish_id, *size, *shiny, special)
+            }
             GameScreen::FishCollection => self.render_collection(renderer),
+            GameScreen::FishDetail { fish_id } => self.render_fish_detail(renderer, fish_id),
+            GameScreen::Achievements => self.render_achievements(renderer),
+            GameScreen::Stats => self.render_stats(renderer),
+            GameScreen::Journal => self.render_journal(renderer),
             GameScreen::DateSelect => self.render_date_select(renderer),
+            GameScreen::GiftSelect { fish_id } => self.render_gift_select(renderer, fish_id),
+            GameScreen::QuickDate { fish_id } => self.render_quick_date(renderer, fish_id),
+            GameScreen::OfferSelect { fish_id, .. } => self.render_offer_select(renderer, fish_id),
             GameScreen::Dating(state) => {
-                let affection = self.player.relationship(&state.fish_id);
-                state.render(renderer, affection, self.time, &self.registry);
+                let affection = if state.is_replay() {
+                    state.starting_affection()
+                } else {
+                    self.player.relationship(&state.fish_id)
+                };
+                state.render(renderer, affection, self.time, &self.registry, &self.settings);
             }
-            GameScreen::DateResult { fish_id, affection } => {
-                self.render_date_result(renderer, fish_id, *affection);
+            GameScreen::GroupDateSelect { first } => self.render_group_date_select(renderer, first.as_ref()),
+            GameScreen::GroupDate(state) => {
+                // Hearts follow the pair's average affection
+                let partner = state.partner().map_or(0, |f| self.player.relationship(f));
+                let affection = (self.player.relationship(&state.fish_id) + partner) / 2;
+                state.render(renderer, affection, self.time, &self.registry, &self.settings);
+            }
+            GameScreen::GroupDateResult {
+                fish,
+                affection,
+                ended_early,
+                ..
+            } => self.render_group_date_result(renderer, fish, *affection, *ended_early),
+            GameScreen::DateResult {
+                fish_id,
+                affection,
+                gift_bonus,
+                ended_early,
+                offering,
+                ..
+            } => {
+                self.render_date_result(
+                    renderer,
+                    fish_id,
+                    *affection,
+                    *gift_bonus,
+                    *ended_early,
+                    offering.as_ref(),
+                );
             }
             GameScreen::GameOver => self.render_game_over(renderer),
-            GameScreen::MoonBattle(state) => state.render(renderer, self.time),
+            GameScreen::ConfirmQuit => self.render_confirm_quit(renderer),
+            GameScreen::ConfirmNewGame => self.render_confirm_new_game(renderer),
+            GameScreen::ConfirmResumeDate(resume) => self.render_confirm_resume_date(renderer, resume),
+            GameScreen::ConfirmReleaseSmalls => self.render_confirm_release_smalls(renderer),
+            GameScreen::PluginErrors => self.render_plugin_errors(renderer),
+            GameScreen::PluginManager => self.render_plugin_manager(renderer),
+            GameScreen::Replays => self.render_replays(renderer),
+            GameScreen::PluginGallery => self.render_plugin_gallery(renderer),
+            GameScreen::ModeSelect => self.render_mode_select(renderer),
+            GameScreen::NameEntry => self.render_name_entry(renderer),
+            GameScreen::WelcomeBack(welcome) => self.render_welcome_back(renderer, welcome),
+            GameScreen::NoDates => self.render_no_dates(renderer),
+            GameScreen::MoonBattle(state) => {
+                state.render(renderer, self.time, self.settings.shake_scale())
+            }
+            GameScreen::Cutscene(state) => state.render(renderer),
+        }
+
+        if self.help_open {
+            match &self.screen {
+                GameScreen::FishingMinigame(_) => {
+                    self.render_help(renderer, "HOW TO FISH", MINIGAME_HELP)
+                }
+                GameScreen::Dating(_) | GameScreen::GroupDate(_) => {
+                    self.render_help(renderer, "HOW DATES WORK", DATE_HELP)
+                }
+                _ => {}
+            }
+        }
+
+        if let Some((text, timer)) = &self.notice {
+            let alpha = timer.min(1.0);
+            let row = renderer.screen_rows() - 1.0;
+            renderer.draw_centered(t(text), row, [1.0, 1.0, 0.0, alpha]);
+        }
+
+        if self.volume_overlay > 0.0 {
+            self.render_volume_overlay(renderer);
+        }
+
+        if self.autosave_indicator > 0.0 {
+            let text = t("Saved");
+            let col = renderer.screen_cols() - text.chars().count() as f32 - 1.0;
+            let [r, g, b, _] = Colors::gray();
+            let alpha = self.autosave_indicator.min(1.0);
+            renderer.draw_at_grid(text, col, 1.0, [r, g, b, alpha]);
         }
 
         // Achievement toast overlay (drawn on top of everything)
         self.achievements.render_toasts(renderer);
     }
 
-    fn render_main_menu(&self, renderer: &mut GameRenderer) {
-        // Window gives us ~48 rows (768px / 16px per row). Spread content evenly.
+    /// Small volume bar in the top-left corner, or "Muted".
+    fn render_volume_overlay(&self, renderer: &mut dyn DrawSurface) {
+        let (col, row) = (2.0, 1.0);
+        let label = t("Volume");
+        renderer.draw_at_grid(label, col, row, Colors::white());
+        let bar_col = col + label.chars().count() as f32 + 1.0;
+        if self.settings.muted {
+            renderer.draw_at_grid(t("Muted"), bar_col, row, Colors::red());
+            return;
+        }
+        ui::draw_progress_bar(
+            renderer,
+            bar_col,
+            row,
+            12,
+            self.settings.master_volume,
+            Colors::cyan(),
+            Colors::dark_gray(),
+        );
+    }
+
+    /// Draw a boxed help overlay over the current screen.
+    fn render_help(&self, renderer: &mut dyn DrawSurface, title: &str, lines: &[&str]) {
+        let box_width = 56;
+        let box_height = lines.len() + 4;
+        let box_row = 8.0;
+        let cols = renderer.screen_cols() as usize;
+        let box_col = (cols.saturating_sub(box_width) / 2) as f32;
+
+        // Blank out the scene behind the box
+        let blank = " ".repeat(box_width);
+        for i in 0..box_height {
+            renderer.draw_at_grid(&blank, box_col, box_row + i as f32, Colors::white());
+        }
+        ui::draw_box(renderer, box_col, box_row, box_width, box_height, Colors::yellow());
+        renderer.draw_at_grid(&format!(" {} ", t(title)), box_col + 2.0, box_row, Colors::yellow());
+
+        for (i, line) in lines.iter().enumerate() {
+            renderer.draw_at_grid(t(line), box_col + 2.0, box_row + 1.0 + i as f32, Colors::white());
+        }
+        renderer.draw_at_grid(
+            t("[H/Enter/Esc] Close"),
+            box_col + 2.0,
+            box_row + (box_height - 2) as f32,
+            Colors::dark_gray(),
+        );
+    }
+
+    fn render_slot_select(&self, renderer: &mut dyn DrawSurface) {
+        let title_art = ascii_art::TITLE_ART.trim_start_matches('\n');
+        let title_lines = title_art.lines().count() as f32;
+        renderer.draw_multiline_centered(title_art, 1.0, Colors::cyan());
+
+        let mut row = 1.0 + title_lines + 3.0;
+        renderer.draw_centered(t("=== SELECT A SAVE SLOT ==="), row, Colors::pink());
+        row += 2.0;
+        if let Some(ref menu) = self.slot_menu {
+            menu.draw_centered(renderer, row);
+            row += menu.items.len() as f32;
+        }
+
+        renderer.draw_centered(
+            t("[Enter] Play  [D] Delete  [Esc] Quit"),
+            row + 3.0,
+            Colors::dark_gray(),
+        );
+    }
+
+    fn render_mode_select(&self, renderer: &mut dyn DrawSurface) {
+        renderer.draw_centered(t("=== NEW GAME ==="), 3.0, Colors::pink());
+        renderer.draw_centered(t("Choose how tough the fish play:"), 5.0, Colors::white());
+
+        let Some(ref menu) = self.mode_menu else {
+            return;
+        };
+        menu.draw_centered(renderer, 8.0);
+
+        let mode = GameMode::ALL[menu.selected_index()];
+        renderer.draw_centered(t(mode.description()), 8.0 + menu.items.len() as f32 + 2.0, Colors::gray());
+        renderer.draw_centered(
+            t("[Enter] Start  [Esc] Back"),
+            8.0 + menu.items.len() as f32 + 5.0,
+            Colors::dark_gray(),
+        );
+    }
+
+    fn render_name_entry(&self, renderer: &mut dyn DrawSurface) {
+        renderer.draw_centered(t("=== NEW GAME ==="), 3.0, Colors::pink());
+        renderer.draw_centered(t("What should the fish call you?"), 5.0, Colors::white());
+        if let Some(ref input) = self.name_input {
+            input.draw_centered(renderer, 8.0, self.time);
+        }
+        renderer.draw_centered(
+            &tf("Leave it blank to go by \"{}\".", &[&t(DEFAULT_PLAYER_NAME)]),
+            10.0,
+            Colors::gray(),
+        );
+        renderer.draw_centered(t("[Enter] Confirm  [Backspace] Delete"), 13.0, Colors::dark_gray());
+    }
+
+    fn render_main_menu(&self, renderer: &mut dyn DrawSurface) {
+        // Window gives us ~48 rows (768px / 16px per row). Spread content evenly.
+
+        // Title art — skip the leading blank line in the raw string
+        let title_art = ascii_art::TITLE_ART.trim_start_matches('\n');
+        let title_lines = title_art.lines().count() as f32;
+        let hue = ui::wave(renderer, self.time * 0.5) * 0.5 + 0.5;
+        let title_color = [0.0 + hue * 0.3, 0.8 + hue * 0.2, 1.0, 1.0];
+        renderer.draw_multiline_centered(title_art, 1.0, title_color);
+
+        // Subtitle — 3 row gap after title art
+        let mut row = 1.0 + title_lines + 3.0;
+        let pulse = ui::wave(renderer, self.time * 2.0) * 0.2 + 0.8;
+        renderer.draw_centered(
+            ascii_art::SUBTITLE,
+            row,
+            [1.0, 1.0, 0.0, pulse],
+        );
+
+        // Animated swimming fish — 3 row gap after subtitle
+        row += 3.0;
+        let fish_x_offset = ui::wave(renderer, self.time * 1.5) * 3.0;
+        let cols = renderer.screen_cols();
+        let fish_col = (cols / 2.0 - 5.0 + fish_x_offset) as f32;
+        renderer.draw_at_grid(
+            ascii_art::BUBBLES_SMALL,
+            fish_col,
+            row,
+            Colors::orange(),
+        );
+        renderer.draw_at_grid(
+            ascii_art::MARINA_SMALL,
+            fish_col + 15.0 - fish_x_offset * 0.5,
+            row + 1.0,
+            Colors::light_blue(),
+        );
+        renderer.draw_at_grid(
+            ascii_art::GILL_SMALL,
+            fish_col + 5.0 + fish_x_offset * 0.7,
+            row + 2.0,
+            Colors::green(),
+        );
+
+        // Animated water line — directly below fish (3 fish lines)
+        row += 4.0;
+        let wave = if ui::anim_frame(renderer, self.time * 3.0).is_multiple_of(2) {
+            "~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~"
+        } else {
+            " ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~"
+        };
+        renderer.draw_centered(wave, row, [0.2, 0.3, 0.7, 0.6]);
+
+        // Menu — 3 row gap after water line
+        row += 3.0;
+        self.menu.draw_centered(renderer, row);
+        row += self.menu.items.len() as f32;
+
+        // Plugin count indicator — 2 row gap after menu
+        row += 2.0;
+        if self.plugin_report.fish_count > 0 {
+            renderer.draw_centered(
+                &tf("Plugins: {} fish loaded", &[&self.plugin_report.fish_count]),
+                row,
+                Colors::purple(),
+            );
+            row += 1.0;
+        }
+        let plugins_dir = self
+            .plugin_report
+            .dir
+            .as_ref()
+            .map_or_else(|| t("none found").to_string(), |d| d.display().to_string());
+        renderer.draw_centered(
+            &tf("Plugins dir: {}  [F5] Reload", &[&plugins_dir]),
+            row,
+            Colors::dark_gray(),
+        );
+        row += 2.0;
+        let failed = self.registry.failed_plugin_count();
+        if failed > 0 {
+            let message = if failed == 1 {
+                t("1 plugin failed to load - press P to view").to_string()
+            } else {
+                tf("{} plugins failed to load - press P to view", &[&failed])
+            };
+            renderer.draw_centered(
+                &message,
+                row,
+                Colors::red(),
+            );
+            row += 2.0;
+        }
+
+        // Status bar — 2 row gap
+        row += 2.0;
+        let day = self.player.current_day;
+        let fish_count = self.player.fish_collection.len();
+        let dates = self.player.dates_completed;
+        let ach_unlocked = AchievementTracker::unlocked_count(&self.player.achievements);
+        let ach_total = AchievementTracker::total_count();
+        let playtime = self.player.playtime_label();
+        renderer.draw_centered(
+            &tf(
+                "Day {} | Actions: {}/{} | Daily streak: {} | Fish: {} | Dates: {} | Achievements: {}/{} | Time: {} | {}",
+                &[
+                    &day,
+                    &self.player.actions_remaining,
+                    &ACTIONS_PER_DAY,
+                    &self.player.login_streak,
+                    &fish_count,
+                    &dates,
+                    &ach_unlocked,
+                    &ach_total,
+                    &playtime,
+                    &t(self.player.game_mode.label()),
+                ],
+            ),
+            row,
+            Colors::dark_gray(),
+        );
+
+        // Controls hint — 3 row gap
+        renderer.draw_centered(
+            t("[Arrow Keys] Navigate  [Enter] Select  [Esc] Quit"),
+            row + 3.0,
+            [0.3, 0.3, 0.3, 0.5],
+        );
+    }
+
+    /// Whether `pond_index` is today's special pond.
+    fn is_special_pond(&self, pond_index: usize) -> bool {
+        special_pond_index(self.player.current_day, self.registry.pond_count()) == Some(pond_index)
+    }
+
+    fn render_catch_result(
+        &self,
+        renderer: &mut dyn DrawSurface,
+        fish_id: &FishId,
+        size: FishSize,
+        shiny: bool,
+        special: bool,
+    ) {
+        if shiny {
+            let color = ui::rainbow_color(renderer, self.time);
+            renderer.draw_centered(t("=== SHINY CATCH! ==="), 2.0, color);
+        } else {
+            renderer.draw_centered(t("=== CATCH! ==="), 2.0, Colors::green());
+        }
+
+        renderer.draw_multiline_centered(ascii_art::CATCH_SUCCESS, 4.0, Colors::yellow());
+
+        let art = fish_helpers::fish_art(fish_id, 0, self.time, &self.registry);
+        let art_color = if shiny { ui::rainbow_color(renderer, self.time) } else { fish_id.color_with_registry(&self.registry) };
+        renderer.draw_multiline_centered(&art, 11.0, art_color);
+
+        let name = fish_id.name_with_registry(&self.registry);
+        let species = fish_id.species_with_registry(&self.registry);
+        renderer.draw_centered(
+            &tf("You caught {} ({})!", &[&name, &species]),
+            19.0,
+            Colors::white(),
+        );
+        let size_line = if special {
+            tf("Size: {}  * Today's special! (+{} affection)", &[&t(size.label()), &SPECIAL_POND_BONUS])
+        } else {
+            tf("Size: {}", &[&t(size.label())])
+        };
+        renderer.draw_centered(&size_line, 20.0, Colors::yellow());
+        renderer.draw_centered(
+            &tf("Total {} kept: {}", &[&name, &self.player.catch_count(fish_id)]),
+            21.0,
+            Colors::gray(),
+        );
+        let streak = self.player.perfect_streak;
+        if streak > 1 {
+            let bonus = (streak as i32 - 1).min(MAX_STREAK_BONUS);
+            renderer.draw_centered(
+                &tf("Perfect streak: {} (+{} affection)", &[&streak, &bonus]),
+                22.0,
+                Colors::orange(),
+            );
+        } else if streak == 1 {
+            renderer.draw_centered(t("Perfect streak: 1"), 22.0, Colors::orange());
+        }
+
+        renderer.draw_centered(
+            &tf("[K]eep  [R]elease (+{} affection)", &[&RELEASE_AFFECTION_BONUS]),
+            23.0,
+            Colors::yellow(),
+        );
+        renderer.draw_centered(t("[F] Keep and fish here again"), 24.0, Colors::white());
+        if !self.player.has_caught(fish_id) {
+            renderer.draw_centered(
+                &tf("Keep at least one {} to go on dates.", &[&name]),
+                25.0,
+                Colors::dark_gray(),
+            );
+        }
+    }
+
+    fn render_collection(&self, renderer: &mut dyn DrawSurface) {
+        renderer.draw_centered(t("=== FISH COLLECTION ==="), 1.0, Colors::cyan());
+        renderer.draw_centered(
+            &tf("Shiny: {} | Released: {}", &[&self.player.shiny_count(), &self.player.released_count]),
+            2.0,
+            Colors::gray(),
+        );
+
+        let completion = self.player.completion_percent(&self.registry);
+        let cols = renderer.screen_cols() as usize;
+        let bar_col = centered_start_col(cols as f32, 32.0);
+        let dex = tf("Dex {}%", &[&format!("{:>3.0}", completion)]);
+        renderer.draw_at_grid(&dex, bar_col, 3.0, Colors::white());
+        ui::draw_progress_bar(
+            renderer,
+            bar_col + 10.0,
+            3.0,
+            22,
+            completion / 100.0,
+            Colors::cyan(),
+            Colors::dark_gray(),
+        );
+
+        if self.player.discovered.is_empty() {
+            renderer.draw_centered(t("No fish caught yet! Go fishing!"), 10.0, Colors::gray());
+            renderer.draw_centered(t("[Enter/Esc] Back"), 12.0, Colors::dark_gray());
+            return;
+        }
+
+        let Some(menu) = &self.collection_menu else { return };
+        let fish = self.collection_entries();
+        // Each entry takes three rows; scroll only as far as needed to keep
+        // the selected one in view
+        let visible = ((renderer.screen_rows() - 9.0) / 3.0).max(1.0) as usize;
+        let selected = menu.selected_index();
+        let mut first = self.collection_scroll.get().min(fish.len().saturating_sub(visible));
+        if selected < first {
+            first = selected;
+        } else if selected >= first + visible {
+            first = selected + 1 - visible;
+        }
+        self.collection_scroll.set(first);
+
+        if first > 0 {
+            renderer.draw_centered(t("^ more ^"), 4.0, Colors::dark_gray());
+        }
+
+        let mut row = 5.0;
+        for (i, fish_id) in fish.iter().enumerate().skip(first).take(visible) {
+            let prefix = if i == menu.selected_index() { "> " } else { "  " };
+            if !self.player.is_discovered(fish_id) {
+                renderer.draw_centered(
+                    &tf(
+                        "{}{} ({}) - Not caught yet",
+                        &[&prefix, &UNDISCOVERED_NAME, &UNDISCOVERED_NAME],
+                    ),
+                    row,
+                    Colors::dark_gray(),
+                );
+                row += 3.0;
+                continue;
+            }
+
+            let count = self.player.catch_count(fish_id);
+            let score = self.player.relationship(fish_id);
+            let label = t(relationship_label(score));
+            let name = fish_id.name_with_registry(&self.registry);
+            let species = fish_id.species_with_registry(&self.registry);
+            let best = self.player.best_size(fish_id).map_or("-", |s| t(s.label()));
+
+            renderer.draw_centered(
+                &tf(
+                    "{}{} ({}) - Caught: {} - Best: {} - {}: {}",
+                    &[&prefix, &name, &species, &count, &best, &label, &score],
+                ),
+                row,
+                if self.player.has_shiny(fish_id) {
+                    ui::rainbow_color(renderer, self.time)
+                } else {
+                    fish_id.color_with_registry(&self.registry)
+                },
+            );
+
+            // Mini hearts
+            ui::draw_hearts(renderer, centered_start_col(cols as f32, 16.0), row + 1.0, score, 5);
+            if let Some(rate) = self.player.catch_rate(fish_id) {
+                renderer.draw_at_grid(
+                    &tf("Rate {}%", &[&format!("{:.0}", rate)]),
+                    (cols / 2 + 9) as f32,
+                    row + 1.0,
+                    Colors::gray(),
+                );
+            }
+
+            row += 3.0;
+        }
+
+        if first + visible < fish.len() {
+            renderer.draw_centered(t("v more v"), row, Colors::dark_gray());
+        }
+        renderer.draw_centered(
+            t("[Enter] Encyclopedia  [X] Release Smalls  [Esc] Back"),
+            row + 1.0,
+            Colors::dark_gray(),
+        );
+    }
+
+    fn render_fish_detail(&self, renderer: &mut dyn DrawSurface, fish_id: &FishId) {
+        let name = fish_id.name_with_registry(&self.registry);
+        let species = fish_id.species_with_registry(&self.registry);
+        let color = fish_id.color_with_registry(&self.registry);
+        let score = self.player.relationship(fish_id);
+
+        renderer.draw_centered(&format!("=== {} ===", name.to_uppercase()), 1.0, Colors::cyan());
+        renderer.draw_centered(&species, 2.0, Colors::gray());
 
-        // Title art — skip the leading blank line in the raw string
-        let title_art = ascii_art::TITLE_ART.trim_start_matches('\n');
-        let title_lines = title_art.lines().count() as f32;
-        let hue = (self.time * 0.5).sin() * 0.5 + 0.5;
-        let title_color = [0.0 + hue * 0.3, 0.8 + hue * 0.2, 1.0, 1.0];
-        renderer.draw_multiline_centered(title_art, 1.0, title_color);
+        let art = fish_helpers::fish_art(fish_id, score, self.time, &self.registry);
+        renderer.draw_multiline_centered(&art, 4.0, color);
+        let mut row = 5.0 + art.lines().count() as f32;
 
-        // Subtitle — 3 row gap after title art
-        let mut row = 1.0 + title_lines + 3.0;
-        let pulse = (self.time * 2.0).sin() * 0.2 + 0.8;
-        renderer.draw_centered(
-            ascii_art::SUBTITLE,
+        let col = centered_start_col(renderer.screen_cols(), LORE_WIDTH as f32);
+        let difficulty = fish_id.difficulty_with_registry(&self.registry);
+        renderer.draw_at_grid(t("Difficulty:"), col, row, Colors::white());
+        ui::draw_difficulty_stars(renderer, col + 15.0, row, difficulty, DIFFICULTY_STARS);
+        row += 1.0;
+
+        renderer.draw_at_grid(t("Fights like:"), col, row, Colors::white());
+        renderer.draw_at_grid(
+            t(fish_id.personality_with_registry(&self.registry).label()),
+            col + 15.0,
             row,
-            [1.0, 1.0, 0.0, pulse],
+            Colors::light_blue(),
         );
+        row += 1.0;
 
-        // Animated swimming fish — 3 row gap after subtitle
-        row += 3.0;
-        let fish_x_offset = (self.time * 1.5).sin() * 3.0;
-        let cols = renderer.screen_cols();
-        let fish_col = (cols / 2.0 - 5.0 + fish_x_offset) as f32;
+        renderer.draw_at_grid(t("Date spot:"), col, row, Colors::white());
         renderer.draw_at_grid(
-            ascii_art::BUBBLES_SMALL,
-            fish_col,
+            &fish_helpers::date_location(fish_id, &self.registry),
+            col + 15.0,
             row,
-            Colors::ORANGE,
+            Colors::light_blue(),
         );
+        row += 1.0;
+
+        renderer.draw_at_grid(t("Relationship:"), col, row, Colors::white());
         renderer.draw_at_grid(
-            ascii_art::MARINA_SMALL,
-            fish_col + 15.0 - fish_x_offset * 0.5,
-            row + 1.0,
-            Colors::LIGHT_BLUE,
+            &format!("{} ({})", t(relationship_label(score)), score),
+            col + 15.0,
+            row,
+            Colors::pink(),
         );
-        renderer.draw_at_grid(
-            ascii_art::GILL_SMALL,
-            fish_col + 5.0 + fish_x_offset * 0.7,
-            row + 2.0,
-            Colors::GREEN,
+        row += 1.0;
+        ui::draw_hearts(renderer, col + 15.0, row, score, 5);
+        row += 2.0;
+
+        let footer_row = renderer.screen_rows() - 2.0;
+        let lines = self.lore_lines(fish_id);
+        let room = (footer_row - 1.0 - row).max(0.0) as usize;
+        self.scroll_page.set(room);
+        let shown: Vec<String> = lines.iter().skip(self.detail_scroll).take(room).cloned().collect();
+        markup::draw_lines(renderer, &shown, col, row, Colors::white());
+
+        let hint = if lines.len() > room {
+            "[Arrow Keys] Scroll  [Enter/Esc] Back"
+        } else {
+            "[Enter/Esc] Back"
+        };
+        renderer.draw_centered(t(hint), footer_row, Colors::dark_gray());
+    }
+
+    fn render_achievements(&self, renderer: &mut dyn DrawSurface) {
+        let page = AchievementTracker::render_list(
+            renderer,
+            &self.player.achievements,
+            1.0,
+            self.achievements_scroll,
+        );
+        self.scroll_page.set(page);
+        let footer_row = renderer.screen_rows() - 2.0;
+        renderer.draw_centered(
+            t("[Arrow Keys] Scroll  [Enter/Esc] Back"),
+            footer_row,
+            Colors::dark_gray(),
         );
+    }
 
-        // Animated water line — directly below fish (3 fish lines)
-        row += 4.0;
-        let wave = if ((self.time * 3.0) as i32) % 2 == 0 {
-            "~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~"
+    /// Lines for the stats screen: lifetime totals on the left, catches per
+    /// species on the right.
+    fn stats_columns(&self) -> (Vec<String>, Vec<String>) {
+        let player = &self.player;
+        let [small, medium, large] = player.size_counts();
+        let highest = player.highest_affection();
+        let catch_rate_line = match player.catch_totals() {
+            (_, 0) => t("Catch rate: -").to_string(),
+            (landed, fought) => tf(
+                "Catch rate: {}% ({}/{})",
+                &[&format!("{:.0}", landed as f32 / fought as f32 * 100.0), &landed, &fought],
+            ),
+        };
+        let got_away_line = match &player.last_got_away {
+            Some((fish_id, day)) => {
+                tf("{} got away on Day {}", &[&fish_id.name_with_registry(&self.registry), &day])
+            }
+            None => t("Nothing has gotten away").to_string(),
+        };
+        let left = vec![
+            tf("Fish caught: {}", &[&player.fish_collection.len()]),
+            tf("  Small: {}", &[&small]),
+            tf("  Medium: {}", &[&medium]),
+            tf("  Large: {}", &[&large]),
+            tf("Shiny: {}", &[&player.shiny_count()]),
+            tf("Released: {}", &[&player.released_count]),
+            catch_rate_line,
+            got_away_line,
+            String::new(),
+            tf("Dates: {}", &[&player.dates_completed]),
+            tf("Best affection: {} ({})", &[&highest, &t(relationship_label(highest))]),
+            String::new(),
+            tf("Day: {}", &[&player.current_day]),
+            tf("Playtime: {}", &[&player.playtime_label()]),
+            tf("Plugin fish found: {}/{}", &[&player.plugin_fish_discovered(), &self.registry.count()]),
+        ];
+
+        let breakdown = player.species_breakdown(&self.registry);
+        let right = if breakdown.is_empty() {
+            vec![t("Nothing caught yet").to_string()]
         } else {
-            " ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~"
+            breakdown
+                .iter()
+                .map(|(species, count)| format!("{}: {}", species, count))
+                .collect()
         };
-        renderer.draw_centered(wave, row, [0.2, 0.3, 0.7, 0.6]);
 
-        // Menu — 3 row gap after water line
-        row += 3.0;
-        self.menu.draw_centered(renderer, row);
-        row += self.menu.items.len() as f32;
+        (left, right)
+    }
 
-        // Plugin count indicator — 2 row gap after menu
-        row += 2.0;
-        if self.registry.count() > 0 {
-            renderer.draw_centered(
-                &format!("Plugins: {} fish loaded", self.registry.count()),
-                row,
-                Colors::PURPLE,
-            );
-            row += 2.0;
+    fn render_stats(&self, renderer: &mut dyn DrawSurface) {
+        renderer.draw_centered(t("=== STATS ==="), 1.0, Colors::cyan());
+
+        let (left, right) = self.stats_columns();
+        let box_width = 36;
+        let gap = 2;
+        let cols = renderer.screen_cols() as usize;
+        let left_col = (cols.saturating_sub(box_width * 2 + gap) / 2) as f32;
+        let right_col = left_col + (box_width + gap) as f32;
+        let box_row = 3.0;
+
+        let footer_row = renderer.screen_rows() - 2.0;
+        let max_height = (footer_row - box_row - 1.0).max(5.0) as usize;
+        let content_rows = left.len().max(right.len());
+        let box_height = (content_rows + 2).min(max_height);
+        let visible_rows = box_height - 2;
+        self.scroll_page.set(visible_rows);
+        let scroll = self.stats_scroll.min(content_rows.saturating_sub(visible_rows));
+
+        for (col, title, lines) in [(left_col, " Journey ", &left), (right_col, " Species ", &right)] {
+            ui::draw_box(renderer, col, box_row, box_width, box_height, Colors::white());
+            renderer.draw_at_grid(t(title), col + 2.0, box_row, Colors::yellow());
+            for (i, line) in lines.iter().skip(scroll).take(visible_rows).enumerate() {
+                renderer.draw_at_grid(
+                    &ui::truncate_to_width(line, box_width - 4),
+                    col + 2.0,
+                    box_row + 1.0 + i as f32,
+                    Colors::white(),
+                );
+            }
         }
 
-        // Status bar — 2 row gap
-        row += 2.0;
-        let day = self.player.current_day;
-        let fish_count = self.player.fish_collection.len();
-        let dates = self.player.dates_completed;
-        let ach_unlocked = AchievementTracker::unlocked_count(&self.player.achievements);
-        let ach_total = AchievementTracker::total_count();
+        let hint = if content_rows > visible_rows {
+            "[Arrow Keys] Scroll  [Enter/Esc] Back"
+        } else {
+            "[Enter/Esc] Back"
+        };
+        renderer.draw_centered(t(hint), footer_row, Colors::dark_gray());
+    }
+
+    fn render_journal(&self, renderer: &mut dyn DrawSurface) {
+        renderer.draw_centered(t("=== JOURNAL ==="), 1.0, Colors::cyan());
+        let catches = &self.player.fish_collection;
         renderer.draw_centered(
-            &format!(
-                "Day {} | Fish: {} | Dates: {} | Achievements: {}/{}",
-                day, fish_count, dates, ach_unlocked, ach_total
-            ),
-            row,
-            Colors::DARK_GRAY,
+            &tf("{} catches so far", &[&catches.len()]),
+            3.0,
+            Colors::gray(),
         );
 
-        // Controls hint — 3 row gap
+        let footer_row = renderer.screen_rows() - 2.0;
+        let room = (footer_row - 6.0).max(0.0) as usize;
+        self.scroll_page.set(room);
+        let col = centered_start_col(renderer.screen_cols(), LORE_WIDTH as f32);
+        if self.journal_scroll > 0 {
+            renderer.draw_centered(t("^ more ^"), 4.0, Colors::dark_gray());
+        }
+        let newest_first = catches.iter().rev().skip(self.journal_scroll);
+        for (i, fish) in newest_first.clone().take(room).enumerate() {
+            let row = 5.0 + i as f32;
+            let day = match fish.caught_on_day {
+                0 => t("Day ?").to_string(),
+                day => tf("Day {}", &[&day]),
+            };
+            let name = fish.id.name_with_registry(&self.registry);
+            let entry = tf(
+                "{} ({}) at {}",
+                &[&name, &t(fish.size.label()), &fish.caught_at],
+            );
+            renderer.draw_at_grid(&day, col, row, Colors::gray());
+            renderer.draw_at_grid(&entry, col + 9.0, row, fish.id.color_with_registry(&self.registry));
+            if fish.shiny {
+                let shiny_col = col + 10.0 + entry.chars().count() as f32;
+                let color = ui::rainbow_color(renderer, self.time);
+                renderer.draw_at_grid(t("*SHINY*"), shiny_col, row, color);
+            }
+        }
+        if newest_first.count() > room {
+            renderer.draw_centered(t("v more v"), footer_row - 1.0, Colors::dark_gray());
+        }
+
         renderer.draw_centered(
-            "[Arrow Keys] Navigate  [Enter] Select  [Esc] Quit",
-            row + 3.0,
-            [0.3, 0.3, 0.3, 0.5],
+            t("[Arrow Keys] Scroll  [Enter/Esc] Back"),
+            footer_row,
+            Colors::dark_gray(),
         );
     }
 
-    fn render_catch_result(&self, renderer: &mut GameRenderer, fish_id: &FishId, size: FishSize) {
-        renderer.draw_centered("=== CATCH! ===", 2.0, Colors::GREEN);
+    fn render_plugin_errors(&self, renderer: &mut dyn DrawSurface) {
+        renderer.draw_centered(t("=== PLUGIN ERRORS ==="), 1.0, Colors::red());
 
-        renderer.draw_multiline_centered(ascii_art::CATCH_SUCCESS, 4.0, Colors::YELLOW);
+        let errors = self.registry.errors();
+        renderer.draw_centered(
+            &tf("{} problem(s) found while loading plugins", &[&errors.len()]),
+            3.0,
+            Colors::gray(),
+        );
 
-        let art = fish_helpers::fish_art(fish_id, 0, &self.registry);
-        renderer.draw_multiline_centered(&art, 11.0, fish_id.color());
+        let width = (renderer.screen_cols() as usize).saturating_sub(8);
+        let footer_row = renderer.screen_rows() - 2.0;
+        // Three rows per error, stopping short of the footer
+        let room = ((footer_row - 7.0) / 3.0).ceil().max(0.0) as usize;
+        self.scroll_page.set(room);
+        for (i, error) in errors.iter().skip(self.plugin_errors_scroll).take(room).enumerate() {
+            let row = 5.0 + i as f32 * 3.0;
+            renderer.draw_at_grid(&error.file, 4.0, row, Colors::yellow());
+            renderer.draw_at_grid(
+                &ui::truncate_to_width(&error.message, width.saturating_sub(2)),
+                6.0,
+                row + 1.0,
+                Colors::white(),
+            );
+        }
 
-        let name = fish_id.name_with_registry(&self.registry);
-        let species = fish_id.species_with_registry(&self.registry);
         renderer.draw_centered(
-            &format!("You caught {} ({})!", name, species),
-            19.0,
-            Colors::WHITE,
+            t("[Arrow Keys] Scroll  [Enter/Esc] Back"),
+            footer_row,
+            Colors::dark_gray(),
         );
+    }
+
+    fn render_plugin_manager(&self, renderer: &mut dyn DrawSurface) {
+        renderer.draw_centered(t("=== PLUGINS ==="), 1.0, Colors::cyan());
         renderer.draw_centered(
-            &format!("Size: {}", size.label()),
-            20.0,
-            Colors::YELLOW,
+            t("Choose which plugin scripts load. Changes apply when you leave."),
+            3.0,
+            Colors::gray(),
         );
+
+        let footer_row = renderer.screen_rows() - 2.0;
+        match &self.plugin_menu {
+            Some(menu) if !menu.items.is_empty() => menu.draw_centered(renderer, 5.0),
+            _ => renderer.draw_centered(t("No plugin scripts found"), 5.0, Colors::dark_gray()),
+        }
+
         renderer.draw_centered(
-            &format!("Total {}: {}", name, self.player.catch_count(fish_id)),
-            21.0,
-            Colors::GRAY,
+            t("[Enter] Toggle  [Esc] Back"),
+            footer_row,
+            Colors::dark_gray(),
         );
-
-        renderer.draw_centered("[Enter] Continue", 24.0, Colors::DARK_GRAY);
     }
 
-    fn render_collection(&self, renderer: &mut GameRenderer) {
-        renderer.draw_centered("=== FISH COLLECTION ===", 1.0, Colors::CYAN);
+    fn render_replays(&self, renderer: &mut dyn DrawSurface) {
+        renderer.draw_centered(t("=== REPLAYS ==="), 1.0, Colors::pink());
+        renderer.draw_centered(t("Watch your best dates again."), 3.0, Colors::gray());
 
-        if self.player.fish_collection.is_empty() {
-            renderer.draw_centered("No fish caught yet! Go fishing!", 10.0, Colors::GRAY);
-            renderer.draw_centered("[Enter/Esc] Back", 12.0, Colors::DARK_GRAY);
-            return;
+        match &self.replay_menu {
+            Some(menu) if !menu.items.is_empty() => menu.draw_centered(renderer, 5.0),
+            _ => renderer.draw_centered(t("No replays saved yet"), 5.0, Colors::dark_gray()),
         }
 
-        let mut row = 3.0;
-        let all_fish = FishId::all_with_plugins(&self.registry);
-        for fish_id in &all_fish {
-            let count = self.player.catch_count(fish_id);
-            if count == 0 {
-                continue;
-            }
+        renderer.draw_centered(
+            t("[Enter] Watch  [Esc] Back"),
+            renderer.screen_rows() - 2.0,
+            Colors::dark_gray(),
+        );
+    }
 
-            let score = self.player.relationship(fish_id);
-            let label = relationship_label(score);
-            let name = fish_id.name_with_registry(&self.registry);
-            let species = fish_id.species_with_registry(&self.registry);
+    /// One plugin fish at a time, spoilers and all, so mod authors can
+    /// check their fish loaded.
+    fn render_plugin_gallery(&self, renderer: &mut dyn DrawSurface) {
+        renderer.draw_centered(t("=== FISH GALLERY ==="), 1.0, Colors::cyan());
+        let footer_row = renderer.screen_rows() - 2.0;
+        renderer.draw_centered(
+            t("[Up/Down] Browse  [Esc] Back"),
+            footer_row,
+            Colors::dark_gray(),
+        );
 
-            renderer.draw_centered(
-                &format!(
-                    "{} ({}) - Caught: {} - {}: {}",
-                    name,
-                    species,
-                    count,
-                    label,
-                    score,
-                ),
-                row,
-                fish_id.color(),
-            );
+        let fish = self.registry.all_fish();
+        let Some(index) = self.gallery_menu.as_ref().map(|m| m.selected_index()) else {
+            return;
+        };
+        let Some(def) = fish.get(index) else {
+            renderer.draw_centered(t("No plugin fish installed"), 5.0, Colors::dark_gray());
+            return;
+        };
 
-            // Mini hearts
-            let cols = renderer.screen_cols() as usize;
-            ui::draw_hearts(renderer, (cols / 2 - 8) as f32, row + 1.0, score, 5);
+        renderer.draw_centered(
+            &tf("Fish {} of {}", &[&(index + 1), &fish.len()]),
+            2.0,
+            Colors::gray(),
+        );
+        renderer.draw_centered(&def.name, 4.0, def.color);
+        renderer.draw_centered(&def.species, 5.0, Colors::gray());
 
-            row += 3.0;
-        }
+        let frame = ui::anim_frame(renderer, self.time * ascii_art::IDLE_FRAME_RATE);
+        let art = def.animated_art(frame, 0);
+        renderer.draw_multiline_centered(art, 7.0, def.color);
+        let mut row = 8.0 + art.lines().count() as f32;
 
-        renderer.draw_centered("[Enter/Esc] Back", row + 2.0, Colors::DARK_GRAY);
+        let col = centered_start_col(renderer.screen_cols(), LORE_WIDTH as f32);
+        renderer.draw_at_grid(t("Difficulty:"), col, row, Colors::white());
+        ui::draw_difficulty_stars(renderer, col + 15.0, row, def.difficulty, DIFFICULTY_STARS);
+        row += 1.0;
+
+        renderer.draw_at_grid(t("Pond:"), col, row, Colors::white());
+        renderer.draw_at_grid(&def.pond_name, col + 15.0, row, Colors::light_blue());
+        row += 2.0;
+
+        let room = (footer_row - 1.0 - row).max(0.0) as usize;
+        let lines: Vec<String> = markup::word_wrap(&def.description, LORE_WIDTH).into_iter().take(room).collect();
+        markup::draw_lines(renderer, &lines, col, row, Colors::white());
     }
 
-    fn render_date_select(&self, renderer: &mut GameRenderer) {
-        renderer.draw_centered("=== CHOOSE A DATE ===", 1.0, Colors::PINK);
+    fn render_date_select(&self, renderer: &mut dyn DrawSurface) {
+        renderer.draw_centered(t("=== CHOOSE A DATE ==="), 1.0, Colors::pink());
         renderer.draw_centered(
-            "Select a fish to take on a date:",
+            t("Select a fish to take on a date:"),
             3.0,
-            Colors::WHITE,
+            Colors::white(),
         );
 
         if let Some(ref menu) = self.date_select_menu {
@@ -555,84 +3005,393 @@ impl Game {
                 .collect();
             if let Some(fish_id) = dateable.get(menu.selected_index()) {
                 let score = self.player.relationship(fish_id);
-                let art = fish_helpers::fish_art(fish_id, score, &self.registry);
-                renderer.draw_multiline_centered(&art, 10.0, fish_id.color());
+                let art = fish_helpers::fish_art(fish_id, score, self.time, &self.registry);
+                renderer.draw_multiline_centered(&art, 10.0, fish_id.color_with_registry(&self.registry));
 
                 let loc = fish_helpers::date_location(fish_id, &self.registry);
                 renderer.draw_centered(
-                    &format!("Date location: {}", loc),
+                    &tf("Date location: {}", &[&loc]),
                     18.0,
-                    Colors::LIGHT_BLUE,
+                    Colors::light_blue(),
+                );
+
+                let chemistry = self.player.chemistry(fish_id);
+                let color = match chemistry {
+                    Chemistry::High => Colors::green(),
+                    Chemistry::Medium => Colors::yellow(),
+                    Chemistry::Low => Colors::orange(),
+                    Chemistry::Unknown => Colors::dark_gray(),
+                };
+                renderer.draw_centered(
+                    &tf("Chemistry: {}", &[&t(chemistry.label())]),
+                    19.0,
+                    color,
                 );
             }
         }
 
-        renderer.draw_centered("[Enter] Go on date  [Esc] Back", 20.0, Colors::DARK_GRAY);
+        renderer.draw_centered(
+            t("[Enter] Go on date  [Q] Quick date  [Esc] Back"),
+            20.0,
+            Colors::dark_gray(),
+        );
     }
 
-    fn render_date_result(&self, renderer: &mut GameRenderer, fish_id: &FishId, affection: i32) {
-        renderer.draw_centered("=== DATE COMPLETE ===", 2.0, Colors::PINK);
+    fn render_quick_date(&self, renderer: &mut dyn DrawSurface, fish_id: &FishId) {
+        renderer.draw_centered(t("=== QUICK DATE ==="), 1.0, Colors::pink());
 
-        let art = fish_helpers::fish_art(fish_id, self.player.relationship(fish_id), &self.registry);
-        renderer.draw_multiline_centered(&art, 5.0, fish_id.color());
+        let name = fish_id.name_with_registry(&self.registry);
+        let score = self.player.relationship(fish_id);
+        let art = fish_helpers::fish_art(fish_id, score, self.time, &self.registry);
+        renderer.draw_multiline_centered(&art, 3.0, fish_id.color_with_registry(&self.registry));
+
+        match &self.quick_date_outcome {
+            None => {
+                renderer.draw_centered(
+                    &tf("You grab a quick bite with {}. How do you play it?", &[&name]),
+                    12.0,
+                    Colors::white(),
+                );
+                if let Some(ref menu) = self.quick_date_menu {
+                    menu.draw_centered(renderer, 14.0);
+                }
+                renderer.draw_centered(t("[Enter] Choose  [Esc] Back"), 20.0, Colors::dark_gray());
+            }
+            Some((reaction, affection)) => {
+                renderer.draw_centered(&tf(reaction, &[&name]), 12.0, Colors::white());
+                let color = if *affection > 0 { Colors::green() } else { Colors::gray() };
+                renderer.draw_centered(&tf("Affection gained: +{}", &[affection]), 14.0, color);
+                let label = t(relationship_label(score));
+                renderer.draw_centered(
+                    &tf("Relationship: {} ({})", &[&label, &score]),
+                    15.0,
+                    Colors::pink(),
+                );
+                renderer.draw_centered(t("[Enter] Continue"), 20.0, Colors::dark_gray());
+            }
+        }
+    }
+
+    fn render_offer_select(&self, renderer: &mut dyn DrawSurface, fish_id: &FishId) {
+        renderer.draw_centered(t("=== BRING A FRIEND? ==="), 1.0, Colors::pink());
+
+        let name = fish_id.name_with_registry(&self.registry);
+        renderer.draw_centered(
+            &tf("Offer {} a spare fish from your collection:", &[&name]),
+            3.0,
+            Colors::white(),
+        );
+        renderer.draw_centered(
+            t("Your last catch of each kind always stays."),
+            4.0,
+            Colors::gray(),
+        );
+
+        if let Some(ref menu) = self.offer_menu {
+            menu.draw_centered(renderer, 6.0);
+        }
+
+        renderer.draw_centered(t("[Enter] Offer  [Esc] Back"), 20.0, Colors::dark_gray());
+    }
+
+    fn render_gift_select(&self, renderer: &mut dyn DrawSurface, fish_id: &FishId) {
+        renderer.draw_centered(t("=== BRING A GIFT? ==="), 1.0, Colors::pink());
 
-        let total = self.player.relationship(fish_id);
-        let label = relationship_label(total);
         let name = fish_id.name_with_registry(&self.registry);
+        renderer.draw_centered(
+            &tf("Pick something to give {} before your date:", &[&name]),
+            3.0,
+            Colors::white(),
+        );
+
+        if let Some(ref menu) = self.gift_menu {
+            menu.draw_centered(renderer, 5.0);
+            if menu.items.len() == 1 {
+                renderer.draw_centered(
+                    t("Catch more fish to find gifts!"),
+                    7.0,
+                    Colors::gray(),
+                );
+            }
+        }
 
+        let score = self.player.relationship(fish_id);
+        let art = fish_helpers::fish_art(fish_id, score, self.time, &self.registry);
+        renderer.draw_multiline_centered(&art, 10.0, fish_id.color_with_registry(&self.registry));
+
+        renderer.draw_centered(t("[Enter] Give gift  [Esc] Back"), 20.0, Colors::dark_gray());
+    }
+
+    fn render_no_dates(&self, renderer: &mut dyn DrawSurface) {
+        renderer.draw_centered(t("=== GO ON A DATE ==="), 2.0, Colors::pink());
         renderer.draw_centered(
-            &format!("Date with {} finished!", name),
-            13.0,
-            Colors::WHITE,
+            t("You haven't caught anyone to date yet - go fishing!"),
+            10.0,
+            Colors::white(),
         );
+        renderer.draw_centered(t("[Enter] Back"), 12.0, Colors::dark_gray());
+    }
+
+    fn render_welcome_back(&self, renderer: &mut dyn DrawSurface, welcome: &DailyWelcome) {
+        renderer.draw_centered(t("=== WELCOME BACK! ==="), 2.0, Colors::pink());
+
+        let streak = if welcome.streak == 1 {
+            t("Daily streak: 1 day").to_string()
+        } else {
+            tf("Daily streak: {} days in a row", &[&welcome.streak])
+        };
+        renderer.draw_centered(&streak, 4.0, Colors::yellow());
+
+        match &welcome.bonus_fish {
+            Some(fish_id) => {
+                let total = self.player.relationship(fish_id);
+                let art = fish_helpers::fish_art(fish_id, total, self.time, &self.registry);
+                renderer.draw_multiline_centered(&art, 6.0, fish_id.color_with_registry(&self.registry));
+                let name = fish_id.name_with_registry(&self.registry);
+                renderer.draw_centered(
+                    &tf("{} missed you! (+{} affection)", &[&name, &DAILY_LOGIN_BONUS]),
+                    14.0,
+                    Colors::white(),
+                );
+            }
+            None => {
+                renderer.draw_centered(t("The fish are biting today."), 14.0, Colors::white());
+            }
+        }
+
+        renderer.draw_centered(t("[Enter] Continue"), 17.0, Colors::dark_gray());
+    }
+
+    fn render_date_result(
+        &self,
+        renderer: &mut dyn DrawSurface,
+        fish_id: &FishId,
+        affection: i32,
+        gift_bonus: bool,
+        ended_early: bool,
+        offering: Option<&(FishId, i32)>,
+    ) {
+        renderer.draw_centered(t("=== DATE COMPLETE ==="), 2.0, Colors::pink());
+
+        let total = self.player.relationship(fish_id);
+        let art = fish_helpers::fish_art(fish_id, total, self.time, &self.registry);
+        renderer.draw_multiline_centered(&art, 5.0, fish_id.color_with_registry(&self.registry));
+
+        let label = t(relationship_label(total));
+        let name = fish_id.name_with_registry(&self.registry);
+
+        let headline = if ended_early { "{} stormed off!" } else { "Date with {} finished!" };
+        renderer.draw_centered(&tf(headline, &[&name]), 13.0, Colors::white());
         renderer.draw_centered(
-            &format!("Affection gained: +{}", affection),
+            &affection_change_line(affection),
             14.0,
             if affection > 5 {
-                Colors::GREEN
+                Colors::green()
             } else if affection > 2 {
-                Colors::YELLOW
+                Colors::yellow()
             } else {
-                Colors::RED
+                Colors::red()
             },
         );
         renderer.draw_centered(
-            &format!("Relationship: {} ({})", label, total),
+            &tf("Relationship: {} ({})", &[&label, &total]),
             15.0,
-            Colors::PINK,
+            Colors::pink(),
         );
+        if gift_bonus {
+            renderer.draw_centered(
+                &tf("{} loved the gift! (x{} affection)", &[&name, &FAVORITE_GIFT_MULTIPLIER]),
+                16.0,
+                Colors::yellow(),
+            );
+        }
 
-        let cols = renderer.screen_cols() as usize;
-        ui::draw_hearts(renderer, (cols / 2 - 8) as f32, 17.0, total, 5);
+        ui::draw_hearts(renderer, centered_start_col(renderer.screen_cols(), 16.0), 17.0, total, 5);
+
+        if let Some((offered, bonus)) = offering {
+            let offered_name = offered.name_with_registry(&self.registry);
+            renderer.draw_centered(
+                &tf("{} enjoyed the {} you brought! (+{} affection)", &[&name, &offered_name, bonus]),
+                18.0,
+                Colors::cyan(),
+            );
+        }
+
+        renderer.draw_centered(t("[Enter] Continue"), 19.0, Colors::dark_gray());
+    }
+
+    fn render_group_date_select(&self, renderer: &mut dyn DrawSurface, first: Option<&FishId>) {
+        renderer.draw_centered(t("=== GROUP DATE ==="), 1.0, Colors::pink());
+        let prompt = match first {
+            None => t("Pick the first fish:").to_string(),
+            Some(first) => tf("Who should join {}?", &[&first.name_with_registry(&self.registry)]),
+        };
+        renderer.draw_centered(&prompt, 3.0, Colors::white());
+        renderer.draw_centered(
+            t("Affection from the date is shared between both fish."),
+            4.0,
+            Colors::gray(),
+        );
+
+        if let Some(ref menu) = self.group_menu {
+            menu.draw_centered(renderer, 6.0);
+        }
 
-        renderer.draw_centered("[Enter] Continue", 19.0, Colors::DARK_GRAY);
+        renderer.draw_centered(t("[Enter] Pick  [Esc] Back"), 20.0, Colors::dark_gray());
     }
 
-    fn render_game_over(&self, renderer: &mut GameRenderer) {
-        renderer.draw_centered("=== CONGRATULATIONS! ===", 3.0, Colors::YELLOW);
+    fn render_group_date_result(
+        &self,
+        renderer: &mut dyn DrawSurface,
+        fish: &[FishId; 2],
+        affection: i32,
+        ended_early: bool,
+    ) {
+        renderer.draw_centered(t("=== GROUP DATE COMPLETE ==="), 2.0, Colors::pink());
 
-        if let Some((fish_id, score)) = self.player.closest_fish() {
-            let art = fish_helpers::fish_art(&fish_id, score, &self.registry);
-            renderer.draw_multiline_centered(&art, 6.0, fish_id.color());
+        let [first, second] = fish;
+        let first_name = first.name_with_registry(&self.registry);
+        let second_name = second.name_with_registry(&self.registry);
+        let headline = if ended_early {
+            "The date fell apart early..."
+        } else {
+            "Group date with {} and {} finished!"
+        };
+        renderer.draw_centered(&tf(headline, &[&first_name, &second_name]), 5.0, Colors::white());
 
+        for (i, fish_id) in fish.iter().enumerate() {
+            let row = 7.0 + i as f32 * 3.0;
+            let total = self.player.relationship(fish_id);
             let name = fish_id.name_with_registry(&self.registry);
             renderer.draw_centered(
-                &format!("You and {} are soulmates!", name),
-                14.0,
-                Colors::PINK,
+                &format!("{}: {}", name, affection_change_line(affection)),
+                row,
+                fish_id.color_with_registry(&self.registry),
             );
             renderer.draw_centered(
-                &format!("Final affection: {}", score),
-                15.0,
-                Colors::WHITE,
+                &tf("Relationship: {} ({})", &[&t(relationship_label(total)), &total]),
+                row + 1.0,
+                Colors::pink(),
             );
         }
 
+        renderer.draw_centered(t("[Enter] Continue"), 14.0, Colors::dark_gray());
+    }
+
+    fn render_confirm_quit(&self, renderer: &mut dyn DrawSurface) {
+        let box_row = 16.0;
+        ui::draw_centered_box(renderer, box_row, 44, 7, Colors::white());
+        renderer.draw_centered(t("Leaving so soon?"), box_row + 2.0, Colors::pink());
+        renderer.draw_centered(t("Save and quit?"), box_row + 3.0, Colors::white());
+        renderer.draw_centered(
+            t("[Y]es  [N]o  [C]ancel"),
+            box_row + 4.0,
+            Colors::yellow(),
+        );
+    }
+
+    fn render_confirm_new_game(&self, renderer: &mut dyn DrawSurface) {
+        let box_row = 16.0;
+        ui::draw_centered_box(renderer, box_row, 44, 7, Colors::white());
+        renderer.draw_centered(t("Start a new game?"), box_row + 2.0, Colors::pink());
+        renderer.draw_centered(
+            t("This erases your current progress."),
+            box_row + 3.0,
+            Colors::white(),
+        );
+        renderer.draw_centered(t("Continue? [Y]es  [N]o"), box_row + 4.0, Colors::yellow());
+    }
+
+    fn render_confirm_resume_date(&self, renderer: &mut dyn DrawSurface, resume: &save::DateResume) {
+        let box_row = 16.0;
+        ui::draw_centered_box(renderer, box_row, 44, 7, Colors::white());
+        renderer.draw_centered(t("Resume interrupted date?"), box_row + 2.0, Colors::pink());
+        renderer.draw_centered(
+            &tf(
+                "Your date with {} was cut short.",
+                &[&resume.fish_id.name_with_registry(&self.registry)],
+            ),
+            box_row + 3.0,
+            Colors::white(),
+        );
+        renderer.draw_centered(t("Continue? [Y]es  [N]o"), box_row + 4.0, Colors::yellow());
+    }
+
+    fn render_confirm_release_smalls(&self, renderer: &mut dyn DrawSurface) {
+        let box_row = 16.0;
+        ui::draw_centered_box(renderer, box_row, 44, 7, Colors::white());
+        renderer.draw_centered(
+            &tf("Release {} Small fish?", &[&self.player.releasable_small_count()]),
+            box_row + 2.0,
+            Colors::pink(),
+        );
+        renderer.draw_centered(
+            t("One of each species stays."),
+            box_row + 3.0,
+            Colors::white(),
+        );
+        renderer.draw_centered(t("Continue? [Y]es  [N]o"), box_row + 4.0, Colors::yellow());
+    }
+
+    fn render_game_over(&self, renderer: &mut dyn DrawSurface) {
+        renderer.draw_centered(t("=== CONGRATULATIONS! ==="), 3.0, Colors::yellow());
+
+        let soulmates = self.player.soulmates();
+        match soulmates.as_slice() {
+            [] => {}
+            [(fish_id, score)] => {
+                let art = fish_helpers::fish_art(fish_id, *score, self.time, &self.registry);
+                renderer.draw_multiline_centered(&art, 6.0, fish_id.color_with_registry(&self.registry));
+
+                let name = fish_id.name_with_registry(&self.registry);
+                renderer.draw_centered(
+                    &tf("You and {} are soulmates!", &[&name]),
+                    14.0,
+                    Colors::pink(),
+                );
+                renderer.draw_centered(
+                    &tf("Final affection: {}", &[&score]),
+                    15.0,
+                    Colors::white(),
+                );
+            }
+            many => {
+                // Split the screen into equal slots and center each fish in its own
+                let cols = renderer.screen_cols();
+                let slot_width = cols / many.len() as f32;
+                for (i, (fish_id, score)) in many.iter().enumerate() {
+                    let slot_start = i as f32 * slot_width;
+
+                    let art = fish_helpers::fish_art(fish_id, *score, self.time, &self.registry);
+                    let art_width = art.lines().map(|l| l.chars().count()).max().unwrap_or(0) as f32;
+                    let art_col = slot_start + ((slot_width - art_width) / 2.0).max(0.0);
+                    renderer.draw_multiline_at_grid(&art, art_col, 5.0, fish_id.color_with_registry(&self.registry));
+
+                    let max_len = (slot_width as usize).saturating_sub(1);
+                    let name = fish_id.name_with_registry(&self.registry);
+                    let name = ui::truncate_to_width(&name, max_len);
+                    let score_text = ui::truncate_to_width(&format!("<3 {}", score), max_len);
+                    for (row, text, color) in [
+                        (14.0, &name, Colors::pink()),
+                        (15.0, &score_text, Colors::white()),
+                    ] {
+                        let col = slot_start + ((slot_width - text.chars().count() as f32) / 2.0).max(0.0);
+                        renderer.draw_at_grid(text, col, row, color);
+                    }
+                }
+                renderer.draw_centered(
+                    &tf("You settled down with {} soulmates!", &[&many.len()]),
+                    16.0,
+                    Colors::yellow(),
+                );
+            }
+        }
+
         renderer.draw_centered(
-            "Thank you for playing cult_papa Fish Dating Simulator!",
+            t("Thank you for playing cult_papa Fish Dating Simulator!"),
             18.0,
-            Colors::CYAN,
+            Colors::cyan(),
         );
-        renderer.draw_centered("[Enter] New Game", 20.0, Colors::DARK_GRAY);
+        renderer.draw_centered(t("[Enter] New Game"), 20.0, Colors::dark_gray());
     }
 }
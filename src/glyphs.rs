@@ -0,0 +1,188 @@
+//! ASCII stand-ins for characters the builtin font can't draw.
+//!
+//! The window renderer's 8x8 font only covers ASCII, so box-drawing lines,
+//! curly quotes or emoji from plugins and locale files would show up as
+//! holes. [`sanitize`] swaps each of them for a look-alike before drawing.
+//! A `glyphs.json` file mapping characters to replacement text adds to or
+//! overrides the built-in table.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use crate::data::save;
+
+/// The active substitution table, set once at startup.
+static GLYPHS: OnceLock<Glyphs> = OnceLock::new();
+
+/// Name of the substitution file, looked for in the working directory and
+/// then the data directory.
+const GLYPHS_FILE: &str = "glyphs.json";
+
+/// Drawn for any character without a substitution.
+const FALLBACK: &str = "?";
+
+/// Built-in substitutions: every character in the first string becomes the
+/// second. Most keep to one cell so boxes and columns stay lined up.
+const DEFAULT_SUBSTITUTIONS: &[(&str, &str)] = &[
+    ("─━═╌╍┄┅┈┉", "-"),
+    ("│┃║╎╏┆┇┊┋", "|"),
+    ("┌┐└┘├┤┬┴┼┏┓┗┛┣┫┳┻╋╔╗╚╝╠╣╦╩╬╭╮╯╰", "+"),
+    ("╱", "/"),
+    ("╲", "\\"),
+    ("░", "."),
+    ("▒", ":"),
+    ("▓█▀▄▌▐■", "#"),
+    ("•·●○◦", "*"),
+    ("★✦✧", "*"),
+    ("☆", "."),
+    ("♥♡❤", "<3"),
+    ("‘’‚′", "'"),
+    ("“”„″", "\""),
+    ("–—―−", "-"),
+    ("…", "..."),
+    ("→", "->"),
+    ("←", "<-"),
+    ("↑▲", "^"),
+    ("↓▼", "v"),
+    ("×", "x"),
+    ("÷", "/"),
+    ("°", "o"),
+    ("~～", "~"),
+    ("àáâãäåā", "a"),
+    ("ÀÁÂÃÄÅĀ", "A"),
+    ("ç", "c"),
+    ("Ç", "C"),
+    ("èéêëē", "e"),
+    ("ÈÉÊËĒ", "E"),
+    ("ìíîïī", "i"),
+    ("ÌÍÎÏĪ", "I"),
+    ("ñ", "n"),
+    ("Ñ", "N"),
+    ("òóôõöøō", "o"),
+    ("ÒÓÔÕÖØŌ", "O"),
+    ("ùúûüū", "u"),
+    ("ÙÚÛÜŪ", "U"),
+    ("ýÿ", "y"),
+    ("Ý", "Y"),
+    ("ß", "ss"),
+    ("¡", "!"),
+    ("¿", "?"),
+    ("\u{a0}", " "),
+];
+
+/// Replacement text for characters the font lacks.
+#[derive(Debug, Clone)]
+pub struct Glyphs {
+    substitutions: HashMap<char, String>,
+}
+
+impl Default for Glyphs {
+    fn default() -> Self {
+        let substitutions = DEFAULT_SUBSTITUTIONS
+            .iter()
+            .flat_map(|(chars, replacement)| chars.chars().map(|c| (c, replacement.to_string())))
+            .collect();
+        Self { substitutions }
+    }
+}
+
+impl Glyphs {
+    /// Load `glyphs.json` over the built-in table. Keys that aren't a single
+    /// character and replacements that aren't plain ASCII are logged and
+    /// skipped.
+    pub fn load() -> Result<Self, String> {
+        let path = [PathBuf::from(GLYPHS_FILE), save::data_dir().join(GLYPHS_FILE)]
+            .into_iter()
+            .find(|path| path.exists())
+            .ok_or_else(|| format!("{} not found", GLYPHS_FILE))?;
+
+        let json = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+        let entries: HashMap<String, String> =
+            serde_json::from_str(&json).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        let mut glyphs = Self::default();
+        let mut applied = 0;
+        for (key, replacement) in entries {
+            let mut chars = key.chars();
+            let (Some(c), None) = (chars.next(), chars.next()) else {
+                tracing::warn!("Glyph key '{}' in {} should be one character", key, path.display());
+                continue;
+            };
+            if !replacement.is_ascii() {
+                tracing::warn!(
+                    "Replacement for '{}' in {} should be plain ASCII, keeping the default",
+                    key,
+                    path.display()
+                );
+                continue;
+            }
+            glyphs.substitutions.insert(c, replacement);
+            applied += 1;
+        }
+        tracing::info!("Loaded {} glyph substitutions from {}", applied, path.display());
+        Ok(glyphs)
+    }
+
+    /// `text` with every non-ASCII character replaced. Plain ASCII text
+    /// comes back as is, without copying.
+    pub fn sanitize<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if text.is_ascii() {
+            return Cow::Borrowed(text);
+        }
+        let mut out = String::with_capacity(text.len());
+        for c in text.chars() {
+            if c.is_ascii() {
+                out.push(c);
+            } else {
+                out.push_str(self.substitutions.get(&c).map_or(FALLBACK, String::as_str));
+            }
+        }
+        Cow::Owned(out)
+    }
+}
+
+/// Load the substitution file if there is one. Stays on the built-in table
+/// otherwise.
+pub fn init() {
+    let glyphs = Glyphs::load().unwrap_or_else(|e| {
+        tracing::info!("Using the built-in glyph substitutions ({})", e);
+        Glyphs::default()
+    });
+    if GLYPHS.set(glyphs).is_err() {
+        tracing::warn!("Glyph substitutions already initialized");
+    }
+}
+
+/// The active substitution table, or the built-in one before `init`.
+pub fn current() -> &'static Glyphs {
+    GLYPHS.get_or_init(Glyphs::default)
+}
+
+/// `text` with the characters the builtin font can't draw swapped for
+/// ASCII look-alikes.
+pub fn sanitize(text: &str) -> Cow<'_, str> {
+    current().sanitize(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn box_drawing_becomes_ascii() {
+        let glyphs = Glyphs::default();
+        let boxed = glyphs.sanitize("┌─┐│└┘");
+        assert!(boxed.is_ascii());
+        assert_eq!(boxed, "+-+|++");
+    }
+
+    #[test]
+    fn ascii_passes_through_and_unknowns_fall_back() {
+        let glyphs = Glyphs::default();
+        assert!(matches!(glyphs.sanitize("plain ><>"), Cow::Borrowed("plain ><>")));
+        assert_eq!(glyphs.sanitize("fish \u{1f41f}"), "fish ?");
+        assert_eq!(glyphs.sanitize("caf\u{e9} \u{2014} d\u{e9}j\u{e0}"), "cafe - deja");
+    }
+}
@@ -0,0 +1,99 @@
+//! Translations for UI text and dialogue.
+//!
+//! English is built in: every string is its own key, so [`t`] hands back the
+//! English text unless the loaded locale has a translation for it. A locale is
+//! a `locale_<lang>.json` file mapping English strings to translated ones.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use crate::data::save;
+
+/// The active locale, set once at startup.
+static LOCALE: OnceLock<Locale> = OnceLock::new();
+
+/// Translations for one language.
+#[derive(Debug, Default)]
+pub struct Locale {
+    /// English text to translated text.
+    strings: HashMap<String, String>,
+}
+
+impl Locale {
+    /// Load `locale_<language>.json`, looking in the working directory and
+    /// then the data directory.
+    pub fn load(language: &str) -> Result<Self, String> {
+        let file_name = format!("locale_{}.json", language);
+        let path = [PathBuf::from(&file_name), save::data_dir().join(&file_name)]
+            .into_iter()
+            .find(|path| path.exists())
+            .ok_or_else(|| format!("{} not found", file_name))?;
+
+        let json = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+        let strings: HashMap<String, String> =
+            serde_json::from_str(&json).map_err(|e| format!("{}: {}", path.display(), e))?;
+        tracing::info!(
+            "Loaded {} translations for '{}' from {}",
+            strings.len(),
+            language,
+            path.display()
+        );
+        Ok(Self { strings })
+    }
+
+    /// The translation of `key`, or `key` itself if there isn't one.
+    pub fn get<'a>(&'a self, key: &'a str) -> &'a str {
+        self.strings.get(key).map(String::as_str).unwrap_or(key)
+    }
+}
+
+/// Pick the language from `preferred` or, failing that, `LANG`, and load its
+/// locale. Stays on English if there is no locale file for it.
+pub fn init(preferred: Option<&str>) {
+    let language = preferred
+        .map(str::to_string)
+        .or_else(|| std::env::var("LANG").ok())
+        .map(|lang| lang.chars().take_while(char::is_ascii_alphabetic).collect::<String>())
+        .unwrap_or_default()
+        .to_lowercase();
+
+    let locale = if language.is_empty() || language == "en" {
+        Locale::default()
+    } else {
+        Locale::load(&language).unwrap_or_else(|e| {
+            tracing::info!("No translations for '{}' ({}), using English", language, e);
+            Locale::default()
+        })
+    };
+    if LOCALE.set(locale).is_err() {
+        tracing::warn!("Locale already initialized");
+    }
+}
+
+/// Translate `key`, falling back to the English text.
+pub fn t(key: &str) -> &str {
+    match LOCALE.get() {
+        Some(locale) => locale.get(key),
+        None => key,
+    }
+}
+
+/// Translate a template and fill each `{}` in it with the next argument.
+pub fn tf(key: &str, args: &[&dyn Display]) -> String {
+    let mut out = String::new();
+    let mut args = args.iter();
+    let mut parts = t(key).split("{}");
+    if let Some(first) = parts.next() {
+        out.push_str(first);
+    }
+    for part in parts {
+        match args.next() {
+            Some(arg) => out.push_str(&arg.to_string()),
+            None => out.push_str("{}"),
+        }
+        out.push_str(part);
+    }
+    out
+}
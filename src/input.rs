@@ -0,0 +1,304 @@
+//! Keyboard and mouse input state and rebindable key bindings.
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use winit::keyboard::KeyCode;
+
+use crate::data::save;
+
+/// Keys that keep firing while held, so long menus can be scrolled.
+/// Letters stay out so held keys can't spell secret words or retype names,
+/// and Enter/Space stay out so a held confirm doesn't pick again and again.
+const REPEATING_KEYS: [KeyCode; 4] = [
+    KeyCode::ArrowUp,
+    KeyCode::ArrowDown,
+    KeyCode::ArrowLeft,
+    KeyCode::ArrowRight,
+];
+
+/// Keyboard and mouse state for a single frame.
+///
+/// `just_pressed` carries the discrete key press that menus react to, while
+/// `held` tracks every key that is physically down so continuous actions
+/// (like pulling the line in the minigame) can apply every frame.
+#[derive(Debug, Default)]
+pub struct InputState {
+    /// Keys currently held down.
+    held: HashSet<KeyCode>,
+    /// Key pressed since the last frame, if any.
+    just_pressed: Option<KeyCode>,
+    /// Mouse cursor position in grid cells (column, row), if over the window.
+    cursor: Option<(f32, f32)>,
+    /// Whether the cursor moved since the last frame.
+    cursor_moved: bool,
+    /// Whether the left mouse button was clicked since the last frame.
+    clicked: bool,
+    /// Character typed since the last frame, for text entry.
+    typed: Option<char>,
+}
+
+impl InputState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a key press from the window event loop.
+    pub fn press(&mut self, key: KeyCode) {
+        self.held.insert(key);
+        self.just_pressed = Some(key);
+    }
+
+    /// Record an auto-repeat from a held key. Only navigation keys count as
+    /// a new press; other repeats are ignored.
+    pub fn repeat(&mut self, key: KeyCode) {
+        if REPEATING_KEYS.contains(&key) {
+            self.just_pressed = Some(key);
+        }
+    }
+
+    /// Record a typed character, separately from the key that produced it.
+    pub fn type_char(&mut self, c: char) {
+        self.typed = Some(c);
+    }
+
+    /// Record a key release from the window event loop.
+    pub fn release(&mut self, key: KeyCode) {
+        self.held.remove(&key);
+    }
+
+    /// Forget all held keys (e.g. when the window loses focus and we
+    /// will never see the matching release events).
+    pub fn clear_held(&mut self) {
+        self.held.clear();
+    }
+
+    /// Record the cursor position in grid cells.
+    pub fn move_cursor(&mut self, col: f32, row: f32) {
+        self.cursor = Some((col, row));
+        self.cursor_moved = true;
+    }
+
+    /// Forget the cursor once it leaves the window.
+    pub fn clear_cursor(&mut self) {
+        self.cursor = None;
+    }
+
+    /// Record a left mouse click.
+    pub fn click(&mut self) {
+        self.clicked = true;
+    }
+
+    /// Cursor position in grid cells (column, row).
+    pub fn cursor(&self) -> Option<(f32, f32)> {
+        self.cursor
+    }
+
+    /// Whether the cursor moved this frame.
+    pub fn cursor_moved(&self) -> bool {
+        self.cursor_moved
+    }
+
+    /// Whether the left mouse button was clicked this frame.
+    pub fn clicked(&self) -> bool {
+        self.clicked
+    }
+
+    /// The key pressed this frame, for menu-style navigation.
+    pub fn just_pressed(&self) -> Option<KeyCode> {
+        self.just_pressed
+    }
+
+    /// The character typed this frame, for text entry.
+    pub fn typed(&self) -> Option<char> {
+        self.typed
+    }
+
+    /// Whether a key is currently held down.
+    pub fn is_held(&self, key: KeyCode) -> bool {
+        self.held.contains(&key)
+    }
+
+    /// Whether any of the given keys is currently held down.
+    pub fn any_held(&self, keys: &[KeyCode]) -> bool {
+        keys.iter().any(|&k| self.is_held(k))
+    }
+
+    /// Clear per-frame state. Call once the game has processed the frame.
+    pub fn end_frame(&mut self) {
+        self.just_pressed = None;
+        self.cursor_moved = false;
+        self.clicked = false;
+        self.typed = None;
+    }
+}
+
+/// The key that types `c`, ignoring case.
+pub fn key_for_char(c: char) -> Option<KeyCode> {
+    let key = match c.to_ascii_lowercase() {
+        'a' => KeyCode::KeyA,
+        'b' => KeyCode::KeyB,
+        'c' => KeyCode::KeyC,
+        'd' => KeyCode::KeyD,
+        'e' => KeyCode::KeyE,
+        'f' => KeyCode::KeyF,
+        'g' => KeyCode::KeyG,
+        'h' => KeyCode::KeyH,
+        'i' => KeyCode::KeyI,
+        'j' => KeyCode::KeyJ,
+        'k' => KeyCode::KeyK,
+        'l' => KeyCode::KeyL,
+        'm' => KeyCode::KeyM,
+        'n' => KeyCode::KeyN,
+        'o' => KeyCode::KeyO,
+        'p' => KeyCode::KeyP,
+        'q' => KeyCode::KeyQ,
+        'r' => KeyCode::KeyR,
+        's' => KeyCode::KeyS,
+        't' => KeyCode::KeyT,
+        'u' => KeyCode::KeyU,
+        'v' => KeyCode::KeyV,
+        'w' => KeyCode::KeyW,
+        'x' => KeyCode::KeyX,
+        'y' => KeyCode::KeyY,
+        'z' => KeyCode::KeyZ,
+        '0' => KeyCode::Digit0,
+        '1' => KeyCode::Digit1,
+        '2' => KeyCode::Digit2,
+        '3' => KeyCode::Digit3,
+        '4' => KeyCode::Digit4,
+        '5' => KeyCode::Digit5,
+        '6' => KeyCode::Digit6,
+        '7' => KeyCode::Digit7,
+        '8' => KeyCode::Digit8,
+        '9' => KeyCode::Digit9,
+        ' ' => KeyCode::Space,
+        '-' | '_' => KeyCode::Minus,
+        '=' | '+' => KeyCode::Equal,
+        '[' | '{' => KeyCode::BracketLeft,
+        ']' | '}' => KeyCode::BracketRight,
+        _ => return None,
+    };
+    Some(key)
+}
+
+// ── Key Bindings ─────────────────────────────────────────────────────────────
+
+/// A rebindable game action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    MenuUp,
+    MenuDown,
+    Confirm,
+    Back,
+    ReelLeft,
+    ReelRight,
+}
+
+/// Keys bound to each action, loaded from `keybindings.json` next to the save.
+///
+/// Missing fields fall back to the default keys, so the file only needs to
+/// list the actions being remapped.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeyBindings {
+    pub menu_up: Vec<KeyCode>,
+    pub menu_down: Vec<KeyCode>,
+    pub confirm: Vec<KeyCode>,
+    pub back: Vec<KeyCode>,
+    pub reel_left: Vec<KeyCode>,
+    pub reel_right: Vec<KeyCode>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            menu_up: vec![KeyCode::ArrowUp, KeyCode::KeyW],
+            menu_down: vec![KeyCode::ArrowDown, KeyCode::KeyS],
+            confirm: vec![KeyCode::Enter, KeyCode::Space],
+            back: vec![KeyCode::Escape],
+            reel_left: vec![KeyCode::KeyA, KeyCode::ArrowLeft],
+            reel_right: vec![KeyCode::KeyD, KeyCode::ArrowRight],
+        }
+    }
+}
+
+fn bindings_path() -> PathBuf {
+    save::data_dir().join("keybindings.json")
+}
+
+impl KeyBindings {
+    /// Load bindings from disk, writing the defaults out on first run so
+    /// players have a file to edit.
+    pub fn load() -> Self {
+        let path = bindings_path();
+        if !path.exists() {
+            let defaults = Self::default();
+            if let Err(e) = defaults.save() {
+                tracing::warn!("Failed to write default key bindings: {}", e);
+            }
+            return defaults;
+        }
+
+        match std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|json| serde_json::from_str(&json).map_err(|e| e.to_string()))
+        {
+            Ok(bindings) => bindings,
+            Err(e) => {
+                tracing::warn!("Invalid key bindings in {} ({}), using defaults", path.display(), e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Save bindings to disk.
+    pub fn save(&self) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(bindings_path(), json).map_err(|e| e.to_string())
+    }
+
+    /// All keys bound to an action.
+    pub fn keys(&self, action: Action) -> &[KeyCode] {
+        match action {
+            Action::MenuUp => &self.menu_up,
+            Action::MenuDown => &self.menu_down,
+            Action::Confirm => &self.confirm,
+            Action::Back => &self.back,
+            Action::ReelLeft => &self.reel_left,
+            Action::ReelRight => &self.reel_right,
+        }
+    }
+
+    /// Whether `key` is bound to `action`.
+    pub fn is(&self, action: Action, key: KeyCode) -> bool {
+        self.keys(action).contains(&key)
+    }
+
+    /// Whether `key` is bound to any action.
+    pub fn is_bound(&self, key: KeyCode) -> bool {
+        [
+            Action::MenuUp,
+            Action::MenuDown,
+            Action::Confirm,
+            Action::Back,
+            Action::ReelLeft,
+            Action::ReelRight,
+        ]
+        .into_iter()
+        .any(|action| self.is(action, key))
+    }
+
+    /// Whether any key bound to `action` is currently held.
+    pub fn held(&self, action: Action, input: &InputState) -> bool {
+        input.any_held(self.keys(action))
+    }
+
+    /// The menu navigation action a key press maps to, if any.
+    pub fn menu_action(&self, key: KeyCode) -> Option<Action> {
+        [Action::MenuUp, Action::MenuDown, Action::Confirm, Action::Back]
+            .into_iter()
+            .find(|&action| self.is(action, key))
+    }
+}
@@ -3,34 +3,76 @@
 //! A 2D ASCII-art dating simulator where you catch fish and take them on dates.
 //! Built with the Sable engine.
 
-use std::time::Instant;
+use std::path::PathBuf;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
 use pollster::FutureExt;
 use winit::application::ApplicationHandler;
-use winit::event::{ElementState, KeyEvent, WindowEvent};
+use winit::event::{ElementState, KeyEvent, MouseButton, WindowEvent};
 use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoop};
 use winit::keyboard::{KeyCode, PhysicalKey};
-use winit::window::WindowId;
+use winit::window::{Fullscreen, WindowId};
 
 use sable_gpu::prelude::*;
 use sable_platform::prelude::*;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::prelude::*;
 
 mod achievements;
 #[allow(dead_code)]
 mod ascii_art;
+mod audio;
+mod console;
+mod cutscene;
 mod data;
 mod dating;
 mod easter_egg;
 mod fishing;
 mod game;
+mod glyphs;
+mod i18n;
+mod input;
 mod plugins;
 #[allow(dead_code)]
 mod render;
+mod settings;
+mod surface;
+mod terminal;
+mod theme;
 #[allow(dead_code)]
 mod ui;
 
-const WINDOW_WIDTH: u32 = 1024;
-const WINDOW_HEIGHT: u32 = 768;
+/// Write a captured ASCII frame to `screenshots/frame_<timestamp>.txt` in the
+/// data directory.
+fn save_screenshot(text: &str) -> Result<PathBuf, String> {
+    let dir = data::save::data_dir().join("screenshots");
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+    let path = dir.join(format!("frame_{}.txt", timestamp));
+    std::fs::write(&path, text).map_err(|e| e.to_string())?;
+    Ok(path)
+}
+
+/// How quickly the overlay's FPS follows the latest frame time (0 to 1).
+const FPS_SMOOTHING: f32 = 0.1;
+
+/// Draw frame stats in the top-right corner for diagnosing busy screens.
+fn draw_debug_overlay(renderer: &mut render::GameRenderer, fps: f32, image_count: u32) {
+    use crate::surface::DrawSurface;
+
+    let text = format!(
+        "FPS {:.0}  glyphs {}/{}  sprites {}",
+        fps,
+        renderer.glyph_count(),
+        renderer.glyph_capacity(),
+        image_count
+    );
+    let col = renderer.screen_cols() - text.chars().count() as f32 - 1.0;
+    renderer.draw_at_grid(&text, col.max(0.0), 0.0, render::Colors::green());
+}
 
 /// Create the event loop with platform-specific settings.
 fn create_event_loop() -> std::result::Result<EventLoop<()>, winit::error::EventLoopError> {
@@ -39,17 +81,20 @@ fn create_event_loop() -> std::result::Result<EventLoop<()>, winit::error::Event
         use winit::platform::wayland::EventLoopBuilderExtWayland;
         use winit::platform::x11::EventLoopBuilderExtX11;
 
-        if let Ok(event_loop) = EventLoop::builder().with_wayland().build() {
+        // winit allows only one attempt at creating the event loop, so pick
+        // the backend up front rather than falling back after a failure
+        if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+            let event_loop = EventLoop::builder().with_wayland().build()?;
             tracing::info!("Using Wayland backend");
             return Ok(event_loop);
         }
 
-        if let Ok(event_loop) = EventLoop::builder().with_x11().build() {
-            tracing::info!("Using X11 backend");
-            return Ok(event_loop);
-        }
+        let event_loop = EventLoop::builder().with_x11().build()?;
+        tracing::info!("Using X11 backend");
+        Ok(event_loop)
     }
 
+    #[cfg(not(target_os = "linux"))]
     EventLoop::builder().build()
 }
 
@@ -59,21 +104,85 @@ struct App {
     renderer: Option<render::GameRenderer>,
     game: game::Game,
     last_frame: Instant,
-    pending_key: Option<KeyCode>,
+    input: input::InputState,
+    /// Why the window or GPU couldn't start, reported once the event loop exits.
+    startup_error: Option<String>,
+    /// Whether the F3 debug overlay is showing.
+    debug_overlay: bool,
+    /// Whether the console layer was installed at startup, so backtick can
+    /// open the debug console.
+    console_enabled: bool,
+    /// Whether the backtick debug console is showing.
+    console_open: bool,
+    /// Last key pressed, for the debug console.
+    last_key: Option<KeyCode>,
+    /// Frames per second, smoothed over recent frames.
+    fps: f32,
+    /// Image sprites drawn in the last frame, for the debug overlay.
+    last_image_count: u32,
 }
 
 impl App {
-    fn new() -> Self {
+    fn new(seed: Option<u64>, console_enabled: bool) -> Self {
         // Load plugin fish from the plugins/ directory
-        let registry = plugins::load_all_plugins();
+        let (registry, plugin_report) = plugins::load_all_plugins();
 
         Self {
             window: None,
             gpu: None,
             renderer: None,
-            game: game::Game::new(registry),
+            game: game::Game::new(registry, plugin_report, 0, seed),
             last_frame: Instant::now(),
-            pending_key: None,
+            input: input::InputState::new(),
+            startup_error: None,
+            debug_overlay: false,
+            console_enabled,
+            console_open: false,
+            last_key: None,
+            fps: 0.0,
+            last_image_count: 0,
+        }
+    }
+
+    /// Stop the event loop because the game can't draw, keeping the reason
+    /// for `main` to report.
+    fn fail_startup(&mut self, event_loop: &ActiveEventLoop, reason: String) {
+        tracing::error!("Startup failed: {}", reason);
+        self.startup_error = Some(reason);
+        event_loop.exit();
+    }
+
+    /// Switch the window between borderless fullscreen and windowed.
+    ///
+    /// The window then reports a `Resized` event, which resizes the surface
+    /// and renderer camera like any other resize.
+    fn apply_fullscreen(&self) {
+        let Some(window) = &self.window else { return };
+        let fullscreen = self
+            .game
+            .settings
+            .fullscreen
+            .then_some(Fullscreen::Borderless(None));
+        window.winit_window().set_fullscreen(fullscreen);
+    }
+
+    fn toggle_fullscreen(&mut self) {
+        self.game.settings.fullscreen = !self.game.settings.fullscreen;
+        self.apply_fullscreen();
+        if let Err(e) = self.game.settings.save() {
+            tracing::warn!("Failed to save settings: {}", e);
+        }
+    }
+
+    /// Step to the next text scale and save it.
+    fn cycle_ui_scale(&mut self) {
+        self.game.settings.cycle_ui_scale();
+        if let Some(renderer) = &mut self.renderer {
+            renderer.set_scale(self.game.settings.ui_scale());
+        }
+        tracing::info!("Text scale set to {}", self.game.settings.ui_scale());
+        if let Err(e) = self.game.settings.save() {
+            tracing::warn!("Failed to save settings: {}", e);
         }
     }
 
@@ -108,17 +217,35 @@ impl App {
         renderer.update_camera(gpu.queue());
 
         // Begin text rendering
+        renderer.ensure_glyph_capacity(gpu.device());
         renderer.begin();
         renderer.begin_images();
 
-        // Draw the current game screen
-        self.game.render(renderer);
+        // Draw the current game screen. Blank cells don't cover anything
+        // drawn under them, so the console replaces the scene instead.
+        if self.console_open {
+            console::draw(renderer, self.game.screen_name(), self.last_key);
+        } else {
+            self.game.render(renderer);
+        }
+        if self.debug_overlay {
+            draw_debug_overlay(renderer, self.fps, self.last_image_count);
+        }
+        if let Some(text) = renderer.take_capture() {
+            match save_screenshot(&text) {
+                Ok(path) => tracing::info!("Saved screenshot to {}", path.display()),
+                Err(e) => tracing::warn!("Failed to save screenshot: {}", e),
+            }
+        }
 
         // End text rendering
         let text_count = renderer.end(gpu.queue());
         let image_count = renderer.end_images(gpu.queue());
+        self.last_image_count = image_count;
 
         // Submit render pass
+        let [r, g, b, a] = render::Colors::background().map(f64::from);
+        let clear_color = wgpu::Color { r, g, b, a };
         let mut encoder = gpu.create_command_encoder();
         {
             let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
@@ -127,12 +254,7 @@ impl App {
                     view: &view,
                     resolve_target: None,
                     ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color {
-                            r: 0.05,
-                            g: 0.05,
-                            b: 0.1,
-                            a: 1.0,
-                        }),
+                        load: wgpu::LoadOp::Clear(clear_color),
                         store: wgpu::StoreOp::Store,
                     },
                 })],
@@ -161,15 +283,26 @@ impl ApplicationHandler for App {
             return;
         }
 
+        let (width, height) = self.game.settings.window_size();
         let config = WindowConfig::new("cult_papa Fish Dating Simulator")
-            .with_size(WINDOW_WIDTH, WINDOW_HEIGHT)
+            .with_size(width, height)
             .with_resizable(true)
             .with_vsync(true);
 
-        let window = Window::new(event_loop, &config).expect("Failed to create window");
-        let gpu = GpuContext::new(&window)
-            .block_on()
-            .expect("Failed to create GPU context");
+        let window = match Window::new(event_loop, &config) {
+            Ok(window) => window,
+            Err(e) => {
+                self.fail_startup(event_loop, format!("couldn't create a window: {}", e));
+                return;
+            }
+        };
+        let gpu = match GpuContext::new(&window).block_on() {
+            Ok(gpu) => gpu,
+            Err(e) => {
+                self.fail_startup(event_loop, format!("couldn't initialize the GPU: {}", e));
+                return;
+            }
+        };
 
         tracing::info!(
             "GPU: {} ({:?})",
@@ -177,12 +310,16 @@ impl ApplicationHandler for App {
             gpu.adapter_info().backend
         );
 
-        let renderer = render::GameRenderer::new(&gpu, WINDOW_WIDTH, WINDOW_HEIGHT);
+        let mut renderer = render::GameRenderer::new(&gpu, width, height);
+        renderer.set_scale(self.game.settings.ui_scale());
 
         self.window = Some(window);
         self.renderer = Some(renderer);
         self.gpu = Some(gpu);
         self.last_frame = Instant::now();
+        if self.game.settings.fullscreen {
+            self.apply_fullscreen();
+        }
 
         tracing::info!("cult_papa Fish Dating Simulator initialized!");
     }
@@ -195,7 +332,7 @@ impl ApplicationHandler for App {
     ) {
         match event {
             WindowEvent::CloseRequested => {
-                let _ = data::save::save_game(&self.game.player);
+                self.game.save_on_exit();
                 event_loop.exit();
             }
             WindowEvent::Resized(size) => {
@@ -212,22 +349,63 @@ impl ApplicationHandler for App {
                 event:
                     KeyEvent {
                         physical_key: PhysicalKey::Code(key),
-                        state: ElementState::Pressed,
-                        repeat: false,
+                        state,
+                        repeat,
+                        text,
                         ..
                     },
                 ..
-            } => {
-                self.pending_key = Some(key);
+            } => match state {
+                ElementState::Pressed if !repeat && key == KeyCode::F11 => self.toggle_fullscreen(),
+                ElementState::Pressed if !repeat && key == KeyCode::F10 => self.cycle_ui_scale(),
+                ElementState::Pressed if !repeat && key == KeyCode::F3 => {
+                    self.debug_overlay = !self.debug_overlay;
+                }
+                ElementState::Pressed if !repeat && key == KeyCode::Backquote && self.console_enabled => {
+                    self.console_open = !self.console_open;
+                }
+                ElementState::Pressed if !repeat && key == KeyCode::F2 => {
+                    if let Some(renderer) = &mut self.renderer {
+                        renderer.request_capture();
+                    }
+                }
+                ElementState::Pressed if !repeat => {
+                    self.last_key = Some(key);
+                    self.input.press(key);
+                    if let Some(c) = text.and_then(|text| text.chars().next()) {
+                        self.input.type_char(c);
+                    }
+                }
+                ElementState::Pressed => self.input.repeat(key),
+                ElementState::Released => self.input.release(key),
+            },
+            WindowEvent::CursorMoved { position, .. } => {
+                if let Some(renderer) = &self.renderer {
+                    let (col, row) = renderer.pixel_to_grid(position.x as f32, position.y as f32);
+                    self.input.move_cursor(col, row);
+                }
+            }
+            WindowEvent::CursorLeft { .. } => self.input.clear_cursor(),
+            WindowEvent::MouseInput {
+                state: ElementState::Pressed,
+                button: MouseButton::Left,
+                ..
+            } => self.input.click(),
+            WindowEvent::Focused(false) => {
+                // Releases that happen while unfocused are never delivered
+                self.input.clear_held();
             }
             WindowEvent::RedrawRequested => {
                 let now = Instant::now();
                 let dt = (now - self.last_frame).as_secs_f32().min(0.1);
                 self.last_frame = now;
+                if dt > 0.0 {
+                    self.fps += (1.0 / dt - self.fps) * FPS_SMOOTHING;
+                }
 
                 // Process game logic
-                let key = self.pending_key.take();
-                self.game.update(dt, key);
+                self.game.update(dt, &self.input);
+                self.input.end_frame();
 
                 // Render
                 self.render_frame();
@@ -236,22 +414,157 @@ impl ApplicationHandler for App {
         }
     }
 
-    fn about_to_wait(&mut self, _event_loop: &ActiveEventLoop) {
+    fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        if self.game.quit_requested() {
+            event_loop.exit();
+            return;
+        }
         if let Some(window) = &self.window {
             window.request_redraw();
         }
     }
 }
 
+/// Read the value of `--seed <u64>` from the command line, if given.
+fn parse_seed(args: &[String]) -> Option<u64> {
+    let pos = args.iter().position(|arg| arg == "--seed")?;
+    let Some(value) = args.get(pos + 1) else {
+        tracing::warn!("--seed needs a number, ignoring it");
+        return None;
+    };
+    match value.parse() {
+        Ok(seed) => Some(seed),
+        Err(e) => {
+            tracing::warn!("Invalid --seed value '{}': {}", value, e);
+            None
+        }
+    }
+}
+
+/// Save slot named by `--slot <1-3>`, 0-based. Defaults to the first slot.
+fn parse_slot(args: &[String]) -> usize {
+    let Some(pos) = args.iter().position(|arg| arg == "--slot") else {
+        return 0;
+    };
+    match args.get(pos + 1).and_then(|value| value.parse::<usize>().ok()) {
+        Some(slot) if (1..=data::save::SLOT_COUNT).contains(&slot) => slot - 1,
+        _ => {
+            tracing::warn!("--slot needs a number from 1 to {}, using slot 1", data::save::SLOT_COUNT);
+            0
+        }
+    }
+}
+
+/// Handle `--export-stats <file>`: write the save's stats summary and exit.
+fn export_stats(args: &[String]) {
+    let Some(pos) = args.iter().position(|arg| arg == "--export-stats") else {
+        return;
+    };
+    let Some(path) = args.get(pos + 1) else {
+        eprintln!("--export-stats needs a file path");
+        std::process::exit(2);
+    };
+    let (registry, _) = plugins::load_all_plugins();
+    match data::save::export_stats(parse_slot(args), &registry, std::path::Path::new(path)) {
+        Ok(()) => std::process::exit(0),
+        Err(e) => {
+            eprintln!("Failed to export stats: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
 fn main() {
-    tracing_subscriber::fmt::init();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let terminal_mode = args.iter().any(|arg| arg == "--terminal");
+    let debug_console = !terminal_mode && settings::Settings::load().debug_console;
+
+    if terminal_mode {
+        // The terminal is the screen, so logs go to a file instead
+        let log_path = data::save::data_dir().join("terminal.log");
+        match std::fs::File::create(&log_path) {
+            Ok(file) => tracing_subscriber::fmt()
+                .with_writer(std::sync::Mutex::new(file))
+                .with_ansi(false)
+                .init(),
+            Err(e) => eprintln!("Failed to open {}: {}", log_path.display(), e),
+        }
+    } else if debug_console {
+        tracing_subscriber::registry()
+            .with(LevelFilter::INFO)
+            .with(tracing_subscriber::fmt::layer())
+            .with(console::ConsoleLayer)
+            .init();
+    } else {
+        tracing_subscriber::fmt::init();
+    }
 
     tracing::info!("Starting cult_papa Fish Dating Simulator");
     tracing::info!("Catch fish. Date fish. Find love.");
 
-    let event_loop = create_event_loop().expect("Failed to create event loop");
+    // Exported stats label relationships with the tiers in play
+    data::tiers::init();
+    export_stats(&args);
+    let seed = parse_seed(&args);
+    i18n::init(settings::Settings::load().language.as_deref());
+    theme::init();
+    glyphs::init();
+
+    if terminal_mode {
+        let (registry, plugin_report) = plugins::load_all_plugins();
+        let game = game::Game::new(registry, plugin_report, 0, seed);
+        if let Err(e) = terminal::run(game) {
+            eprintln!("Terminal error: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let event_loop = match create_event_loop() {
+        Ok(event_loop) => event_loop,
+        Err(e) => {
+            tracing::error!("Failed to create event loop: {}", e);
+            print_startup_help(&format!("couldn't connect to a display: {}", e));
+            std::process::exit(1);
+        }
+    };
     event_loop.set_control_flow(ControlFlow::Poll);
 
-    let mut app = App::new();
+    let mut app = App::new(seed, debug_console);
     event_loop.run_app(&mut app).expect("Event loop error");
+
+    if let Some(reason) = app.startup_error {
+        print_startup_help(&reason);
+        std::process::exit(1);
+    }
+}
+
+/// Explain a failed window or GPU startup and what the player can try.
+fn print_startup_help(reason: &str) {
+    eprintln!("cult_papa Fish Dating Simulator {}.", reason);
+    eprintln!();
+    eprintln!("The game needs a display and a graphics driver with Vulkan, Metal,");
+    eprintln!("DirectX 12 or OpenGL support. Things to try:");
+    eprintln!("  - Update your graphics drivers.");
+    eprintln!("  - On Linux, check that DISPLAY or WAYLAND_DISPLAY is set and that");
+    eprintln!("    Mesa (or your vendor's Vulkan driver) is installed.");
+    eprintln!("  - Over SSH or on a headless machine, play in the terminal instead:");
+    eprintln!("      cult-papa-fish-dating-simulator --terminal");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn seed_flag_is_parsed() {
+        assert_eq!(parse_seed(&args(&["game", "--seed", "42"])), Some(42));
+        assert_eq!(parse_seed(&args(&["game"])), None);
+        assert_eq!(parse_seed(&args(&["game", "--seed"])), None);
+        assert_eq!(parse_seed(&args(&["game", "--seed", "soon"])), None);
+    }
 }
@@ -0,0 +1,23 @@
+//! Cutscene definition data structure for plugin cutscenes.
+//!
+//! A `CutsceneDef` is a short run of ASCII art frames that plays when the
+//! player types its trigger word on the main menu, like the moon battle.
+
+/// One frame of a cutscene.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    /// Multi-line ASCII art, drawn centered.
+    pub art: String,
+    /// Line of text shown under the art.
+    pub caption: String,
+    /// Seconds before the next frame plays on its own.
+    pub duration: f32,
+}
+
+/// A cutscene registered with `register_cutscene`.
+#[derive(Debug, Clone)]
+pub struct CutsceneDef {
+    /// Word that starts the cutscene when typed on the main menu.
+    pub trigger: String,
+    pub frames: Vec<Frame>,
+}
@@ -3,11 +3,17 @@
 //! Provides a simplified dialogue builder that Rhai scripts can use to
 //! construct dialogue trees without needing the full sable-dialogue API.
 
+use std::collections::{HashMap, HashSet};
+
 use rhai::{Map, Array};
 use sable_dialogue::prelude::*;
 use sable_dialogue::dialogue::DialogueBuilder;
 use sable_dialogue::node::Choice as DChoice;
 
+use crate::data::dialogue_check::{NodeLinks, validate_links};
+use crate::data::dialogues::{END_DATE_VARIABLE, flag_variable};
+use crate::i18n::t;
+
 /// A simplified dialogue definition that can be constructed from Rhai.
 /// Converted to a `DialogueTree` via `to_dialogue_tree()`.
 #[derive(Debug, Clone)]
@@ -25,6 +31,8 @@ pub enum NodeDef {
         speaker: String,
         text: String,
         next: String,
+        /// Portrait to show for this line ("happy", "angry", "shy").
+        emotion: Option<String>,
     },
     Choice {
         id: String,
@@ -34,6 +42,14 @@ pub enum NodeDef {
     End {
         id: String,
     },
+    /// Jumps to `if_set` when the player has the memory flag, otherwise to
+    /// `otherwise`. Decided when the date starts, so it never shows up as a node.
+    Branch {
+        id: String,
+        flag: String,
+        if_set: String,
+        otherwise: String,
+    },
 }
 
 /// A simplified choice option.
@@ -42,6 +58,13 @@ pub struct ChoiceOptionDef {
     pub text: String,
     pub next: String,
     pub affection: i32,
+    /// Minimum affection needed to pick this option (0 = always available).
+    pub require_affection: i32,
+    /// Memory flag remembered for later dates when this option is picked.
+    pub flag: Option<String>,
+    /// Picking this option ends the date at once, with the fish storming
+    /// off. `next` is ignored.
+    pub end_date: bool,
 }
 
 impl DialogueDef {
@@ -57,12 +80,13 @@ impl DialogueDef {
         self.speakers.push((id.to_string(), display_name.to_string()));
     }
 
-    pub fn add_text(&mut self, id: &str, speaker: &str, text: &str, next: &str) {
+    pub fn add_text(&mut self, id: &str, speaker: &str, text: &str, next: &str, emotion: Option<&str>) {
         self.nodes.push(NodeDef::Text {
             id: id.to_string(),
             speaker: speaker.to_string(),
             text: text.to_string(),
             next: next.to_string(),
+            emotion: emotion.map(str::to_string),
         });
     }
 
@@ -80,13 +104,102 @@ impl DialogueDef {
         });
     }
 
-    /// Convert this definition into a sable-dialogue `DialogueTree`.
-    pub fn to_dialogue_tree(&self) -> DialogueTree {
-        let start_node = self.nodes.first().map(|n| match n {
-            NodeDef::Text { id, .. } => id.as_str(),
-            NodeDef::Choice { id, .. } => id.as_str(),
-            NodeDef::End { id } => id.as_str(),
-        }).unwrap_or("start");
+    pub fn add_branch(&mut self, id: &str, flag: &str, if_set: &str, otherwise: &str) {
+        self.nodes.push(NodeDef::Branch {
+            id: id.to_string(),
+            flag: flag.to_string(),
+            if_set: if_set.to_string(),
+            otherwise: otherwise.to_string(),
+        });
+    }
+
+    fn node_id(node: &NodeDef) -> &str {
+        match node {
+            NodeDef::Text { id, .. }
+            | NodeDef::Choice { id, .. }
+            | NodeDef::End { id }
+            | NodeDef::Branch { id, .. } => id,
+        }
+    }
+
+    /// Follow branch nodes from `id` to the node the player will actually
+    /// reach with `flags`. Gives up after visiting every node once, so a
+    /// loop of branches can't hang.
+    fn resolve<'a>(&'a self, mut id: &'a str, flags: &HashSet<String>) -> &'a str {
+        for _ in 0..self.nodes.len() {
+            let branch = self.nodes.iter().find_map(|node| match node {
+                NodeDef::Branch { id: branch_id, flag, if_set, otherwise } if branch_id == id => {
+                    Some(if flags.contains(flag) { if_set } else { otherwise })
+                }
+                _ => None,
+            });
+            match branch {
+                Some(next) => id = next,
+                None => break,
+            }
+        }
+        id
+    }
+
+    /// Check the node graph for duplicate ids, `next` targets that don't
+    /// exist, nodes the first node never leads to, and anything but exactly
+    /// one reachable end. Returns one message per problem found.
+    pub fn validate(&self) -> Vec<String> {
+        let start = self.nodes.first().map(Self::node_id).unwrap_or("start");
+        let links: Vec<NodeLinks> = self.nodes.iter().map(Self::links).collect();
+        validate_links(start, &links)
+            .into_iter()
+            .map(|issue| issue.to_string())
+            .collect()
+    }
+
+    /// Where `node` can lead. Branches count both ways, since which one is
+    /// taken depends on the player.
+    fn links(node: &NodeDef) -> NodeLinks {
+        let targets = match node {
+            NodeDef::Text { next, .. } => vec![next.clone()],
+            // Early endings go to the end node, which has to exist anyway
+            NodeDef::Choice { options, .. } => options
+                .iter()
+                .filter(|o| !o.end_date)
+                .map(|o| o.next.clone())
+                .collect(),
+            NodeDef::End { .. } => Vec::new(),
+            NodeDef::Branch { if_set, otherwise, .. } => vec![if_set.clone(), otherwise.clone()],
+        };
+        NodeLinks {
+            id: Self::node_id(node).to_string(),
+            targets,
+            end: matches!(node, NodeDef::End { .. }),
+        }
+    }
+
+    /// Affection requirements of gated choice options, keyed by option text.
+    ///
+    /// `sable_dialogue` choices can't carry the requirement themselves, so the
+    /// date scene looks it up by the text it is shown.
+    pub fn choice_requirements(&self) -> HashMap<String, i32> {
+        let mut requirements = HashMap::new();
+        for node in &self.nodes {
+            if let NodeDef::Choice { options, .. } = node {
+                for opt in options.iter().filter(|o| o.require_affection > 0) {
+                    requirements.insert(opt.text.clone(), opt.require_affection);
+                }
+            }
+        }
+        requirements
+    }
+
+    /// Convert this definition into a sable-dialogue `DialogueTree`, taking
+    /// the branches that match the player's memory `flags`.
+    pub fn to_dialogue_tree(&self, flags: &HashSet<String>) -> DialogueTree {
+        let start_node = self.nodes.first().map(Self::node_id).unwrap_or("start");
+        let start_node = self.resolve(start_node, flags);
+
+        let end_node = self.nodes.iter().find_map(|node| match node {
+            NodeDef::End { id } => Some(id.as_str()),
+            _ => None,
+        });
 
         let mut builder = DialogueBuilder::new(start_node)
             .title(&self.title);
@@ -97,24 +210,34 @@ impl DialogueDef {
 
         for node in &self.nodes {
             match node {
-                NodeDef::Text { id, speaker, text, next } => {
+                NodeDef::Text { id, speaker, text, next, emotion } => {
                     builder = builder.node(DialogueNode::Text {
                         id: id.clone(),
                         speaker: Some(speaker.clone()),
-                        emotion: None,
-                        text: text.clone(),
-                        text_key: None,
-                        next_node: Some(next.clone()),
+                        emotion: emotion.clone(),
+                        text: t(text).to_string(),
+                        text_key: Some(text.clone()),
+                        next_node: Some(self.resolve(next, flags).to_string()),
                         actions: Vec::new(),
                         voice_clip: None,
                     });
                 }
                 NodeDef::Choice { id, prompt, options } => {
                     let choices: Vec<DChoice> = options.iter().map(|opt| {
-                        let mut choice = DChoice::new(&opt.text, &opt.next);
+                        let next = match end_node {
+                            Some(end) if opt.end_date => end,
+                            _ => self.resolve(&opt.next, flags),
+                        };
+                        let mut choice = DChoice::new(&opt.text, next);
                         if opt.affection != 0 {
                             choice = choice.sets("affection", opt.affection);
                         }
+                        if let Some(flag) = &opt.flag {
+                            choice = choice.sets(&flag_variable(flag), 1_i32);
+                        }
+                        if opt.end_date {
+                            choice = choice.sets(END_DATE_VARIABLE, 1_i32);
+                        }
                         choice
                     }).collect();
 
@@ -128,6 +251,7 @@ impl DialogueDef {
                 NodeDef::End { id } => {
                     builder = builder.node(DialogueNode::end(id));
                 }
+                NodeDef::Branch { .. } => {}
             }
         }
 
@@ -136,16 +260,28 @@ impl DialogueDef {
 }
 
 /// Parse an array of choice options from Rhai.
-/// Each option can be a map with keys: text, next, affection
+/// Each option can be a map with keys: text, next, affection, require_affection, flag,
+/// end_date. `next` may be left out when `end_date` is true.
 pub fn parse_choice_options(arr: &Array) -> Vec<ChoiceOptionDef> {
     arr.iter().filter_map(|item| {
         if let Some(map) = item.clone().try_cast::<Map>() {
             let text = map.get("text")?.clone().into_string().ok()?;
-            let next = map.get("next")?.clone().into_string().ok()?;
+            let end_date = map.get("end_date")
+                .and_then(|v| v.as_bool().ok())
+                .unwrap_or(false);
+            let next = match map.get("next") {
+                Some(next) => next.clone().into_string().ok()?,
+                None if end_date => String::new(),
+                None => return None,
+            };
             let affection = map.get("affection")
                 .and_then(|v| v.as_int().ok())
                 .unwrap_or(0) as i32;
-            Some(ChoiceOptionDef { text, next, affection })
+            let require_affection = map.get("require_affection")
+                .and_then(|v| v.as_int().ok())
+                .unwrap_or(0) as i32;
+            let flag = map.get("flag").and_then(|v| v.clone().into_string().ok());
+            Some(ChoiceOptionDef { text, next, affection, require_affection, flag, end_date })
         } else {
             None
         }
@@ -3,10 +3,17 @@
 //! A `FishDef` holds all the data that defines a dateable fish character,
 //! whether built-in or loaded from a Rhai plugin script.
 
+use std::collections::{HashMap, HashSet};
+
 use sable_dialogue::prelude::*;
 use sable_dialogue::dialogue::DialogueBuilder;
 use sable_dialogue::node::Choice as DChoice;
 
+use crate::data::FishPersonality;
+use crate::i18n::{t, tf};
+
+use super::dialogue_def::DialogueDef;
+
 /// Complete definition of a dateable fish character.
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -19,8 +26,16 @@ pub struct FishDef {
     pub species: String,
     /// Short description shown in menus.
     pub description: String,
+    /// Longer lore shown in the collection's encyclopedia. Paragraphs are
+    /// separated by blank lines.
+    pub encyclopedia: String,
     /// Difficulty of catching this fish (0.0 = easy, 1.0 = hard).
     pub difficulty: f32,
+    /// Half-width of the minigame's reeling zone (0.0-1.0). Derived from
+    /// `difficulty` when unset.
+    pub center_zone: Option<f32>,
+    /// How the fish fights in the minigame.
+    pub personality: FishPersonality,
     /// RGBA color for rendering.
     pub color: [f32; 4],
 
@@ -33,6 +48,8 @@ pub struct FishDef {
     pub art_sad: String,
     /// Small inline art used during fishing minigame.
     pub art_small: String,
+    /// Optional idle animation frames, cycled in place of the mood art.
+    pub art_frames: Vec<String>,
 
     // ── Date location ──────────────────────────────────────────────
     /// Name of the date location (e.g., "Kelp Garden").
@@ -41,10 +58,24 @@ pub struct FishDef {
     pub date_scene_art: String,
     /// Name of the fishing pond where this fish can be caught.
     pub pond_name: String,
+    /// Gift item this fish loves (boosts affection when given before a date).
+    pub favorite_gift: Option<String>,
 
     // ── Dialogues ──────────────────────────────────────────────────
-    /// Dialogue trees for dates (rotated by date number).
-    pub dialogues: Vec<DialogueTree>,
+    /// Dialogues for dates (rotated by date number). Built into trees when
+    /// a date starts, so branches can follow the player's memory flags.
+    pub dialogues: Vec<DialogueDef>,
+    /// Extra dialogues unlocked at Romantic Interest, appended to the rotation.
+    pub romantic_dialogues: Vec<DialogueDef>,
+    /// Short scenes played the first time the fish reaches a relationship
+    /// tier, keyed by the tier's floor (see `MILESTONE_TIERS`).
+    pub milestone_dialogues: HashMap<i32, DialogueDef>,
+    /// Group date scripts keyed by the partner fish's ID ("bubbles",
+    /// "marina", "gill" or a plugin ID).
+    pub group_dialogues: HashMap<String, DialogueDef>,
+    /// Minimum affection for gated choice options across all dialogues,
+    /// keyed by option text.
+    pub choice_requirements: HashMap<String, i32>,
 }
 
 impl FishDef {
@@ -59,14 +90,36 @@ impl FishDef {
         }
     }
 
+    /// Get animation frame `frame`, or the mood art if there are no frames.
+    pub fn animated_art(&self, frame: usize, affection: i32) -> &str {
+        if self.art_frames.is_empty() {
+            return self.art_for_affection(affection);
+        }
+        &self.art_frames[frame % self.art_frames.len()]
+    }
+
     /// Build a dialogue tree for a given date number.
-    pub fn dialogue_for_date(&self, date_number: u32) -> DialogueTree {
-        if self.dialogues.is_empty() {
+    ///
+    /// When `romantic` is set, any romantic dialogues join the end of the rotation.
+    /// Branch nodes follow the player's memory `flags`.
+    pub fn dialogue_for_date(
+        &self,
+        date_number: u32,
+        romantic: bool,
+        flags: &HashSet<String>,
+    ) -> DialogueTree {
+        let romantic_count = if romantic { self.romantic_dialogues.len() } else { 0 };
+        let total = self.dialogues.len() + romantic_count;
+        if total == 0 {
             // Fallback: generate a minimal dialogue
             return Self::fallback_dialogue(&self.name);
         }
-        let idx = (date_number as usize) % self.dialogues.len();
-        self.dialogues[idx].clone()
+        let idx = (date_number as usize) % total;
+        if idx < self.dialogues.len() {
+            self.dialogues[idx].to_dialogue_tree(flags)
+        } else {
+            self.romantic_dialogues[idx - self.dialogues.len()].to_dialogue_tree(flags)
+        }
     }
 
     /// Generate a simple fallback dialogue when no dialogues are defined.
@@ -85,18 +138,15 @@ impl FishDef {
                 id: "start".into(),
                 speaker: Some(speaker_id.clone()),
                 emotion: None,
-                text: format!(
-                    "Hi there! I'm {}. Thanks for taking me out!",
-                    name
-                ),
-                text_key: None,
+                text: tf("Hi there! I'm {}. Thanks for taking me out!", &[&name]),
+                text_key: Some("Hi there! I'm {}. Thanks for taking me out!".into()),
                 next_node: Some("q1".into()),
                 actions: Vec::new(),
                 voice_clip: None,
             })
             .node(DialogueNode::Choice {
                 id: "q1".into(),
-                prompt: Some(format!("{} smiles at you.", name)),
+                prompt: Some(tf("{} smiles at you.", &[&name])),
                 speaker: None,
                 choices: vec![
                     DChoice::new("This is nice!", "ending")
@@ -109,8 +159,8 @@ impl FishDef {
                 id: "ending".into(),
                 speaker: Some(speaker_id),
                 emotion: None,
-                text: format!("I had a great time! See you around!"),
-                text_key: None,
+                text: t("I had a great time! See you around!").to_string(),
+                text_key: Some("I had a great time! See you around!".into()),
                 next_node: Some("end".into()),
                 actions: Vec::new(),
                 voice_clip: None,
@@ -4,48 +4,85 @@
 //! from the `plugins/` directory.
 
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::path::Path;
 use std::rc::Rc;
 
 use rhai::{Engine, Dynamic, Map, Array, CustomType, TypeBuilder};
 
+use crate::data::{FishPersonality, MILESTONE_TIERS, milestone_key};
+use crate::easter_egg::MOON_SECRET;
+
+use super::cutscene_def::{CutsceneDef, Frame};
 use super::dialogue_def::{DialogueDef, parse_choice_options};
 use super::fish_def::FishDef;
+use super::manifest::{MANIFEST_FILE, PluginManifest};
+use super::pond_def::PondDef;
 use super::registry::FishRegistry;
 
-/// Load all `.rhai` plugins from the given directory into the registry.
+/// Seconds a cutscene frame shows when the script doesn't say.
+const DEFAULT_FRAME_DURATION: f32 = 3.0;
+
+/// Load all `.rhai` plugins from the given directory into the registry,
+/// following `manifest.json` when there is one.
 pub fn load_plugins(plugins_dir: &Path, registry: &mut FishRegistry) {
     if !plugins_dir.exists() {
         tracing::info!("No plugins directory found at {:?}, skipping plugin loading", plugins_dir);
         return;
     }
 
+    let scripts = find_scripts(plugins_dir);
+    let order = match PluginManifest::load(plugins_dir) {
+        Ok(Some(manifest)) => {
+            let (order, missing) = manifest.load_order(&scripts);
+            for file in &missing {
+                registry.add_error(file, format!("listed in {} but the file doesn't exist", MANIFEST_FILE));
+            }
+            let disabled = scripts.len() - scripts.iter().filter(|s| order.contains(s)).count();
+            if disabled > 0 {
+                tracing::info!("{} plugin script(s) disabled in {}", disabled, MANIFEST_FILE);
+            }
+            order
+        }
+        Ok(None) => scripts,
+        Err(e) => {
+            // A broken manifest shouldn't hide every plugin
+            registry.add_error(MANIFEST_FILE, e);
+            scripts
+        }
+    };
+
+    if order.is_empty() {
+        tracing::info!("No .rhai plugin scripts to load in {:?}", plugins_dir);
+        return;
+    }
+
+    tracing::info!("Loading {} plugin script(s) from {:?}", order.len(), plugins_dir);
+
+    for file in &order {
+        load_single_plugin(&plugins_dir.join(file), registry);
+    }
+}
+
+/// File names of the `.rhai` scripts in `plugins_dir`, sorted.
+pub fn find_scripts(plugins_dir: &Path) -> Vec<String> {
     let entries = match std::fs::read_dir(plugins_dir) {
         Ok(entries) => entries,
         Err(e) => {
             tracing::warn!("Failed to read plugins directory: {:?}", e);
-            return;
+            return Vec::new();
         }
     };
 
-    let mut scripts: Vec<std::path::PathBuf> = entries
+    let mut scripts: Vec<String> = entries
         .filter_map(|e| e.ok())
         .map(|e| e.path())
         .filter(|p| p.extension().is_some_and(|ext| ext == "rhai"))
+        .filter_map(|p| p.file_name().map(|name| name.to_string_lossy().into_owned()))
         .collect();
 
     scripts.sort();
-
-    if scripts.is_empty() {
-        tracing::info!("No .rhai plugin scripts found in {:?}", plugins_dir);
-        return;
-    }
-
-    tracing::info!("Found {} plugin script(s) in {:?}", scripts.len(), plugins_dir);
-
-    for script_path in &scripts {
-        load_single_plugin(script_path, registry);
-    }
+    scripts
 }
 
 /// Load a single `.rhai` plugin script.
@@ -56,34 +93,65 @@ fn load_single_plugin(path: &Path, registry: &mut FishRegistry) {
     let source = match std::fs::read_to_string(path) {
         Ok(s) => s,
         Err(e) => {
-            tracing::error!("Failed to read plugin {}: {:?}", filename, e);
+            registry.add_error(&filename, format!("failed to read file: {}", e));
             return;
         }
     };
 
-    // Create a shared vec to collect registered fish from the script
+    // Create shared vecs to collect registered fish, ponds and errors from the script
     let registered: Rc<RefCell<Vec<FishDef>>> = Rc::new(RefCell::new(Vec::new()));
+    let ponds: Rc<RefCell<Vec<PondDef>>> = Rc::new(RefCell::new(Vec::new()));
+    let cutscenes: Rc<RefCell<Vec<CutsceneDef>>> = Rc::new(RefCell::new(Vec::new()));
+    let errors: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(Vec::new()));
+
+    let engine = create_engine(registered.clone(), ponds.clone(), cutscenes.clone(), errors.clone());
+    let result = engine.eval::<()>(&source);
 
-    let engine = create_engine(registered.clone());
+    for message in errors.borrow().iter() {
+        registry.add_error(&filename, message.clone());
+    }
 
-    match engine.eval::<()>(&source) {
+    match result {
         Ok(()) => {
             let fish_defs = registered.borrow();
-            if fish_defs.is_empty() {
+            let pond_defs = ponds.borrow();
+            let cutscene_defs = cutscenes.borrow();
+            let nothing_registered = fish_defs.is_empty() && pond_defs.is_empty() && cutscene_defs.is_empty();
+            if nothing_registered && errors.borrow().is_empty() {
                 tracing::warn!("Plugin {} didn't register any fish", filename);
             }
             for fish in fish_defs.iter() {
-                registry.register(fish.clone());
+                if !registry.register(fish.clone()) {
+                    registry.add_error(&filename, format!("fish id '{}' is already registered", fish.id));
+                }
+            }
+            for pond in pond_defs.iter() {
+                if !registry.register_pond(pond.clone()) {
+                    registry.add_error(&filename, format!("pond '{}' is already registered", pond.name));
+                }
+            }
+            for cutscene in cutscene_defs.iter() {
+                if !registry.register_cutscene(cutscene.clone()) {
+                    registry.add_error(
+                        &filename,
+                        format!("cutscene trigger '{}' is already registered", cutscene.trigger),
+                    );
+                }
             }
         }
         Err(e) => {
-            tracing::error!("Error in plugin {}: {}", filename, e);
+            registry.add_error(&filename, format!("script error: {}", e));
         }
     }
 }
 
 /// Create a Rhai engine with all the fish plugin API functions registered.
-fn create_engine(registered: Rc<RefCell<Vec<FishDef>>>) -> Engine {
+fn create_engine(
+    registered: Rc<RefCell<Vec<FishDef>>>,
+    ponds: Rc<RefCell<Vec<PondDef>>>,
+    cutscenes: Rc<RefCell<Vec<CutsceneDef>>>,
+    errors: Rc<RefCell<Vec<String>>>,
+) -> Engine {
     let mut engine = Engine::new();
 
     // Register the DialogueDef custom type
@@ -103,7 +171,12 @@ fn create_engine(registered: Rc<RefCell<Vec<FishDef>>>) -> Engine {
 
     // dialogue.text(id, speaker, text, next)
     engine.register_fn("text", |d: &mut DialogueDef, id: &str, speaker: &str, text: &str, next: &str| {
-        d.add_text(id, speaker, text, next);
+        d.add_text(id, speaker, text, next, None);
+    });
+
+    // dialogue.text(id, speaker, text, next, emotion)
+    engine.register_fn("text", |d: &mut DialogueDef, id: &str, speaker: &str, text: &str, next: &str, emotion: &str| {
+        d.add_text(id, speaker, text, next, Some(emotion));
     });
 
     // dialogue.choice(id, prompt, options_array)
@@ -118,19 +191,57 @@ fn create_engine(registered: Rc<RefCell<Vec<FishDef>>>) -> Engine {
         d.add_end(id);
     });
 
+    // dialogue.branch(id, flag, if_set, otherwise)
+    engine.register_fn("branch", |d: &mut DialogueDef, id: &str, flag: &str, if_set: &str, otherwise: &str| {
+        d.add_branch(id, flag, if_set, otherwise);
+    });
+
     // ── Fish registration ──────────────────────────────────────────────
 
     // register_fish(map) - takes a Rhai map and registers a fish
     let reg = registered.clone();
+    let fish_errors = errors.clone();
     engine.register_fn("register_fish", move |fish_map: Map| {
         match parse_fish_def(&fish_map) {
             Ok(fish) => {
                 reg.borrow_mut().push(fish);
             }
-            Err(e) => {
+            Err(problems) => {
                 // We can't easily return errors in Rhai registered fns,
-                // so we log and continue
-                eprintln!("[plugin error] Failed to register fish: {}", e);
+                // so we collect them for the registry and continue
+                let label = fish_label(&fish_map);
+                fish_errors.borrow_mut().extend(
+                    problems.into_iter().map(|e| format!("fish {}: {}", label, e)),
+                );
+            }
+        }
+    });
+
+    // ── Pond registration ──────────────────────────────────────────────
+
+    // register_pond(map) - takes a Rhai map #{ name: "...", fish_ids: [...] }
+    let pond_errors = errors.clone();
+    engine.register_fn("register_pond", move |pond_map: Map| {
+        match parse_pond_def(&pond_map) {
+            Ok(pond) => {
+                ponds.borrow_mut().push(pond);
+            }
+            Err(e) => {
+                pond_errors.borrow_mut().push(format!("pond: {}", e));
+            }
+        }
+    });
+
+    // ── Cutscene registration ──────────────────────────────────────────
+
+    // register_cutscene(map) - takes a Rhai map #{ trigger: "...", frames: [...] }
+    engine.register_fn("register_cutscene", move |cutscene_map: Map| {
+        match parse_cutscene_def(&cutscene_map) {
+            Ok(cutscene) => {
+                cutscenes.borrow_mut().push(cutscene);
+            }
+            Err(e) => {
+                errors.borrow_mut().push(format!("cutscene: {}", e));
             }
         }
     });
@@ -141,8 +252,18 @@ fn create_engine(registered: Rc<RefCell<Vec<FishDef>>>) -> Engine {
     engine
 }
 
-/// Parse a Rhai Map into a FishDef.
-fn parse_fish_def(map: &Map) -> Result<FishDef, String> {
+/// Name a fish map in error messages by its id, falling back to its name.
+fn fish_label(map: &Map) -> String {
+    ["id", "name"]
+        .iter()
+        .filter_map(|key| map.get(*key).and_then(|v| v.clone().into_string().ok()))
+        .find(|s| !s.trim().is_empty())
+        .map(|s| format!("'{}'", s))
+        .unwrap_or_else(|| "(unnamed)".to_string())
+}
+
+/// Parse a Rhai Map into a FishDef, returning every validation problem found.
+fn parse_fish_def(map: &Map) -> Result<FishDef, Vec<String>> {
     let get_str = |key: &str| -> Result<String, String> {
         map.get(key)
             .ok_or_else(|| format!("missing required field '{}'", key))?
@@ -157,21 +278,18 @@ fn parse_fish_def(map: &Map) -> Result<FishDef, String> {
             .unwrap_or_else(|| default.to_string())
     };
 
-    let id = get_str("id")?;
-    let name = get_str("name")?;
-    let species = get_str("species")?;
+    let get_f32 = |key: &str| -> Option<f32> { map.get(key).and_then(as_f32) };
+
+    let id = get_str("id").map_err(|e| vec![e])?;
+    if id.trim().is_empty() {
+        return Err(vec!["field 'id' must not be empty".to_string()]);
+    }
+    let name = get_str("name").map_err(|e| vec![e])?;
+    let species = get_str("species").map_err(|e| vec![e])?;
     let description = get_str_or("description", "A mysterious fish.");
-    let difficulty = map.get("difficulty")
-        .and_then(|v| {
-            if let Ok(f) = v.as_float() {
-                Some(f as f32)
-            } else if let Ok(i) = v.as_int() {
-                Some(i as f32)
-            } else {
-                None
-            }
-        })
-        .unwrap_or(0.5);
+    let encyclopedia = get_str_or("encyclopedia", &description);
+    let difficulty = get_f32("difficulty").unwrap_or(0.5);
+    let center_zone = get_f32("center_zone");
 
     let color = parse_color(map.get("color")).unwrap_or([1.0, 1.0, 1.0, 1.0]);
 
@@ -179,45 +297,234 @@ fn parse_fish_def(map: &Map) -> Result<FishDef, String> {
     let art_neutral = get_str_or("art_neutral", "  ><(((o>");
     let art_sad = get_str_or("art_sad", "  ><(((o>");
     let art_small = get_str_or("art_small", "><>");
+    let art_frames = map.get("art_frames")
+        .and_then(|v| v.clone().try_cast::<Array>())
+        .map(|frames| {
+            frames.into_iter()
+                .filter_map(|f| f.into_string().ok())
+                .collect()
+        })
+        .unwrap_or_default();
 
     let date_location = get_str_or("date_location", "The Deep");
     let date_scene_art = get_str_or("date_scene_art", "  ~~~~~~~~\n  ~ ~ ~ ~ ~\n  ~~~~~~~~");
     let pond_name = get_str_or("pond_name", &format!("{}'s Pond", name));
-
-    // Parse dialogues array
-    let dialogues = if let Some(dates_val) = map.get("dates") {
-        if let Some(dates_arr) = dates_val.clone().try_cast::<Array>() {
-            dates_arr.iter()
-                .filter_map(|d| {
-                    d.clone().try_cast::<DialogueDef>()
-                        .map(|def| def.to_dialogue_tree())
-                })
-                .collect()
-        } else {
-            Vec::new()
+    let favorite_gift = map.get("favorite_gift").and_then(|v| v.clone().into_string().ok());
+
+    // Parse and validate dialogue arrays
+    let date_defs = parse_dialogue_defs(map.get("dates"));
+    // `romantic_dates` is the older name, still read when the new one is absent
+    let romantic_field = ["romantic_dialogues", "romantic_dates"]
+        .into_iter()
+        .find(|key| map.contains_key(*key))
+        .unwrap_or("romantic_dialogues");
+    let romantic_defs = parse_dialogue_defs(map.get(romantic_field));
+
+    let mut errors = Vec::new();
+    for (field, defs) in [("dates", &date_defs), (romantic_field, &romantic_defs)] {
+        for (i, def) in defs.iter().enumerate() {
+            errors.extend(
+                def.validate()
+                    .into_iter()
+                    .map(|e| format!("{}[{}] \"{}\": {}", field, i, def.title, e)),
+            );
+        }
+    }
+    let milestone_dialogues = parse_milestone_dialogues(map.get("milestone_dialogues"), &mut errors);
+    let group_dialogues = parse_group_dialogues(map.get("group_dialogues"), &mut errors);
+    let personality = match map.get("personality").map(|v| v.clone().into_string()) {
+        None => FishPersonality::default(),
+        Some(Ok(key)) => FishPersonality::from_key(&key).unwrap_or_else(|| {
+            let keys: Vec<&str> = FishPersonality::ALL.iter().map(|p| p.key()).collect();
+            errors.push(format!(
+                "unknown personality '{}' (expected one of: {})",
+                key,
+                keys.join(", ")
+            ));
+            FishPersonality::default()
+        }),
+        Some(Err(_)) => {
+            errors.push("field 'personality' must be a string".to_string());
+            FishPersonality::default()
         }
-    } else {
-        Vec::new()
     };
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let choice_requirements = date_defs.iter()
+        .chain(&romantic_defs)
+        .chain(milestone_dialogues.values())
+        .chain(group_dialogues.values())
+        .flat_map(DialogueDef::choice_requirements)
+        .collect();
+    let dialogues = date_defs;
+    let romantic_dialogues = romantic_defs;
 
     Ok(FishDef {
         id,
         name,
         species,
         description,
+        encyclopedia,
         difficulty,
+        center_zone,
+        personality,
         color,
         art_happy,
         art_neutral,
         art_sad,
         art_small,
+        art_frames,
         date_location,
         date_scene_art,
         pond_name,
+        favorite_gift,
         dialogues,
+        romantic_dialogues,
+        milestone_dialogues,
+        group_dialogues,
+        choice_requirements,
     })
 }
 
+/// Collect milestone scenes from a Rhai map keyed by tier, like
+/// `#{ friend: d, close_friend: d, romantic_interest: d, soulmate: d }`.
+fn parse_milestone_dialogues(val: Option<&Dynamic>, errors: &mut Vec<String>) -> HashMap<i32, DialogueDef> {
+    let mut milestones = HashMap::new();
+    let Some(map) = val.and_then(|v| v.clone().try_cast::<Map>()) else {
+        return milestones;
+    };
+    for (key, def) in map {
+        let Some(tier) = MILESTONE_TIERS.into_iter().find(|&tier| milestone_key(tier) == key.as_str()) else {
+            let keys: Vec<String> = MILESTONE_TIERS.into_iter().map(milestone_key).collect();
+            errors.push(format!("milestone_dialogues: unknown tier '{}' (expected {})", key, keys.join(", ")));
+            continue;
+        };
+        let Some(def) = def.try_cast::<DialogueDef>() else {
+            errors.push(format!("milestone_dialogues.{} must be a dialogue", key));
+            continue;
+        };
+        errors.extend(
+            def.validate()
+                .into_iter()
+                .map(|e| format!("milestone_dialogues.{} \"{}\": {}", key, def.title, e)),
+        );
+        milestones.insert(tier, def);
+    }
+    milestones
+}
+
+/// Collect group date scripts from a Rhai map keyed by partner ID, like
+/// `#{ bubbles: d, coral_seahorse: d }`. Partners aren't checked here, since
+/// their plugin may not have loaded yet.
+fn parse_group_dialogues(val: Option<&Dynamic>, errors: &mut Vec<String>) -> HashMap<String, DialogueDef> {
+    let mut scripts = HashMap::new();
+    let Some(map) = val.and_then(|v| v.clone().try_cast::<Map>()) else {
+        return scripts;
+    };
+    for (partner, def) in map {
+        let Some(def) = def.try_cast::<DialogueDef>() else {
+            errors.push(format!("group_dialogues.{} must be a dialogue", partner));
+            continue;
+        };
+        errors.extend(
+            def.validate()
+                .into_iter()
+                .map(|e| format!("group_dialogues.{} \"{}\": {}", partner, def.title, e)),
+        );
+        scripts.insert(partner.to_string(), def);
+    }
+    scripts
+}
+
+/// Collect the `DialogueDef`s from a Rhai array, skipping non-dialogue entries.
+fn parse_dialogue_defs(val: Option<&Dynamic>) -> Vec<DialogueDef> {
+    let Some(arr) = val.and_then(|v| v.clone().try_cast::<Array>()) else {
+        return Vec::new();
+    };
+    arr.iter()
+        .filter_map(|d| d.clone().try_cast::<DialogueDef>())
+        .collect()
+}
+
+/// Parse a Rhai Map into a PondDef.
+fn parse_pond_def(map: &Map) -> Result<PondDef, String> {
+    let name = map.get("name")
+        .ok_or_else(|| "missing required field 'name'".to_string())?
+        .clone()
+        .into_string()
+        .map_err(|_| "field 'name' must be a string".to_string())?;
+
+    let fish_ids: Vec<String> = map.get("fish_ids")
+        .and_then(|v| v.clone().try_cast::<Array>())
+        .map(|ids| ids.into_iter().filter_map(|id| id.into_string().ok()).collect())
+        .unwrap_or_default();
+
+    if fish_ids.is_empty() {
+        return Err(format!("pond '{}' has no fish_ids", name));
+    }
+
+    Ok(PondDef { name, fish_ids })
+}
+
+/// Parse a Rhai map #{ trigger: "...", frames: [#{ art, caption, duration }, ...] }
+/// into a CutsceneDef.
+fn parse_cutscene_def(map: &Map) -> Result<CutsceneDef, String> {
+    let trigger = map.get("trigger")
+        .ok_or_else(|| "missing required field 'trigger'".to_string())?
+        .clone()
+        .into_string()
+        .map_err(|_| "field 'trigger' must be a string".to_string())?
+        .to_lowercase();
+
+    // Digits pick menu items and other keys don't reach the menu, so only
+    // letters can be typed as a secret
+    if trigger.len() < 2 || !trigger.chars().all(|c| c.is_ascii_lowercase()) {
+        return Err(format!("trigger '{}' must be at least 2 letters a-z", trigger));
+    }
+    if trigger == MOON_SECRET {
+        return Err(format!("trigger '{}' is taken by the moon battle", trigger));
+    }
+
+    let frames: Vec<Frame> = map.get("frames")
+        .and_then(|v| v.clone().try_cast::<Array>())
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|frame| frame.try_cast::<Map>())
+        .map(|frame| {
+            let get_str = |key: &str| {
+                frame.get(key).and_then(|v| v.clone().into_string().ok()).unwrap_or_default()
+            };
+            Frame {
+                art: get_str("art"),
+                caption: get_str("caption"),
+                duration: frame.get("duration")
+                    .and_then(as_f32)
+                    .filter(|d| *d > 0.0)
+                    .unwrap_or(DEFAULT_FRAME_DURATION),
+            }
+        })
+        .collect();
+
+    if frames.is_empty() {
+        return Err(format!("cutscene '{}' has no frames", trigger));
+    }
+
+    Ok(CutsceneDef { trigger, frames })
+}
+
+/// A Rhai number written as either a float or an int.
+fn as_f32(val: &Dynamic) -> Option<f32> {
+    if let Ok(f) = val.as_float() {
+        Some(f as f32)
+    } else if let Ok(i) = val.as_int() {
+        Some(i as f32)
+    } else {
+        None
+    }
+}
+
 /// Parse an RGBA color from a Rhai array [r, g, b, a] or [r, g, b].
 fn parse_color(val: Option<&Dynamic>) -> Option<[f32; 4]> {
     let val = val?;
@@ -252,3 +559,27 @@ impl CustomType for DialogueDef {
         builder.with_name("DialogueDef");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fish_map(romantic_key: &str) -> Map {
+        let mut date = DialogueDef::new("Moonlit Swim");
+        date.add_end("start");
+        let mut map = Map::new();
+        map.insert("id".into(), Dynamic::from("coral".to_string()));
+        map.insert("name".into(), Dynamic::from("Coral".to_string()));
+        map.insert("species".into(), Dynamic::from("Seahorse".to_string()));
+        map.insert(romantic_key.into(), Dynamic::from_array(vec![Dynamic::from(date)]));
+        map
+    }
+
+    #[test]
+    fn romantic_dialogues_accept_the_old_key() {
+        for key in ["romantic_dialogues", "romantic_dates"] {
+            let def = parse_fish_def(&fish_map(key)).expect("fish should parse");
+            assert_eq!(def.romantic_dialogues.len(), 1, "{}", key);
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! Optional plugin manifest choosing which scripts load and in what order.
+//!
+//! Without `manifest.json` in the plugins directory every `.rhai` file loads
+//! alphabetically. With one, listed scripts load first in the listed order
+//! and disabled ones are skipped. Scripts the manifest doesn't mention still
+//! load afterwards, so a newly added fish shows up without editing the file.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// File name of the manifest inside the plugins directory.
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// One plugin script listed in the manifest.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// Script file name, e.g. "coral_seahorse.rhai".
+    pub file: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// The plugin scripts to load, in load order.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PluginManifest {
+    pub plugins: Vec<ManifestEntry>,
+}
+
+fn manifest_path(plugins_dir: &Path) -> PathBuf {
+    plugins_dir.join(MANIFEST_FILE)
+}
+
+impl PluginManifest {
+    /// Read the manifest from `plugins_dir`, or `Ok(None)` if there isn't one.
+    pub fn load(plugins_dir: &Path) -> Result<Option<Self>, String> {
+        let path = manifest_path(plugins_dir);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let json = std::fs::read_to_string(&path).map_err(|e| format!("failed to read file: {}", e))?;
+        serde_json::from_str(&json)
+            .map(Some)
+            .map_err(|e| format!("invalid manifest: {}", e))
+    }
+
+    /// Save the manifest to `plugins_dir`.
+    pub fn save(&self, plugins_dir: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(manifest_path(plugins_dir), json).map_err(|e| e.to_string())
+    }
+
+    /// Scripts to load out of `scripts` (the files found on disk, sorted),
+    /// and the listed files that weren't found.
+    pub fn load_order(&self, scripts: &[String]) -> (Vec<String>, Vec<String>) {
+        let mut order = Vec::new();
+        let mut missing = Vec::new();
+        for entry in &self.plugins {
+            if !scripts.contains(&entry.file) {
+                missing.push(entry.file.clone());
+            } else if entry.enabled && !order.contains(&entry.file) {
+                order.push(entry.file.clone());
+            }
+        }
+        for script in scripts {
+            if !self.lists(script) {
+                order.push(script.clone());
+            }
+        }
+        (order, missing)
+    }
+
+    /// Whether `file` has an entry, enabled or not.
+    fn lists(&self, file: &str) -> bool {
+        self.plugins.iter().any(|e| e.file == file)
+    }
+
+    /// Add an enabled entry for each script not listed yet.
+    pub fn add_missing(&mut self, scripts: &[String]) {
+        for script in scripts {
+            if !self.lists(script) {
+                self.plugins.push(ManifestEntry {
+                    file: script.clone(),
+                    enabled: true,
+                });
+            }
+        }
+    }
+}
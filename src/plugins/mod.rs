@@ -1,12 +1,18 @@
 //! Plugin system for loading custom fish characters via Rhai scripts.
 //!
 //! Place `.rhai` files in the `plugins/` directory to add new dateable fish.
+//! The directory is looked up from `FISH_PLUGINS_DIR`, the working directory,
+//! or next to the executable, in that order.
 //! Each script defines a fish character with art, stats, and dialogue trees
-//! using the Rhai scripting API.
+//! using the Rhai scripting API. An optional `manifest.json` there picks which
+//! scripts load and in what order.
 
+pub mod cutscene_def;
 pub mod dialogue_def;
 pub mod fish_def;
 pub mod loader;
+pub mod manifest;
+pub mod pond_def;
 pub mod registry;
 
 pub use fish_def::FishDef;
@@ -14,20 +20,51 @@ pub use registry::FishRegistry;
 
 use std::path::PathBuf;
 
-/// Load all plugins from the default plugins directory.
-pub fn load_all_plugins() -> FishRegistry {
-    let mut registry = FishRegistry::new();
+/// Environment variable that points the game at a plugins directory.
+const PLUGINS_DIR_ENV: &str = "FISH_PLUGINS_DIR";
+
+/// Where plugins were loaded from and how many fish they added.
+#[derive(Debug, Clone, Default)]
+pub struct PluginLoadReport {
+    /// Directory plugins were read from, or `None` if none was found.
+    pub dir: Option<PathBuf>,
+    /// Number of plugin fish registered.
+    pub fish_count: usize,
+}
+
+/// Directories to look for plugins in, most specific first: the
+/// `FISH_PLUGINS_DIR` override, the working directory, then next to the
+/// executable.
+fn plugin_dir_candidates() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(dir) = std::env::var_os(PLUGINS_DIR_ENV) {
+        let dir = PathBuf::from(dir);
+        if !dir.is_dir() {
+            tracing::warn!("{} is set to {}, which isn't a directory", PLUGINS_DIR_ENV, dir.display());
+        }
+        dirs.push(dir);
+    }
+    dirs.push(PathBuf::from("plugins"));
+    if let Some(exe_dir) = std::env::current_exe().ok().and_then(|p| p.parent().map(PathBuf::from)) {
+        dirs.push(exe_dir.join("plugins"));
+    }
+    dirs
+}
 
-    // Look for plugins directory relative to the executable / cwd
-    let plugin_dirs = [
-        PathBuf::from("plugins"),
-        PathBuf::from("./plugins"),
-    ];
+/// Load all plugins from the first plugins directory that exists.
+pub fn load_all_plugins() -> (FishRegistry, PluginLoadReport) {
+    let mut registry = FishRegistry::new();
 
-    for dir in &plugin_dirs {
-        if dir.exists() {
+    let candidates = plugin_dir_candidates();
+    let dir = candidates.iter().find(|dir| dir.is_dir()).cloned();
+    match &dir {
+        Some(dir) => {
+            tracing::info!("Using plugins directory {}", dir.display());
             loader::load_plugins(dir, &mut registry);
-            break;
+        }
+        None => {
+            let looked: Vec<String> = candidates.iter().map(|d| d.display().to_string()).collect();
+            tracing::info!("No plugins directory found (looked in {})", looked.join(", "));
         }
     }
 
@@ -35,5 +72,9 @@ pub fn load_all_plugins() -> FishRegistry {
         tracing::info!("Loaded {} plugin fish total", registry.count());
     }
 
-    registry
+    let report = PluginLoadReport {
+        dir,
+        fish_count: registry.count(),
+    };
+    (registry, report)
 }
@@ -0,0 +1,14 @@
+//! Pond definition data structure for plugin ponds.
+//!
+//! A `PondDef` names a fishing spot and lists which plugin fish live there.
+//! Ponds can be registered explicitly with `register_pond`, or are implied
+//! by each fish's `pond_name`.
+
+/// A fishing pond and the plugin fish that can bite there.
+#[derive(Debug, Clone)]
+pub struct PondDef {
+    /// Display name (e.g., "Kelp Garden Shallows").
+    pub name: String,
+    /// Plugin IDs of the fish living in this pond.
+    pub fish_ids: Vec<String>,
+}
@@ -4,7 +4,19 @@
 
 use std::collections::HashMap;
 
+use crate::ascii_art;
+
+use super::cutscene_def::CutsceneDef;
 use super::fish_def::FishDef;
+use super::pond_def::PondDef;
+
+/// A problem found while loading a plugin script.
+#[derive(Debug, Clone)]
+pub struct PluginError {
+    /// Script file name (e.g., "coral_seahorse.rhai").
+    pub file: String,
+    pub message: String,
+}
 
 /// Central registry of all plugin fish characters.
 #[derive(Debug, Default)]
@@ -14,6 +26,14 @@ pub struct FishRegistry {
     fish: HashMap<String, FishDef>,
     /// Ordered list of plugin IDs (for deterministic iteration).
     order: Vec<String>,
+    /// Ponds registered explicitly via `register_pond`, in registration order.
+    explicit_ponds: Vec<PondDef>,
+    /// Resolved plugin ponds, rebuilt whenever a fish or pond is registered.
+    ponds: Vec<PondDef>,
+    /// Cutscenes registered via `register_cutscene`, in registration order.
+    cutscenes: Vec<CutsceneDef>,
+    /// Errors collected while loading plugins, in load order.
+    errors: Vec<PluginError>,
 }
 
 #[allow(dead_code)]
@@ -35,9 +55,96 @@ impl FishRegistry {
         );
         self.fish.insert(id.clone(), fish);
         self.order.push(id);
+        self.rebuild_ponds();
+        true
+    }
+
+    /// Register a plugin pond. Returns false if a pond with that name was
+    /// already registered explicitly.
+    pub fn register_pond(&mut self, pond: PondDef) -> bool {
+        if self.explicit_ponds.iter().any(|p| p.name == pond.name) {
+            tracing::warn!("Plugin pond '{}' already registered, skipping duplicate", pond.name);
+            return false;
+        }
+        tracing::info!("Registered plugin pond: {} ({} fish)", pond.name, pond.fish_ids.len());
+        self.explicit_ponds.push(pond);
+        self.rebuild_ponds();
+        true
+    }
+
+    /// Register a plugin cutscene. Returns false if its trigger is taken.
+    pub fn register_cutscene(&mut self, cutscene: CutsceneDef) -> bool {
+        if self.cutscenes.iter().any(|c| c.trigger == cutscene.trigger) {
+            tracing::warn!("Plugin cutscene '{}' already registered, skipping duplicate", cutscene.trigger);
+            return false;
+        }
+        tracing::info!(
+            "Registered plugin cutscene: {} ({} frames)",
+            cutscene.trigger,
+            cutscene.frames.len()
+        );
+        self.cutscenes.push(cutscene);
         true
     }
 
+    /// Resolve plugin ponds: explicit ponds first (keeping only registered
+    /// fish), then every remaining fish joins the pond named by its
+    /// `pond_name`, sharing it with any other fish of the same pond name.
+    fn rebuild_ponds(&mut self) {
+        let mut ponds: Vec<PondDef> = self
+            .explicit_ponds
+            .iter()
+            .map(|p| PondDef {
+                name: p.name.clone(),
+                fish_ids: p
+                    .fish_ids
+                    .iter()
+                    .filter(|id| self.fish.contains_key(*id))
+                    .cloned()
+                    .collect(),
+            })
+            .filter(|p| !p.fish_ids.is_empty())
+            .collect();
+
+        for id in &self.order {
+            if ponds.iter().any(|p| p.fish_ids.contains(id)) {
+                continue;
+            }
+            let Some(fish) = self.fish.get(id) else { continue };
+            match ponds.iter_mut().find(|p| p.name == fish.pond_name) {
+                Some(pond) => pond.fish_ids.push(id.clone()),
+                None => ponds.push(PondDef {
+                    name: fish.pond_name.clone(),
+                    fish_ids: vec![id.clone()],
+                }),
+            }
+        }
+
+        self.ponds = ponds;
+    }
+
+    /// Record a plugin loading error.
+    pub fn add_error(&mut self, file: &str, message: impl Into<String>) {
+        let message = message.into();
+        tracing::error!("Plugin {}: {}", file, message);
+        self.errors.push(PluginError {
+            file: file.to_string(),
+            message,
+        });
+    }
+
+    /// All errors collected while loading plugins.
+    pub fn errors(&self) -> &[PluginError] {
+        &self.errors
+    }
+
+    /// Number of distinct plugin files that reported at least one error.
+    pub fn failed_plugin_count(&self) -> usize {
+        let mut files: Vec<&str> = self.errors.iter().map(|e| e.file.as_str()).collect();
+        files.dedup();
+        files.len()
+    }
+
     /// Get a fish definition by plugin ID.
     pub fn get(&self, id: &str) -> Option<&FishDef> {
         self.fish.get(id)
@@ -58,17 +165,103 @@ impl FishRegistry {
         self.fish.len()
     }
 
-    /// Get all pond names from plugin fish (for adding to the pond selection).
+    /// All plugin cutscenes in registration order.
+    pub fn cutscenes(&self) -> &[CutsceneDef] {
+        &self.cutscenes
+    }
+
+    /// All plugin ponds in a stable order (for adding to the pond selection).
+    pub fn ponds(&self) -> &[PondDef] {
+        &self.ponds
+    }
+
+    /// Get all plugin pond names.
     pub fn pond_names(&self) -> Vec<&str> {
-        self.order
+        self.ponds.iter().map(|p| p.name.as_str()).collect()
+    }
+
+    /// Pond index for a plugin fish: the first pond it lives in, offset past
+    /// the built-in ponds.
+    pub fn pond_index_of(&self, id: &str) -> Option<usize> {
+        self.ponds
             .iter()
-            .filter_map(|id| self.fish.get(id))
-            .map(|f| f.pond_name.as_str())
-            .collect()
+            .position(|p| p.fish_ids.iter().any(|f| f == id))
+            .map(|slot| slot + ascii_art::POND_NAMES.len())
+    }
+
+    /// Name of the pond at a given index, built-in or plugin.
+    pub fn pond_name_at(&self, index: usize) -> Option<&str> {
+        if let Some(name) = ascii_art::POND_NAMES.get(index) {
+            return Some(name);
+        }
+        self.ponds
+            .get(index - ascii_art::POND_NAMES.len())
+            .map(|p| p.name.as_str())
+    }
+
+    /// Number of ponds, built-in and plugin.
+    pub fn pond_count(&self) -> usize {
+        ascii_art::POND_NAMES.len() + self.ponds.len()
     }
 
     /// Find a plugin fish by its pond name.
     pub fn fish_by_pond(&self, pond_name: &str) -> Option<&FishDef> {
-        self.fish.values().find(|f| f.pond_name == pond_name)
+        self.ponds
+            .iter()
+            .find(|p| p.name == pond_name)
+            .and_then(|p| p.fish_ids.first())
+            .and_then(|id| self.fish.get(id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::FishPersonality;
+
+    fn fish(id: &str, pond_name: &str) -> FishDef {
+        FishDef {
+            id: id.to_string(),
+            name: id.to_string(),
+            species: String::new(),
+            description: String::new(),
+            encyclopedia: String::new(),
+            difficulty: 0.5,
+            center_zone: None,
+            personality: FishPersonality::default(),
+            color: [1.0; 4],
+            art_happy: String::new(),
+            art_neutral: String::new(),
+            art_sad: String::new(),
+            art_small: String::new(),
+            art_frames: Vec::new(),
+            date_location: String::new(),
+            date_scene_art: String::new(),
+            pond_name: pond_name.to_string(),
+            favorite_gift: None,
+            dialogues: Vec::new(),
+            romantic_dialogues: Vec::new(),
+            milestone_dialogues: HashMap::new(),
+            group_dialogues: HashMap::new(),
+            choice_requirements: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn pond_index_resolves_in_any_registration_order() {
+        let defs = [("eel", "Kelp Garden"), ("ray", "Reef Edge"), ("koi", "Kelp Garden")];
+        let orders = [[0, 1, 2], [2, 1, 0], [1, 0, 2], [1, 2, 0]];
+        for order in orders {
+            let mut registry = FishRegistry::new();
+            for i in order {
+                let (id, pond) = defs[i];
+                assert!(registry.register(fish(id, pond)));
+            }
+            for (id, pond) in defs {
+                let index = registry.pond_index_of(id).expect("fish has a pond");
+                assert_eq!(registry.pond_name_at(index), Some(pond), "{} in order {:?}", id, order);
+            }
+            assert_eq!(registry.pond_count(), ascii_art::POND_NAMES.len() + 2);
+        }
     }
 }
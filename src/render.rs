@@ -8,6 +8,17 @@ use std::path::Path;
 use sable_gpu::prelude::*;
 use wgpu::util::DeviceExt;
 
+use crate::glyphs;
+use crate::surface::DrawSurface;
+use crate::theme;
+
+/// Characters the text buffer holds at startup. Text drawn past the
+/// capacity is dropped, so the buffer doubles after a frame overflows it.
+pub const INITIAL_GLYPH_CAPACITY: usize = 20_000;
+
+/// Share of the glyph capacity a frame can use before a warning is logged.
+const GLYPH_WARNING_SHARE: f32 = 0.9;
+
 /// An image sprite that can be drawn at a grid position.
 pub struct ImageSprite {
     pub texture: Texture,
@@ -25,33 +36,131 @@ pub struct GameRenderer {
     pub camera_bind_group: wgpu::BindGroup,
     pub font_bind_group: wgpu::BindGroup,
     pub camera: Camera2D,
+    /// Window size in physical pixels, for mapping the mouse onto the grid.
+    viewport: [f32; 2],
+    /// Text scale relative to the 8x8 builtin font.
+    scale: f32,
+    /// Record the next frame's text into `capture`.
+    capture_requested: bool,
+    /// Plain-text grid of the frame being drawn, only while capturing.
+    capture: Option<Vec<Vec<char>>>,
+    /// Characters the text buffer can hold.
+    glyph_capacity: usize,
+    /// Characters drawn so far this frame.
+    glyphs_drawn: usize,
+    /// Characters drawn in the last finished frame.
+    last_glyph_count: usize,
+    /// Whether the near-capacity warning has been logged.
+    glyph_warning_logged: bool,
+    /// Hold animations still; set from the settings each frame.
+    reduce_motion: bool,
     /// Bind group layout for textures (reused for image sprites).
     texture_bind_group_layout: wgpu::BindGroupLayout,
     /// Loaded image sprites (easter egg faces, etc.)
     pub cult_papa_face: Option<ImageSprite>,
 }
 
-/// Color presets for the game.
+/// Color presets for the game, read from the active [`theme`].
 pub struct Colors;
 
 impl Colors {
-    pub const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
-    pub const YELLOW: [f32; 4] = [1.0, 1.0, 0.0, 1.0];
-    pub const CYAN: [f32; 4] = [0.0, 1.0, 1.0, 1.0];
-    pub const GREEN: [f32; 4] = [0.2, 1.0, 0.2, 1.0];
-    pub const RED: [f32; 4] = [1.0, 0.3, 0.3, 1.0];
-    pub const ORANGE: [f32; 4] = [1.0, 0.6, 0.1, 1.0];
-    pub const BLUE: [f32; 4] = [0.3, 0.5, 1.0, 1.0];
-    pub const PINK: [f32; 4] = [1.0, 0.5, 0.7, 1.0];
-    pub const GRAY: [f32; 4] = [0.5, 0.5, 0.5, 1.0];
-    pub const DARK_GRAY: [f32; 4] = [0.3, 0.3, 0.3, 1.0];
-    pub const LIGHT_BLUE: [f32; 4] = [0.5, 0.7, 1.0, 1.0];
-    pub const PURPLE: [f32; 4] = [0.7, 0.3, 1.0, 1.0];
+    pub fn white() -> [f32; 4] {
+        theme::color("white")
+    }
+
+    pub fn yellow() -> [f32; 4] {
+        theme::color("yellow")
+    }
+
+    pub fn cyan() -> [f32; 4] {
+        theme::color("cyan")
+    }
+
+    pub fn green() -> [f32; 4] {
+        theme::color("green")
+    }
+
+    pub fn red() -> [f32; 4] {
+        theme::color("red")
+    }
+
+    pub fn orange() -> [f32; 4] {
+        theme::color("orange")
+    }
+
+    pub fn blue() -> [f32; 4] {
+        theme::color("blue")
+    }
+
+    pub fn pink() -> [f32; 4] {
+        theme::color("pink")
+    }
+
+    pub fn gray() -> [f32; 4] {
+        theme::color("gray")
+    }
+
+    pub fn dark_gray() -> [f32; 4] {
+        theme::color("dark_gray")
+    }
+
+    pub fn light_blue() -> [f32; 4] {
+        theme::color("light_blue")
+    }
+
+    pub fn purple() -> [f32; 4] {
+        theme::color("purple")
+    }
+
+    /// Window background behind the text.
+    pub fn background() -> [f32; 4] {
+        theme::color("background")
+    }
+
+    /// Unselected menu items.
+    pub fn menu_text() -> [f32; 4] {
+        theme::color("menu_text")
+    }
+
+    /// The selected menu item.
+    pub fn menu_highlight() -> [f32; 4] {
+        theme::color("menu_highlight")
+    }
+
+    /// Filled affection hearts.
+    pub fn heart() -> [f32; 4] {
+        theme::color("heart")
+    }
+
+    /// Affection hearts not yet earned.
+    pub fn heart_empty() -> [f32; 4] {
+        theme::color("heart_empty")
+    }
+
+    /// A steady line.
+    pub fn tension_low() -> [f32; 4] {
+        theme::color("tension_low")
+    }
+
+    /// A line under moderate tension.
+    pub fn tension_moderate() -> [f32; 4] {
+        theme::color("tension_moderate")
+    }
+
+    /// A line under high tension.
+    pub fn tension_high() -> [f32; 4] {
+        theme::color("tension_high")
+    }
+
+    /// A line about to snap.
+    pub fn tension_extreme() -> [f32; 4] {
+        theme::color("tension_extreme")
+    }
 }
 
 impl GameRenderer {
-    /// The scale for all text rendering (2x the 8x8 builtin font).
-    pub const SCALE: f32 = 2.0;
+    /// Default text scale (2x the 8x8 builtin font).
+    pub const DEFAULT_SCALE: f32 = 2.0;
     /// Character width in pixels at scale 1.0.
     pub const CHAR_W: f32 = 8.0;
     /// Character height in pixels at scale 1.0.
@@ -59,12 +168,23 @@ impl GameRenderer {
 
     /// Scaled character width.
     pub fn char_width(&self) -> f32 {
-        Self::CHAR_W * Self::SCALE
+        Self::CHAR_W * self.scale
     }
 
     /// Scaled character height (line height).
     pub fn char_height(&self) -> f32 {
-        Self::CHAR_H * Self::SCALE
+        Self::CHAR_H * self.scale
+    }
+
+    /// Current text scale.
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Change the text scale. Layout reflows on the next frame since every
+    /// grid computation goes through `char_width`/`char_height`.
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale;
     }
 
     /// Create the renderer from a GPU context.
@@ -79,7 +199,7 @@ impl GameRenderer {
 
         let font = BuiltinFont::create_font();
         let font_texture = BuiltinFont::create_texture(device, queue);
-        let text_renderer = TextRenderer::new(device, 20000);
+        let text_renderer = TextRenderer::new(device, INITIAL_GLYPH_CAPACITY);
 
         let camera = Camera2D::new(width as f32, height as f32);
         let camera_uniform = Camera2DUniform::from_camera(&camera);
@@ -200,6 +320,15 @@ impl GameRenderer {
             camera_bind_group,
             font_bind_group,
             camera,
+            viewport: [width as f32, height as f32],
+            scale: Self::DEFAULT_SCALE,
+            capture_requested: false,
+            capture: None,
+            glyph_capacity: INITIAL_GLYPH_CAPACITY,
+            glyphs_drawn: 0,
+            last_glyph_count: 0,
+            glyph_warning_logged: false,
+            reduce_motion: false,
             texture_bind_group_layout,
             cult_papa_face: None,
         };
@@ -213,6 +342,15 @@ impl GameRenderer {
     /// Resize viewport.
     pub fn resize(&mut self, width: u32, height: u32) {
         self.camera.set_viewport(width as f32, height as f32);
+        self.viewport = [width as f32, height as f32];
+    }
+
+    /// Convert a window pixel position into grid (column, row).
+    pub fn pixel_to_grid(&self, x: f32, y: f32) -> (f32, f32) {
+        let [width, height] = self.viewport;
+        let col = x / width.max(1.0) * self.screen_cols();
+        let row = y / height.max(1.0) * self.screen_rows();
+        (col, row)
     }
 
     /// Update camera uniform buffer.
@@ -228,80 +366,95 @@ impl GameRenderer {
     /// Begin a new frame of text drawing.
     pub fn begin(&mut self) {
         self.text_renderer.begin();
+        self.glyphs_drawn = 0;
+        self.capture = None;
+        if self.capture_requested {
+            self.capture_requested = false;
+            let cols = self.screen_cols().max(0.0) as usize;
+            let rows = self.screen_rows().max(0.0) as usize;
+            self.capture = Some(vec![vec![' '; cols]; rows]);
+        }
     }
 
-    /// Draw text at a pixel position with a given style.
-    pub fn draw_text(&mut self, text: &str, pos: [f32; 2], style: &TextStyle) {
-        self.text_renderer
-            .draw_text(text, pos, &self.font, style);
+    /// Record the text of the next frame as a plain-text screenshot.
+    pub fn request_capture(&mut self) {
+        self.capture_requested = true;
     }
 
-    /// Draw text at a grid position (column, row) from top-left of screen.
-    pub fn draw_at_grid(&mut self, text: &str, col: f32, row: f32, color: [f32; 4]) {
-        let (left, _, top, _) = self.camera.visible_bounds();
-        let x = left + col * self.char_width();
-        let y = top + row * self.char_height();
-        let style = TextStyle::new()
-            .with_scale(Self::SCALE)
-            .with_color(color[0], color[1], color[2], color[3]);
-        self.text_renderer
-            .draw_text(text, [x, y], &self.font, &style);
-    }
-
-    /// Draw multi-line text at a grid position.
-    pub fn draw_multiline_at_grid(
-        &mut self,
-        text: &str,
-        col: f32,
-        row: f32,
-        color: [f32; 4],
-    ) {
-        for (i, line) in text.lines().enumerate() {
-            self.draw_at_grid(line, col, row + i as f32, color);
+    /// Take the captured frame, one line per row, if one was recorded.
+    pub fn take_capture(&mut self) -> Option<String> {
+        let grid = self.capture.take()?;
+        let lines: Vec<String> = grid
+            .iter()
+            .map(|row| row.iter().collect::<String>().trim_end().to_string())
+            .collect();
+        Some(lines.join("\n") + "\n")
+    }
+
+    /// Copy drawn text into the capture grid, clipped to the screen.
+    fn record(&mut self, text: &str, col: f32, row: f32) {
+        let Some(grid) = &mut self.capture else { return };
+        let row = row.round();
+        if row < 0.0 {
+            return;
+        }
+        let Some(cells) = grid.get_mut(row as usize) else { return };
+        let start = col.round() as isize;
+        for (i, ch) in text.chars().enumerate() {
+            let x = start + i as isize;
+            if x < 0 {
+                continue;
+            }
+            if let Some(cell) = cells.get_mut(x as usize) {
+                *cell = ch;
+            }
         }
     }
 
-    /// Draw centered text at a given row.
-    pub fn draw_centered(&mut self, text: &str, row: f32, color: [f32; 4]) {
-        let (_, _, top, _) = self.camera.visible_bounds();
-        let y = top + row * self.char_height();
-        let style = TextStyle::new()
-            .with_scale(Self::SCALE)
-            .with_color(color[0], color[1], color[2], color[3])
-            .with_align(TextAlign::Center);
+    /// Draw text at a pixel position with a given style.
+    pub fn draw_text(&mut self, text: &str, pos: [f32; 2], style: &TextStyle) {
+        self.glyphs_drawn += text.chars().count();
         self.text_renderer
-            .draw_text(text, [0.0, y], &self.font, &style);
-    }
-
-    /// Draw multi-line centered text.
-    ///
-    /// Centers the *block* as a whole based on the widest line, then draws
-    /// every line from the same starting column so internal ASCII-art
-    /// alignment is preserved.
-    pub fn draw_multiline_centered(&mut self, text: &str, start_row: f32, color: [f32; 4]) {
-        let max_width = text.lines().map(|l| l.len()).max().unwrap_or(0) as f32;
-        let cols = self.screen_cols();
-        let start_col = (cols - max_width) / 2.0;
-        for (i, line) in text.lines().enumerate() {
-            self.draw_at_grid(line, start_col, start_row + i as f32, color);
-        }
+            .draw_text(text, pos, &self.font, style);
     }
 
     /// End text drawing and return vertex count.
     pub fn end(&mut self, queue: &wgpu::Queue) -> u32 {
+        self.last_glyph_count = self.glyphs_drawn;
+        let warn_at = (self.glyph_capacity as f32 * GLYPH_WARNING_SHARE) as usize;
+        if self.glyphs_drawn >= warn_at && !self.glyph_warning_logged {
+            self.glyph_warning_logged = true;
+            tracing::warn!(
+                "Frame drew {} characters, near the text buffer capacity of {}",
+                self.glyphs_drawn,
+                self.glyph_capacity
+            );
+        }
         self.text_renderer.end(queue)
     }
 
-    /// Get the number of columns visible on screen.
-    pub fn screen_cols(&self) -> f32 {
-        let (left, right, _, _) = self.camera.visible_bounds();
-        (right - left) / self.char_width()
+    /// Characters drawn in the last finished frame.
+    pub fn glyph_count(&self) -> usize {
+        self.last_glyph_count
     }
 
-    /// Get the number of rows visible on screen.
-    pub fn screen_rows(&self) -> f32 {
-        let (_, _, top, bottom) = self.camera.visible_bounds();
-        (bottom - top) / self.char_height()
+    /// Characters the text buffer can hold.
+    pub fn glyph_capacity(&self) -> usize {
+        self.glyph_capacity
+    }
+
+    /// Grow the text buffer if the last frame overflowed it. Call before
+    /// `begin`; the overflowing frame itself has already lost its text.
+    pub fn ensure_glyph_capacity(&mut self, device: &wgpu::Device) {
+        if self.last_glyph_count <= self.glyph_capacity {
+            return;
+        }
+        while self.glyph_capacity < self.last_glyph_count {
+            self.glyph_capacity *= 2;
+        }
+        tracing::warn!("Growing the text buffer to {} characters", self.glyph_capacity);
+        self.text_renderer = TextRenderer::new(device, self.glyph_capacity);
+        self.glyph_warning_logged = false;
     }
 
     // ─── Image Sprite Rendering ─────────────────────────────────────────────
@@ -360,40 +513,6 @@ impl GameRenderer {
         }
     }
 
-    /// Draw the cult_papa face at a grid position with a given size in grid cells.
-    /// `size_cells` is how many grid cells wide/tall the image should be.
-    pub fn draw_cult_papa_face(
-        &mut self,
-        col: f32,
-        row: f32,
-        size_cells: f32,
-        tint: [f32; 4],
-    ) {
-        let (left, _, top, _) = self.camera.visible_bounds();
-        let pixel_size = size_cells * self.char_width();
-        // Position is the center of the sprite
-        let x = left + col * self.char_width() + pixel_size * 0.5;
-        let y = top + row * self.char_height() + pixel_size * 0.5;
-
-        if let Some(ref mut face) = self.cult_papa_face {
-            let params = SpriteParams::new()
-                .with_color(tint[0], tint[1], tint[2], tint[3]);
-            face.batch.draw([x, y], [pixel_size, pixel_size], &params);
-        }
-    }
-
-    /// Draw the cult_papa face centered at a grid row, with a given size in cells.
-    pub fn draw_cult_papa_face_centered(
-        &mut self,
-        row: f32,
-        size_cells: f32,
-        tint: [f32; 4],
-    ) {
-        let cols = self.screen_cols();
-        let col = (cols - size_cells) / 2.0;
-        self.draw_cult_papa_face(col, row, size_cells, tint);
-    }
-
     /// End image sprite drawing. Returns the sprite count for rendering.
     pub fn end_images(&mut self, queue: &wgpu::Queue) -> u32 {
         if let Some(ref mut face) = self.cult_papa_face {
@@ -422,3 +541,66 @@ impl GameRenderer {
         self.cult_papa_face.is_some()
     }
 }
+
+impl DrawSurface for GameRenderer {
+    fn draw_at_grid(&mut self, text: &str, col: f32, row: f32, color: [f32; 4]) {
+        // The builtin font only has ASCII glyphs
+        let text = &glyphs::sanitize(text);
+        let (left, _, top, _) = self.camera.visible_bounds();
+        let x = left + col * self.char_width();
+        let y = top + row * self.char_height();
+        let style = TextStyle::new()
+            .with_scale(self.scale)
+            .with_color(color[0], color[1], color[2], color[3]);
+        self.draw_text(text, [x, y], &style);
+        self.record(text, col, row);
+    }
+
+    fn draw_centered(&mut self, text: &str, row: f32, color: [f32; 4]) {
+        let text = &glyphs::sanitize(text);
+        let (_, _, top, _) = self.camera.visible_bounds();
+        let y = top + row * self.char_height();
+        let style = TextStyle::new()
+            .with_scale(self.scale)
+            .with_color(color[0], color[1], color[2], color[3])
+            .with_align(TextAlign::Center);
+        self.draw_text(text, [0.0, y], &style);
+        if self.capture.is_some() {
+            let col = (self.screen_cols() - text.chars().count() as f32) / 2.0;
+            self.record(text, col, row);
+        }
+    }
+
+    fn screen_cols(&self) -> f32 {
+        let (left, right, _, _) = self.camera.visible_bounds();
+        (right - left) / self.char_width()
+    }
+
+    fn screen_rows(&self) -> f32 {
+        let (_, _, top, bottom) = self.camera.visible_bounds();
+        (bottom - top) / self.char_height()
+    }
+
+    fn reduce_motion(&self) -> bool {
+        self.reduce_motion
+    }
+
+    fn set_reduce_motion(&mut self, on: bool) {
+        self.reduce_motion = on;
+    }
+
+    /// `size_cells` is how many grid cells wide/tall the image should be.
+    fn draw_cult_papa_face(&mut self, col: f32, row: f32, size_cells: f32, tint: [f32; 4]) {
+        let (left, _, top, _) = self.camera.visible_bounds();
+        let pixel_size = size_cells * self.char_width();
+        // Position is the center of the sprite
+        let x = left + col * self.char_width() + pixel_size * 0.5;
+        let y = top + row * self.char_height() + pixel_size * 0.5;
+
+        if let Some(ref mut face) = self.cult_papa_face {
+            let params = SpriteParams::new()
+                .with_color(tint[0], tint[1], tint[2], tint[3]);
+            face.batch.draw([x, y], [pixel_size, pixel_size], &params);
+        }
+    }
+}
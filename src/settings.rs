@@ -0,0 +1,193 @@
+//! Player settings persisted to `settings.json` next to the save.
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::data::{GameMode, save};
+
+/// Fastest typewriter speed selectable in-game, in characters per second.
+pub const MAX_TEXT_SPEED: f32 = 120.0;
+/// Typewriter speed change per `+`/`-` press.
+pub const TEXT_SPEED_STEP: f32 = 10.0;
+
+/// Strongest screen shake the settings file may ask for.
+const MAX_SCREEN_SHAKE: f32 = 2.0;
+
+/// Text scales selectable in-game with F10, smallest first.
+pub const UI_SCALE_STEPS: [f32; 4] = [1.5, 2.0, 2.5, 3.0];
+
+/// Window size used when the settings file doesn't pick one.
+const DEFAULT_WINDOW_WIDTH: u32 = 1024;
+const DEFAULT_WINDOW_HEIGHT: u32 = 768;
+/// Smallest window size accepted from the settings file.
+const MIN_WINDOW_WIDTH: u32 = 320;
+const MIN_WINDOW_HEIGHT: u32 = 240;
+
+/// Look of the fishing minigame's tug meter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MeterPreset {
+    /// `[---===+===---]` with a `|` cursor.
+    #[default]
+    Classic,
+    /// Solid block characters.
+    Blocks,
+    /// Just the zone and the cursor.
+    Minimal,
+}
+
+/// User-adjustable game settings.
+///
+/// Missing fields fall back to their defaults, so older settings files
+/// keep working as new options are added.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Dialogue typewriter speed in characters per second. 0 shows text instantly.
+    pub text_speed: f32,
+    /// Typewrite choice prompts too, and hold choice navigation until they finish.
+    pub choices_wait_for_text: bool,
+    /// Window size at launch, in physical pixels.
+    pub window_width: u32,
+    pub window_height: u32,
+    /// Start in borderless fullscreen. Toggled in-game with F11.
+    pub fullscreen: bool,
+    /// Text scale relative to the 8x8 font. Cycled in-game with F10.
+    pub ui_scale: f32,
+    /// Master volume for music and sound effects, 0.0 to 1.0. Stepped
+    /// in-game with `[` and `]`.
+    #[serde(alias = "volume")]
+    pub master_volume: f32,
+    /// Silence all audio without losing the volume. Toggled in-game with M.
+    pub muted: bool,
+    /// Seconds between autosaves of unsaved progress. 0 turns autosave off.
+    pub autosave_interval: f32,
+    /// Strength of screen shake, 0.0 (none) to 2.0.
+    pub screen_shake: f32,
+    /// Hold animations still for players sensitive to motion: no screen
+    /// shake, pulsing, flashing or particles, whatever `screen_shake` says.
+    pub reduce_motion: bool,
+    /// Step the fishing minigame physics at a fixed rate, so the fight feels
+    /// the same at any frame rate.
+    pub fixed_timestep: bool,
+    /// Reel without quick-time prompts, for players who want the plain
+    /// tug-of-war.
+    pub classic_minigame: bool,
+    /// Ease up fish that keep getting away. Unset follows the game mode:
+    /// on except in Hardcore.
+    pub fishing_assist: Option<bool>,
+    /// How the tug meter is drawn: "classic", "blocks" or "minimal".
+    pub meter_style: MeterPreset,
+    /// Keep recent log events in memory for the backtick debug console.
+    /// Takes effect on the next launch.
+    pub debug_console: bool,
+    /// Keep a marker of the date in progress so it can be resumed after a
+    /// crash.
+    pub resume_dates: bool,
+    /// Language code for `locale_<language>.json`. Unset follows `LANG`.
+    pub language: Option<String>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            text_speed: 30.0,
+            choices_wait_for_text: false,
+            window_width: DEFAULT_WINDOW_WIDTH,
+            window_height: DEFAULT_WINDOW_HEIGHT,
+            fullscreen: false,
+            ui_scale: 2.0,
+            master_volume: 0.8,
+            muted: false,
+            autosave_interval: 60.0,
+            screen_shake: 1.0,
+            reduce_motion: false,
+            fixed_timestep: true,
+            classic_minigame: false,
+            fishing_assist: None,
+            meter_style: MeterPreset::Classic,
+            debug_console: false,
+            resume_dates: true,
+            language: None,
+        }
+    }
+}
+
+fn settings_path() -> PathBuf {
+    save::data_dir().join("settings.json")
+}
+
+impl Settings {
+    /// Load settings from disk, falling back to defaults if the file is
+    /// missing or invalid.
+    pub fn load() -> Self {
+        let path = settings_path();
+        if !path.exists() {
+            return Self::default();
+        }
+
+        match std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|json| serde_json::from_str(&json).map_err(|e| e.to_string()))
+        {
+            Ok(settings) => settings,
+            Err(e) => {
+                tracing::warn!("Invalid settings in {} ({}), using defaults", path.display(), e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Save settings to disk.
+    pub fn save(&self) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(settings_path(), json).map_err(|e| e.to_string())
+    }
+
+    /// Launch window size, clamped to something usable.
+    pub fn window_size(&self) -> (u32, u32) {
+        (
+            self.window_width.max(MIN_WINDOW_WIDTH),
+            self.window_height.max(MIN_WINDOW_HEIGHT),
+        )
+    }
+
+    /// Text scale, clamped to the selectable range.
+    pub fn ui_scale(&self) -> f32 {
+        self.ui_scale.clamp(UI_SCALE_STEPS[0], UI_SCALE_STEPS[UI_SCALE_STEPS.len() - 1])
+    }
+
+    /// Step to the next larger text scale, wrapping back to the smallest.
+    pub fn cycle_ui_scale(&mut self) {
+        self.ui_scale = UI_SCALE_STEPS
+            .iter()
+            .copied()
+            .find(|&step| step > self.ui_scale() + f32::EPSILON)
+            .unwrap_or(UI_SCALE_STEPS[0]);
+    }
+
+    /// Multiplier for screen shake, 0 when reduce motion is on.
+    pub fn shake_scale(&self) -> f32 {
+        if self.reduce_motion {
+            0.0
+        } else {
+            self.screen_shake.clamp(0.0, MAX_SCREEN_SHAKE)
+        }
+    }
+
+    /// Whether the fishing assist is on for a save in `mode`.
+    pub fn fishing_assist(&self, mode: GameMode) -> bool {
+        self.fishing_assist.unwrap_or_else(|| mode.assist_by_default())
+    }
+
+    /// Whether dialogue text should appear all at once.
+    pub fn instant_text(&self) -> bool {
+        self.text_speed <= 0.0
+    }
+
+    /// Nudge the typewriter speed by `steps` increments, clamped to the valid range.
+    pub fn adjust_text_speed(&mut self, steps: f32) {
+        self.text_speed = (self.text_speed + steps * TEXT_SPEED_STEP).clamp(0.0, MAX_TEXT_SPEED);
+    }
+}
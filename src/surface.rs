@@ -0,0 +1,116 @@
+//! Character-grid drawing surface shared by the GPU and terminal renderers.
+//!
+//! Game code draws through `DrawSurface` only, so the same screens render
+//! to the window (`GameRenderer`) or a terminal (`TerminalRenderer`).
+
+/// Column that centers a block `width` columns wide on a screen `cols` wide,
+/// never left of the screen edge.
+pub fn centered_start_col(cols: f32, width: f32) -> f32 {
+    ((cols - width) / 2.0).max(0.0)
+}
+
+/// Something that can draw colored text on a grid of character cells.
+pub trait DrawSurface {
+    /// Draw text at a grid position (column, row) from top-left of screen.
+    fn draw_at_grid(&mut self, text: &str, col: f32, row: f32, color: [f32; 4]);
+
+    /// Draw centered text at a given row.
+    fn draw_centered(&mut self, text: &str, row: f32, color: [f32; 4]);
+
+    /// Get the number of columns visible on screen.
+    fn screen_cols(&self) -> f32;
+
+    /// Get the number of rows visible on screen.
+    fn screen_rows(&self) -> f32;
+
+    /// Whether animations should hold still, for players sensitive to motion
+    /// and flashing. See `ui::wave`.
+    fn reduce_motion(&self) -> bool {
+        false
+    }
+
+    /// Turn reduced motion on or off. Surfaces that always animate ignore it.
+    fn set_reduce_motion(&mut self, _on: bool) {}
+
+    /// Draw the cult_papa face image. Surfaces without images skip it.
+    fn draw_cult_papa_face(&mut self, _col: f32, _row: f32, _size_cells: f32, _tint: [f32; 4]) {}
+
+    /// Draw multi-line text at a grid position.
+    fn draw_multiline_at_grid(&mut self, text: &str, col: f32, row: f32, color: [f32; 4]) {
+        for (i, line) in text.lines().enumerate() {
+            self.draw_at_grid(line, col, row + i as f32, color);
+        }
+    }
+
+    /// Draw multi-line centered text.
+    ///
+    /// Centers the *block* as a whole based on the widest line, then draws
+    /// every line from the same starting column so internal ASCII-art
+    /// alignment is preserved.
+    ///
+    /// Art wider than the screen starts at the left edge and is clipped at
+    /// the right edge.
+    fn draw_multiline_centered(&mut self, text: &str, start_row: f32, color: [f32; 4]) {
+        let max_width = text.lines().map(|l| l.chars().count()).max().unwrap_or(0) as f32;
+        let cols = self.screen_cols();
+        let start_col = centered_start_col(cols, max_width);
+        let visible = (cols - start_col).max(0.0) as usize;
+        for (i, line) in text.lines().enumerate() {
+            let clipped = match line.char_indices().nth(visible) {
+                Some((end, _)) => &line[..end],
+                None => line,
+            };
+            self.draw_at_grid(clipped, start_col, start_row + i as f32, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A surface `cols` wide that records where text lands.
+    struct Recorder {
+        cols: f32,
+        drawn: Vec<(String, f32)>,
+    }
+
+    impl DrawSurface for Recorder {
+        fn draw_at_grid(&mut self, text: &str, col: f32, _row: f32, _color: [f32; 4]) {
+            self.drawn.push((text.to_string(), col));
+        }
+
+        fn draw_centered(&mut self, text: &str, _row: f32, color: [f32; 4]) {
+            let col = centered_start_col(self.cols, text.chars().count() as f32);
+            self.draw_at_grid(text, col, 0.0, color);
+        }
+
+        fn screen_cols(&self) -> f32 {
+            self.cols
+        }
+
+        fn screen_rows(&self) -> f32 {
+            30.0
+        }
+    }
+
+    #[test]
+    fn start_column_is_never_negative() {
+        for cols in 0..40 {
+            for width in 0..80 {
+                assert!(centered_start_col(cols as f32, width as f32) >= 0.0);
+            }
+        }
+        assert_eq!(centered_start_col(80.0, 20.0), 30.0);
+    }
+
+    #[test]
+    fn wide_art_is_clipped_on_a_narrow_screen() {
+        let mut surface = Recorder { cols: 10.0, drawn: Vec::new() };
+        surface.draw_multiline_centered("a wide line of ascii art\nshort", 0.0, [1.0; 4]);
+        for (text, col) in &surface.drawn {
+            assert_eq!(*col, 0.0);
+            assert!(text.chars().count() <= 10);
+        }
+    }
+}
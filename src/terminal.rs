@@ -0,0 +1,307 @@
+//! ANSI terminal frontend, selected with `--terminal`.
+//!
+//! Runs the same `Game` as the window, but draws the character grid with
+//! crossterm and reads keys from the terminal instead of winit.
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use crossterm::event::{
+    self, Event, KeyCode as TermKey, KeyEvent, KeyEventKind, KeyModifiers,
+    KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+};
+use crossterm::style::{Color, Print, ResetColor, SetForegroundColor};
+use crossterm::{cursor, execute, queue, terminal};
+use winit::keyboard::KeyCode;
+
+use crate::game::Game;
+use crate::input::{key_for_char, InputState};
+use crate::surface::DrawSurface;
+
+/// Target time between frames (~30 FPS).
+const FRAME_TIME: Duration = Duration::from_millis(33);
+
+/// How long a key counts as held after its last press when the terminal
+/// can't report key releases. Key repeat keeps refreshing it.
+const HOLD_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Smallest terminal the screens are laid out for (the window's grid at
+/// the default scale).
+const MIN_COLS: u16 = 64;
+const MIN_ROWS: u16 = 48;
+
+/// One character cell of the terminal grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cell {
+    ch: char,
+    color: [u8; 3],
+}
+
+const BLANK: Cell = Cell {
+    ch: ' ',
+    color: [0, 0, 0],
+};
+
+/// Draws the game's character grid to the terminal.
+///
+/// Frames are drawn into a back buffer and only cells that changed since
+/// the last frame are written out, which keeps the terminal from flickering.
+pub struct TerminalRenderer {
+    cols: u16,
+    rows: u16,
+    /// Frame being drawn.
+    back: Vec<Cell>,
+    /// What the terminal is currently showing. Empty forces a full redraw.
+    front: Vec<Cell>,
+    /// Hold animations still; set from the settings each frame.
+    reduce_motion: bool,
+}
+
+impl TerminalRenderer {
+    pub fn new() -> Self {
+        let (cols, rows) = terminal::size().unwrap_or((80, 24));
+        let mut renderer = Self {
+            cols: 0,
+            rows: 0,
+            back: Vec::new(),
+            front: Vec::new(),
+            reduce_motion: false,
+        };
+        renderer.resize(cols, rows);
+        renderer
+    }
+
+    /// Match a new terminal size. The next frame redraws everything.
+    pub fn resize(&mut self, cols: u16, rows: u16) {
+        self.cols = cols;
+        self.rows = rows;
+        self.back = vec![BLANK; cols as usize * rows as usize];
+        self.front.clear();
+    }
+
+    /// Start a new frame.
+    pub fn begin(&mut self) {
+        self.back.fill(BLANK);
+        if self.cols < MIN_COLS || self.rows < MIN_ROWS {
+            let warning = format!(
+                "Terminal is {}x{}, the game needs {}x{}",
+                self.cols, self.rows, MIN_COLS, MIN_ROWS
+            );
+            self.put(&warning, 0.0, 0.0, [1.0, 0.3, 0.3, 1.0]);
+        }
+    }
+
+    /// Write the changed cells of the frame to the terminal.
+    pub fn present(&mut self, out: &mut impl Write) -> io::Result<()> {
+        let full = self.front.len() != self.back.len();
+        if full {
+            queue!(out, terminal::Clear(terminal::ClearType::All))?;
+        }
+
+        let mut color = None;
+        let mut next_pos = None;
+        for (i, cell) in self.back.iter().enumerate() {
+            if !full && self.front[i] == *cell {
+                continue;
+            }
+            if next_pos != Some(i) {
+                let col = (i % self.cols as usize) as u16;
+                let row = (i / self.cols as usize) as u16;
+                queue!(out, cursor::MoveTo(col, row))?;
+            }
+            if cell.ch != ' ' && color != Some(cell.color) {
+                let [r, g, b] = cell.color;
+                queue!(out, SetForegroundColor(Color::Rgb { r, g, b }))?;
+                color = Some(cell.color);
+            }
+            queue!(out, Print(cell.ch))?;
+            next_pos = Some(i + 1);
+        }
+        out.flush()?;
+
+        self.front.clone_from(&self.back);
+        Ok(())
+    }
+
+    /// Copy text into the back buffer, clipped to the screen.
+    fn put(&mut self, text: &str, col: f32, row: f32, color: [f32; 4]) {
+        let row = row.round();
+        if row < 0.0 || row >= self.rows as f32 {
+            return;
+        }
+        // No transparency in a terminal, so fade toward the black background
+        let rgb = [0, 1, 2].map(|c| ((color[c] * color[3]).clamp(0.0, 1.0) * 255.0) as u8);
+        let start = col.round() as isize;
+        let row_start = row as usize * self.cols as usize;
+        for (i, ch) in text.chars().enumerate() {
+            let x = start + i as isize;
+            if x < 0 || x >= self.cols as isize {
+                continue;
+            }
+            self.back[row_start + x as usize] = if ch == ' ' {
+                BLANK
+            } else {
+                Cell { ch, color: rgb }
+            };
+        }
+    }
+}
+
+impl DrawSurface for TerminalRenderer {
+    fn draw_at_grid(&mut self, text: &str, col: f32, row: f32, color: [f32; 4]) {
+        self.put(text, col, row, color);
+    }
+
+    fn draw_centered(&mut self, text: &str, row: f32, color: [f32; 4]) {
+        let col = (self.screen_cols() - text.chars().count() as f32) / 2.0;
+        self.put(text, col, row, color);
+    }
+
+    fn screen_cols(&self) -> f32 {
+        self.cols as f32
+    }
+
+    fn screen_rows(&self) -> f32 {
+        self.rows as f32
+    }
+
+    fn reduce_motion(&self) -> bool {
+        self.reduce_motion
+    }
+
+    fn set_reduce_motion(&mut self, on: bool) {
+        self.reduce_motion = on;
+    }
+}
+
+/// Map a terminal key to the window key code the game understands.
+fn map_key(code: TermKey) -> Option<KeyCode> {
+    let key = match code {
+        TermKey::Enter => KeyCode::Enter,
+        TermKey::Esc => KeyCode::Escape,
+        TermKey::Backspace => KeyCode::Backspace,
+        TermKey::Tab => KeyCode::Tab,
+        TermKey::Up => KeyCode::ArrowUp,
+        TermKey::Down => KeyCode::ArrowDown,
+        TermKey::Left => KeyCode::ArrowLeft,
+        TermKey::Right => KeyCode::ArrowRight,
+        TermKey::F(n) => match n {
+            1 => KeyCode::F1,
+            2 => KeyCode::F2,
+            3 => KeyCode::F3,
+            4 => KeyCode::F4,
+            5 => KeyCode::F5,
+            6 => KeyCode::F6,
+            7 => KeyCode::F7,
+            8 => KeyCode::F8,
+            9 => KeyCode::F9,
+            10 => KeyCode::F10,
+            11 => KeyCode::F11,
+            12 => KeyCode::F12,
+            _ => return None,
+        },
+        TermKey::Char(c) => return key_for_char(c),
+        _ => return None,
+    };
+    Some(key)
+}
+
+/// Run the game in the terminal until the player quits.
+///
+/// The terminal is put back the way it was even if the game loop fails.
+pub fn run(mut game: Game) -> io::Result<()> {
+    let mut out = io::stdout();
+    terminal::enable_raw_mode()?;
+    execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+
+    // Terminals that report key releases let the minigame see real holds
+    let reports_release = terminal::supports_keyboard_enhancement().unwrap_or(false);
+    if reports_release {
+        execute!(
+            out,
+            PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::REPORT_EVENT_TYPES)
+        )?;
+    }
+
+    let result = run_loop(&mut game, &mut out, reports_release);
+
+    if reports_release {
+        let _ = execute!(out, PopKeyboardEnhancementFlags);
+    }
+    let _ = execute!(out, ResetColor, cursor::Show, terminal::LeaveAlternateScreen);
+    let _ = terminal::disable_raw_mode();
+    result
+}
+
+fn run_loop(game: &mut Game, out: &mut impl Write, reports_release: bool) -> io::Result<()> {
+    let mut renderer = TerminalRenderer::new();
+    let mut input = InputState::new();
+    // Last press of each held key, for releasing them after HOLD_TIMEOUT
+    let mut pressed_at: HashMap<KeyCode, Instant> = HashMap::new();
+    let mut last_frame = Instant::now();
+
+    while !game.quit_requested() {
+        let deadline = last_frame + FRAME_TIME;
+        while event::poll(deadline.saturating_duration_since(Instant::now()))? {
+            match event::read()? {
+                Event::Key(KeyEvent {
+                    code: TermKey::Char('c'),
+                    modifiers,
+                    kind: KeyEventKind::Press,
+                    ..
+                }) if modifiers.contains(KeyModifiers::CONTROL) => {
+                    // Raw mode swallows the signal, so treat Ctrl+C like closing the window
+                    game.save_on_exit();
+                    return Ok(());
+                }
+                Event::Key(key) => {
+                    if let (TermKey::Char(c), KeyEventKind::Press) = (key.code, key.kind) {
+                        input.type_char(c);
+                    }
+                    let Some(code) = map_key(key.code) else { continue };
+                    match key.kind {
+                        KeyEventKind::Press => {
+                            input.press(code);
+                            pressed_at.insert(code, Instant::now());
+                        }
+                        KeyEventKind::Repeat => {
+                            input.repeat(code);
+                            pressed_at.insert(code, Instant::now());
+                        }
+                        KeyEventKind::Release => {
+                            input.release(code);
+                            pressed_at.remove(&code);
+                        }
+                    }
+                }
+                Event::Resize(cols, rows) => renderer.resize(cols, rows),
+                Event::FocusLost => input.clear_held(),
+                _ => {}
+            }
+        }
+
+        if !reports_release {
+            pressed_at.retain(|&code, at| {
+                let held = at.elapsed() < HOLD_TIMEOUT;
+                if !held {
+                    input.release(code);
+                }
+                held
+            });
+        }
+
+        let now = Instant::now();
+        let dt = (now - last_frame).as_secs_f32().min(0.1);
+        last_frame = now;
+
+        game.update(dt, &input);
+        input.end_frame();
+
+        renderer.begin();
+        game.render(&mut renderer);
+        renderer.present(out)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+//! User-editable color theme.
+//!
+//! Every color the game draws with has a name and a built-in default. A
+//! `theme.json` file mapping names to RGBA arrays (each channel 0-1)
+//! overrides any of them, so the whole game can be recolored without
+//! recompiling. Entries that are missing or malformed keep their default.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use crate::data::save;
+
+/// The active theme, set once at startup.
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+/// Name of the theme file, looked for in the working directory and then the
+/// data directory.
+const THEME_FILE: &str = "theme.json";
+
+/// Every themeable color and its built-in value.
+const DEFAULT_COLORS: &[(&str, [f32; 4])] = &[
+    ("background", [0.05, 0.05, 0.1, 1.0]),
+    ("white", [1.0, 1.0, 1.0, 1.0]),
+    ("yellow", [1.0, 1.0, 0.0, 1.0]),
+    ("cyan", [0.0, 1.0, 1.0, 1.0]),
+    ("green", [0.2, 1.0, 0.2, 1.0]),
+    ("red", [1.0, 0.3, 0.3, 1.0]),
+    ("orange", [1.0, 0.6, 0.1, 1.0]),
+    ("blue", [0.3, 0.5, 1.0, 1.0]),
+    ("pink", [1.0, 0.5, 0.7, 1.0]),
+    ("gray", [0.5, 0.5, 0.5, 1.0]),
+    ("dark_gray", [0.3, 0.3, 0.3, 1.0]),
+    ("light_blue", [0.5, 0.7, 1.0, 1.0]),
+    ("purple", [0.7, 0.3, 1.0, 1.0]),
+    ("menu_text", [1.0, 1.0, 1.0, 1.0]),
+    ("menu_highlight", [1.0, 1.0, 0.0, 1.0]),
+    ("heart", [1.0, 0.3, 0.3, 1.0]),
+    ("heart_empty", [0.3, 0.3, 0.3, 1.0]),
+    ("tension_low", [0.2, 1.0, 0.2, 1.0]),
+    ("tension_moderate", [1.0, 1.0, 0.0, 1.0]),
+    ("tension_high", [1.0, 0.6, 0.1, 1.0]),
+    ("tension_extreme", [1.0, 0.1, 0.1, 1.0]),
+];
+
+/// Named colors, starting from the built-in defaults.
+#[derive(Debug, Clone)]
+pub struct Theme {
+    colors: HashMap<&'static str, [f32; 4]>,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            colors: DEFAULT_COLORS.iter().copied().collect(),
+        }
+    }
+}
+
+impl Theme {
+    /// Load `theme.json` over the defaults. Unknown names and entries that
+    /// aren't four numbers from 0 to 1 are logged and skipped.
+    pub fn load() -> Result<Self, String> {
+        let path = [PathBuf::from(THEME_FILE), save::data_dir().join(THEME_FILE)]
+            .into_iter()
+            .find(|path| path.exists())
+            .ok_or_else(|| format!("{} not found", THEME_FILE))?;
+
+        let json = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+        let entries: HashMap<String, serde_json::Value> =
+            serde_json::from_str(&json).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        let mut theme = Self::default();
+        let mut applied = 0;
+        for (name, value) in &entries {
+            let Some((key, _)) = DEFAULT_COLORS.iter().find(|(key, _)| key == name) else {
+                tracing::warn!("Unknown theme color '{}' in {}", name, path.display());
+                continue;
+            };
+            match parse_color(value) {
+                Some(color) => {
+                    theme.colors.insert(key, color);
+                    applied += 1;
+                }
+                None => tracing::warn!(
+                    "Theme color '{}' in {} should be four numbers from 0 to 1, keeping the default",
+                    name,
+                    path.display()
+                ),
+            }
+        }
+        tracing::info!("Loaded {} theme colors from {}", applied, path.display());
+        Ok(theme)
+    }
+
+    /// The color called `name`. Unknown names come back white.
+    pub fn color(&self, name: &str) -> [f32; 4] {
+        self.colors.get(name).copied().unwrap_or([1.0; 4])
+    }
+}
+
+/// An `[r, g, b, a]` array with every channel in 0-1.
+fn parse_color(value: &serde_json::Value) -> Option<[f32; 4]> {
+    let channels = value.as_array()?;
+    if channels.len() != 4 {
+        return None;
+    }
+    let mut color = [0.0; 4];
+    for (slot, channel) in color.iter_mut().zip(channels) {
+        let c = channel.as_f64()?;
+        if !(0.0..=1.0).contains(&c) {
+            return None;
+        }
+        *slot = c as f32;
+    }
+    Some(color)
+}
+
+/// Load the theme file if there is one. Stays on the built-in colors
+/// otherwise.
+pub fn init() {
+    let theme = Theme::load().unwrap_or_else(|e| {
+        tracing::info!("Using the built-in theme ({})", e);
+        Theme::default()
+    });
+    if THEME.set(theme).is_err() {
+        tracing::warn!("Theme already initialized");
+    }
+}
+
+/// The active theme, or the built-in one before `init`.
+pub fn current() -> &'static Theme {
+    THEME.get_or_init(Theme::default)
+}
+
+/// The active theme's color called `name`.
+pub fn color(name: &str) -> [f32; 4] {
+    current().color(name)
+}
@@ -0,0 +1,193 @@
+//! Inline color markup for dialogue text.
+//!
+//! `{red}` switches to a color, `{/}` switches back to the default. Braces
+//! that don't form a known tag are shown as-is.
+
+use crate::render::Colors;
+use crate::surface::DrawSurface;
+
+/// Look up the color for a markup tag name.
+pub fn tag_color(name: &str) -> Option<[f32; 4]> {
+    let color = match name {
+        "white" => Colors::white(),
+        "yellow" => Colors::yellow(),
+        "cyan" => Colors::cyan(),
+        "green" => Colors::green(),
+        "red" => Colors::red(),
+        "orange" => Colors::orange(),
+        "blue" => Colors::blue(),
+        "pink" => Colors::pink(),
+        "gray" | "grey" => Colors::gray(),
+        "lightblue" => Colors::light_blue(),
+        "purple" => Colors::purple(),
+        _ => return None,
+    };
+    Some(color)
+}
+
+/// A recognized tag at the start of `text`: its byte length and the color it
+/// switches to (`None` for the `{/}` reset).
+fn tag_at(text: &str) -> Option<(usize, Option<[f32; 4]>)> {
+    let rest = text.strip_prefix('{')?;
+    let end = rest.find('}')?;
+    let name = &rest[..end];
+    let color = if name == "/" { None } else { Some(tag_color(name)?) };
+    Some((end + 2, color))
+}
+
+/// Number of characters `text` shows on screen, not counting tags.
+pub fn visible_len(text: &str) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < text.len() {
+        if let Some((tag_len, _)) = tag_at(&text[i..]) {
+            i += tag_len;
+            continue;
+        }
+        let ch = text[i..].chars().next().unwrap_or_default();
+        i += ch.len_utf8();
+        len += 1;
+    }
+    len
+}
+
+/// The start of `text` up to `count` visible characters, keeping any tags
+/// along the way so partially typed text stays colored.
+pub fn visible_prefix(text: &str, count: usize) -> &str {
+    let mut shown = 0;
+    let mut i = 0;
+    while i < text.len() {
+        if let Some((tag_len, _)) = tag_at(&text[i..]) {
+            i += tag_len;
+            continue;
+        }
+        if shown == count {
+            break;
+        }
+        let ch = text[i..].chars().next().unwrap_or_default();
+        i += ch.len_utf8();
+        shown += 1;
+    }
+    &text[..i]
+}
+
+/// Simple word wrapping. Widths count visible characters, so color tags
+/// don't take up room. Words wider than a line are broken across lines.
+pub fn word_wrap(text: &str, max_width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current_line = String::new();
+
+    for mut word in text.split_whitespace() {
+        if !current_line.is_empty() {
+            if visible_len(&current_line) + 1 + visible_len(word) <= max_width {
+                current_line.push(' ');
+                current_line.push_str(word);
+                continue;
+            }
+            lines.push(std::mem::take(&mut current_line));
+        }
+
+        while max_width > 0 && visible_len(word) > max_width {
+            let head = visible_prefix(word, max_width);
+            lines.push(head.to_string());
+            word = &word[head.len()..];
+        }
+        current_line = word.to_string();
+    }
+
+    if !current_line.is_empty() {
+        lines.push(current_line);
+    }
+
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+
+    lines
+}
+
+/// Split `text` into runs of same-colored text, starting in `color`.
+///
+/// Returns the runs and the color still active at the end, so a tag can
+/// carry over onto the next wrapped line.
+pub fn parse_runs(
+    text: &str,
+    mut color: [f32; 4],
+    default: [f32; 4],
+) -> (Vec<(String, [f32; 4])>, [f32; 4]) {
+    let mut runs: Vec<(String, [f32; 4])> = Vec::new();
+    let mut current = String::new();
+    let mut i = 0;
+    while i < text.len() {
+        if let Some((tag_len, tag)) = tag_at(&text[i..]) {
+            if !current.is_empty() {
+                runs.push((std::mem::take(&mut current), color));
+            }
+            color = tag.unwrap_or(default);
+            i += tag_len;
+            continue;
+        }
+        let ch = text[i..].chars().next().unwrap_or_default();
+        current.push(ch);
+        i += ch.len_utf8();
+    }
+    if !current.is_empty() {
+        runs.push((current, color));
+    }
+    (runs, color)
+}
+
+/// Draw wrapped lines of marked-up text, one per row, carrying the active
+/// color from line to line.
+pub fn draw_lines(
+    renderer: &mut dyn DrawSurface,
+    lines: &[String],
+    col: f32,
+    row: f32,
+    default: [f32; 4],
+) {
+    let mut color = default;
+    for (i, line) in lines.iter().enumerate() {
+        let (runs, end_color) = parse_runs(line, color, default);
+        let mut x = col;
+        for (text, run_color) in &runs {
+            renderer.draw_at_grid(text, x, row + i as f32, *run_color);
+            x += text.chars().count() as f32;
+        }
+        color = end_color;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typewriter_steps_through_multibyte_text() {
+        let text = "Caf\u{e9} \u{2014} {pink}d\u{e9}j\u{e0}{/} vu";
+        let len = visible_len(text);
+        assert_eq!(len, 14);
+        for count in 0..=len {
+            let shown = visible_prefix(text, count);
+            assert_eq!(visible_len(shown), count);
+        }
+        assert_eq!(visible_prefix(text, 4), "Caf\u{e9}");
+        assert_eq!(visible_prefix(text, len), text);
+    }
+
+    #[test]
+    fn long_word_breaks_across_lines() {
+        let word = "a".repeat(100);
+        let lines = word_wrap(&word, 40);
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].len(), 40);
+        assert_eq!(lines[1].len(), 40);
+        assert_eq!(lines[2].len(), 20);
+    }
+
+    #[test]
+    fn wrap_ignores_tags_when_measuring() {
+        let lines = word_wrap("{red}hello{/} there", 11);
+        assert_eq!(lines, vec!["{red}hello{/} there".to_string()]);
+    }
+}
@@ -1,11 +1,21 @@
 //! Menu selection components.
 
-use crate::render::{Colors, GameRenderer};
+use std::cell::Cell;
+
+use winit::keyboard::KeyCode;
+
+use crate::i18n;
+use crate::render::Colors;
+use crate::surface::DrawSurface;
 
 /// A simple selectable menu.
+///
+/// Items are English keys, translated when drawn, so callers can match on them.
 pub struct SelectionMenu {
     pub items: Vec<String>,
     pub selected: usize,
+    /// Grid row of the first item when last drawn, for mouse hit testing.
+    drawn_at: Cell<Option<f32>>,
 }
 
 impl SelectionMenu {
@@ -13,7 +23,23 @@ impl SelectionMenu {
         Self {
             items,
             selected: 0,
+            drawn_at: Cell::new(None),
+        }
+    }
+
+    /// Remember where the menu was drawn, for menus that draw their own items.
+    pub fn mark_drawn_at(&self, start_row: f32) {
+        self.drawn_at.set(Some(start_row));
+    }
+
+    /// Index of the item on grid row `row`, if the menu has been drawn there.
+    pub fn hit_test(&self, row: f32) -> Option<usize> {
+        let offset = row - self.drawn_at.get()?;
+        if offset < 0.0 {
+            return None;
         }
+        let index = offset as usize;
+        (index < self.items.len()).then_some(index)
     }
 
     pub fn move_up(&mut self) {
@@ -28,34 +54,63 @@ impl SelectionMenu {
         }
     }
 
+    /// Select an item by index, clamped to the menu length.
+    pub fn select(&mut self, index: usize) {
+        self.selected = index.min(self.items.len().saturating_sub(1));
+    }
+
+    /// Select the item for a number key (`1` is the first item). Returns
+    /// whether the key picked an item; digits past the end are ignored.
+    pub fn quick_select(&mut self, key: KeyCode) -> bool {
+        let index = match key {
+            KeyCode::Digit1 => 0,
+            KeyCode::Digit2 => 1,
+            KeyCode::Digit3 => 2,
+            KeyCode::Digit4 => 3,
+            KeyCode::Digit5 => 4,
+            KeyCode::Digit6 => 5,
+            KeyCode::Digit7 => 6,
+            KeyCode::Digit8 => 7,
+            KeyCode::Digit9 => 8,
+            _ => return false,
+        };
+        if index >= self.items.len() {
+            return false;
+        }
+        self.selected = index;
+        true
+    }
+
     pub fn selected_index(&self) -> usize {
         self.selected
     }
 
-    pub fn draw(&self, renderer: &mut GameRenderer, col: f32, start_row: f32) {
+    pub fn draw(&self, renderer: &mut dyn DrawSurface, col: f32, start_row: f32) {
+        self.mark_drawn_at(start_row);
         for (i, item) in self.items.iter().enumerate() {
             let is_selected = i == self.selected;
             let prefix = if is_selected { "> " } else { "  " };
             let color = if is_selected {
-                Colors::YELLOW
+                Colors::menu_highlight()
             } else {
-                Colors::WHITE
+                Colors::menu_text()
             };
-            let text = format!("{}{}", prefix, item);
+            let text = format!("{}{}", prefix, i18n::t(item));
             renderer.draw_at_grid(&text, col, start_row + i as f32, color);
         }
     }
 
-    pub fn draw_centered(&self, renderer: &mut GameRenderer, start_row: f32) {
+    pub fn draw_centered(&self, renderer: &mut dyn DrawSurface, start_row: f32) {
+        self.mark_drawn_at(start_row);
         for (i, item) in self.items.iter().enumerate() {
             let is_selected = i == self.selected;
             let prefix = if is_selected { "> " } else { "  " };
             let color = if is_selected {
-                Colors::YELLOW
+                Colors::menu_highlight()
             } else {
-                Colors::WHITE
+                Colors::menu_text()
             };
-            let text = format!("{}{}", prefix, item);
+            let text = format!("{}{}", prefix, i18n::t(item));
             renderer.draw_centered(&text, start_row + i as f32, color);
         }
     }
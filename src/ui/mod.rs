@@ -1,12 +1,18 @@
 //! Reusable UI components for menus, boxes, and bars.
 
+pub mod markup;
 pub mod menu;
+pub mod text_input;
 
-use crate::render::{Colors, GameRenderer};
+use crate::render::Colors;
+use crate::surface::DrawSurface;
+
+/// Shiny color shown instead of the rainbow cycle with reduced motion.
+const STILL_RAINBOW_COLOR: [f32; 4] = [1.0, 0.85, 0.3, 1.0];
 
 /// Draw a bordered box at grid position with given dimensions.
 pub fn draw_box(
-    renderer: &mut GameRenderer,
+    renderer: &mut dyn DrawSurface,
     col: f32,
     row: f32,
     width: usize,
@@ -31,9 +37,20 @@ pub fn draw_box(
     }
 }
 
+/// Width and height of multi-line art, in grid cells.
+pub fn art_size(art: &str) -> (usize, usize) {
+    let width = art.lines().map(|line| line.chars().count()).max().unwrap_or(0);
+    (width, art.lines().count())
+}
+
+/// The first `max_lines` lines of `art`.
+pub fn clip_art_lines(art: &str, max_lines: usize) -> String {
+    art.lines().take(max_lines).collect::<Vec<_>>().join("\n")
+}
+
 /// Draw a centered bordered box.
 pub fn draw_centered_box(
-    renderer: &mut GameRenderer,
+    renderer: &mut dyn DrawSurface,
     row: f32,
     width: usize,
     height: usize,
@@ -46,7 +63,7 @@ pub fn draw_centered_box(
 
 /// Draw a progress bar at grid position.
 pub fn draw_progress_bar(
-    renderer: &mut GameRenderer,
+    renderer: &mut dyn DrawSurface,
     col: f32,
     row: f32,
     width: usize,
@@ -58,7 +75,7 @@ pub fn draw_progress_bar(
     let filled = (progress.clamp(0.0, 1.0) * inner as f32) as usize;
     let empty = inner - filled;
 
-    renderer.draw_at_grid("[", col, row, Colors::WHITE);
+    renderer.draw_at_grid("[", col, row, Colors::white());
     renderer.draw_at_grid(
         &"#".repeat(filled),
         col + 1.0,
@@ -71,12 +88,24 @@ pub fn draw_progress_bar(
         row,
         empty_color,
     );
-    renderer.draw_at_grid("]", col + 1.0 + inner as f32, row, Colors::WHITE);
+    renderer.draw_at_grid("]", col + 1.0 + inner as f32, row, Colors::white());
+}
+
+/// Truncate text to fit within a given width, counted in characters.
+pub fn truncate_to_width(text: &str, max_width: usize) -> String {
+    if text.chars().count() <= max_width {
+        text.to_string()
+    } else if max_width <= 3 {
+        text.chars().take(max_width).collect()
+    } else {
+        let kept: String = text.chars().take(max_width - 3).collect();
+        format!("{}...", kept)
+    }
 }
 
 /// Draw affection hearts.
 pub fn draw_hearts(
-    renderer: &mut GameRenderer,
+    renderer: &mut dyn DrawSurface,
     col: f32,
     row: f32,
     score: i32,
@@ -86,10 +115,82 @@ pub fn draw_hearts(
     let mut x = col;
     for i in 0..max_hearts {
         if i < full_hearts {
-            renderer.draw_at_grid("<3", x, row, Colors::RED);
+            renderer.draw_at_grid("<3", x, row, Colors::heart());
         } else {
-            renderer.draw_at_grid("<3", x, row, Colors::DARK_GRAY);
+            renderer.draw_at_grid("<3", x, row, Colors::heart_empty());
         }
         x += 3.0;
     }
 }
+
+/// Draw a fish's catch difficulty (0.0 to 1.0) as `max` stars, filled `*`
+/// up to its rating and `.` after. Each star covers an equal slice, so with
+/// five stars 0.0-0.2 is one star and 0.8-1.0 is five.
+pub fn draw_difficulty_stars(
+    renderer: &mut dyn DrawSurface,
+    col: f32,
+    row: f32,
+    difficulty: f32,
+    max: u32,
+) {
+    let max = max.max(1);
+    let stars = difficulty_stars(difficulty, max);
+    renderer.draw_at_grid(&"*".repeat(stars as usize), col, row, difficulty_color(difficulty));
+    renderer.draw_at_grid(
+        &".".repeat((max - stars) as usize),
+        col + stars as f32,
+        row,
+        Colors::dark_gray(),
+    );
+}
+
+/// Stars out of `max` for a difficulty, at least one.
+fn difficulty_stars(difficulty: f32, max: u32) -> u32 {
+    ((difficulty.clamp(0.0, 1.0) * max as f32).ceil() as u32).clamp(1, max)
+}
+
+/// Green for easy fish through yellow and orange to red for the hardest.
+fn difficulty_color(difficulty: f32) -> [f32; 4] {
+    match difficulty {
+        d if d <= 0.4 => Colors::green(),
+        d if d <= 0.6 => Colors::yellow(),
+        d if d <= 0.8 => Colors::orange(),
+        _ => Colors::red(),
+    }
+}
+
+/// Color that cycles through the rainbow over time (used for shiny fish).
+/// Holds a steady gold with reduced motion.
+pub fn rainbow_color(renderer: &dyn DrawSurface, time: f32) -> [f32; 4] {
+    if renderer.reduce_motion() {
+        return STILL_RAINBOW_COLOR;
+    }
+    let phase = time * 2.0;
+    let channel = |offset: f32| (phase + offset).sin() * 0.5 + 0.5;
+    [
+        channel(0.0),
+        channel(std::f32::consts::TAU / 3.0),
+        channel(2.0 * std::f32::consts::TAU / 3.0),
+        1.0,
+    ]
+}
+
+/// `phase.sin()` for pulsing, flashing and bobbing effects. With reduced
+/// motion it holds at the wave's peak, 1.0, so they stay steady.
+pub fn wave(renderer: &dyn DrawSurface, phase: f32) -> f32 {
+    if renderer.reduce_motion() { 1.0 } else { phase.sin() }
+}
+
+/// Animation frame number `time` frames in, or always 0 with reduced motion.
+pub fn anim_frame(renderer: &dyn DrawSurface, time: f32) -> usize {
+    if renderer.reduce_motion() { 0 } else { time as usize }
+}
+
+/// Screen shake offset in grid cells for a shake `amount` (0.0 to 1.0).
+/// Small amounts snap to no offset so a fading shake doesn't jitter.
+pub fn shake_offset(amount: f32, time: f32) -> (f32, f32) {
+    if amount <= 0.05 {
+        return (0.0, 0.0);
+    }
+    ((time * 50.0).sin() * amount * 0.5, (time * 37.0).cos() * amount * 0.3)
+}
@@ -0,0 +1,58 @@
+//! Single-line text entry.
+
+use winit::keyboard::KeyCode;
+
+use crate::render::Colors;
+use crate::surface::DrawSurface;
+use crate::ui;
+
+/// A text field built up from typed characters.
+///
+/// Backspace deletes the last character and Enter confirms. Other keys are
+/// left to the caller.
+pub struct TextInput {
+    value: String,
+    /// Longest value accepted, in characters.
+    max_len: usize,
+}
+
+impl TextInput {
+    pub fn new(initial: &str, max_len: usize) -> Self {
+        Self {
+            value: initial.chars().take(max_len).collect(),
+            max_len,
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Apply this frame's key press and typed character. Returns whether
+    /// Enter was pressed.
+    pub fn update(&mut self, key: Option<KeyCode>, typed: Option<char>) -> bool {
+        match key {
+            Some(KeyCode::Enter) => return true,
+            Some(KeyCode::Backspace) => {
+                self.value.pop();
+                return false;
+            }
+            _ => {}
+        }
+        let room = self.value.chars().count() < self.max_len;
+        if let Some(c) = typed.filter(|c| room && !c.is_control()) {
+            self.value.push(c);
+        }
+        false
+    }
+
+    /// Draw the field centered on `row` with a blinking cursor. With
+    /// reduced motion the cursor stays lit.
+    pub fn draw_centered(&self, renderer: &mut dyn DrawSurface, row: f32, time: f32) {
+        let lit = ui::anim_frame(renderer, time * 4.0).is_multiple_of(2);
+        let cursor = if lit { '_' } else { ' ' };
+        let padding = " ".repeat(self.max_len - self.value.chars().count());
+        let text = format!("[ {}{}{} ]", self.value, cursor, padding);
+        renderer.draw_centered(&text, row, Colors::yellow());
+    }
+}